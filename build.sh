@@ -1,4 +1,4 @@
 #!/bin/sh
 
-cargo fmt
+cargo fmt --all
 cargo build --all
@@ -1,5 +1,5 @@
 #!/bin/sh
 
-cargo fmt
-cargo test -- --nocapture
-cargo clippy
+cargo fmt --all
+cargo test --workspace -- --nocapture
+cargo clippy --workspace
@@ -0,0 +1,1043 @@
+use chrono::{Datelike, Local, NaiveDate, NaiveTime};
+use freundchen_core::data::{
+    ApplicationData, Dimension, Factor, FactorKind, Medication, Schedule, SleepEntry, Track,
+    MAXIMUM_SLEEP_QUALITY,
+};
+use freundchen_core::export::to_csv;
+use freundchen_core::mood::number_of_days_in_month;
+use freundchen_core::paths::{default_data_path, settings_path};
+use freundchen_core::statistics::{
+    averages_per_factor_value, tag_frequencies, Adherence, Correlation, MoodStatistics,
+    SleepStatistics,
+};
+use freundchen_core::storage::{open_storage, Storage};
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The usage of the command line interface.
+const USAGE: &str = "freundchen-cli dimension add NAME NUMBER_OF_VALUES [#rrggbb ...]
+       freundchen-cli dimension list
+       freundchen-cli dimension remove NAME
+       freundchen-cli export [--from YYYY-MM-DD] [--to YYYY-MM-DD]
+       freundchen-cli factor add NAME boolean|number|choice [CHOICE ...]
+       freundchen-cli factor list
+       freundchen-cli factor remove NAME
+       freundchen-cli factor set NAME YYYY-MM-DD VALUE|-
+       freundchen-cli medication add NAME DOSE YYYY-MM-DD daily|as-needed|every DAYS
+       freundchen-cli medication dose NAME YYYY-MM-DD DOSE
+       freundchen-cli medication list
+       freundchen-cli medication remove NAME
+       freundchen-cli medication stop NAME YYYY-MM-DD
+       freundchen-cli medication take|skip NAME YYYY-MM-DD
+       freundchen-cli migrate OUTPUT.json|OUTPUT.sqlite
+       freundchen-cli sleep set YYYY-MM-DD HH:MM HH:MM QUALITY [AWAKENINGS]
+       freundchen-cli sleep remove YYYY-MM-DD
+       freundchen-cli statistics [--from YYYY-MM-DD] [--to YYYY-MM-DD]";
+
+/// The exit code if a command failed.
+const EXIT_CODE_ERROR: i32 = 1;
+
+/// An error of a command.
+#[derive(Debug)]
+enum CliError {
+    /// The command line arguments are invalid.
+    Arguments(String),
+    Core(freundchen_core::Error),
+    Io(std::io::Error),
+    Settings(toml::de::Error),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::Arguments(message) => write!(f, "{}", message),
+            CliError::Core(error) => write!(f, "{}", error),
+            CliError::Io(error) => write!(f, "{}", error),
+            CliError::Settings(error) => write!(f, "Could not parse the settings: {}", error),
+        }
+    }
+}
+
+impl From<freundchen_core::Error> for CliError {
+    fn from(error: freundchen_core::Error) -> Self {
+        CliError::Core(error)
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(error: std::io::Error) -> Self {
+        CliError::Io(error)
+    }
+}
+
+// =================================================================================================
+
+/// The part of the application's settings the commands need.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Settings {
+    /// The path of the application data file. `None` means the default path.
+    data_path: Option<PathBuf>,
+}
+
+/// Returns the path of the application data file from the application's settings.
+fn data_path() -> Result<PathBuf, CliError> {
+    let settings_path = settings_path();
+
+    let settings: Settings = if settings_path.exists() {
+        toml::from_str(&fs::read_to_string(settings_path)?).map_err(CliError::Settings)?
+    } else {
+        Settings::default()
+    };
+
+    Ok(settings.data_path.unwrap_or_else(default_data_path))
+}
+
+/// Parses a date like `2026-09-30`.
+fn parse_date(text: &str) -> Result<NaiveDate, CliError> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+        .map_err(|_| CliError::Arguments(format!("\"{}\" is not a date like \"2026-09-30\"", text)))
+}
+
+/// Parses a number, e.g. a quality.
+fn parse_number<T: FromStr>(text: &str) -> Result<T, CliError> {
+    text.trim()
+        .parse()
+        .map_err(|_| CliError::Arguments(format!("\"{}\" is not a number, usage: {}", text, USAGE)))
+}
+
+/// Parses a time like `23:30`.
+fn parse_time(text: &str) -> Result<NaiveTime, CliError> {
+    NaiveTime::parse_from_str(text.trim(), "%H:%M")
+        .map_err(|_| CliError::Arguments(format!("\"{}\" is not a time like \"23:30\"", text)))
+}
+
+/// Parses the options `--from` and `--to` of a date range. The current month is the default.
+fn parse_date_range(
+    arguments: &[String],
+    today: NaiveDate,
+) -> Result<(NaiveDate, NaiveDate), CliError> {
+    let mut first_date = today.with_day(1).unwrap_or(today);
+    let mut last_date = first_date
+        .with_day(number_of_days_in_month(first_date) as u32)
+        .unwrap_or(today);
+
+    let mut arguments = arguments.iter();
+
+    while let Some(argument) = arguments.next() {
+        let mut option_value = || {
+            arguments
+                .next()
+                .ok_or_else(|| CliError::Arguments(format!("{} needs a value", argument)))
+        };
+
+        match argument.as_str() {
+            "--from" => first_date = parse_date(option_value()?)?,
+            "--to" => last_date = parse_date(option_value()?)?,
+            _ => {
+                return Err(CliError::Arguments(format!(
+                    "Unexpected argument \"{}\", usage: {}",
+                    argument, USAGE
+                )))
+            }
+        }
+    }
+
+    if last_date < first_date {
+        return Err(CliError::Arguments(format!(
+            "The range ends on {} before it starts on {}",
+            last_date, first_date
+        )));
+    }
+
+    Ok((first_date, last_date))
+}
+
+/// Returns the name of the track, "Mood" for the mood.
+fn track_name(application_data: &ApplicationData, track: Track) -> &str {
+    match track {
+        Track::Dimension(index) => application_data
+            .dimensions
+            .get(index)
+            .map_or("", |dimension| dimension.name.as_str()),
+        Track::Mood => "Mood",
+    }
+}
+
+// =================================================================================================
+
+/// Adds, lists or removes the dimensions tracked besides the mood. The application should be
+/// closed, as it would overwrite the changes when it saves.
+fn dimension_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let mut application_data = storage.load(today)?;
+
+    match arguments {
+        [subcommand, name, number_of_values, colors @ ..] if subcommand == "add" => {
+            if application_data
+                .dimensions
+                .iter()
+                .any(|dimension| dimension.name == name.trim())
+            {
+                return Err(CliError::Arguments(format!(
+                    "The dimension \"{}\" already exists",
+                    name.trim()
+                )));
+            }
+
+            let number_of_values = number_of_values.parse().map_err(|_| {
+                CliError::Arguments(format!(
+                    "\"{}\" is not a number of values, usage: {}",
+                    number_of_values, USAGE
+                ))
+            })?;
+
+            let dimension = Dimension::new(name, number_of_values, colors.to_vec())?;
+
+            println!(
+                "Added the dimension \"{}\" with the values from 0 to {}.",
+                dimension.name,
+                dimension.number_of_values - 1
+            );
+
+            application_data.dimensions.push(dimension);
+            storage.save(&application_data)?;
+        }
+        [subcommand] if subcommand == "list" => {
+            for dimension in &application_data.dimensions {
+                println!(
+                    "{}: values from 0 to {}, {} days",
+                    dimension.name,
+                    dimension.number_of_values - 1,
+                    dimension.values_per_date.len()
+                );
+            }
+        }
+        [subcommand, name] if subcommand == "remove" => {
+            let Some(index) = application_data
+                .dimensions
+                .iter()
+                .position(|dimension| dimension.name == name.trim())
+            else {
+                return Err(CliError::Arguments(format!(
+                    "There is no dimension \"{}\"",
+                    name.trim()
+                )));
+            };
+
+            let dimension = application_data.dimensions.remove(index);
+            storage.save(&application_data)?;
+
+            println!(
+                "Removed the dimension \"{}\" and its values of {} days.",
+                dimension.name,
+                dimension.values_per_date.len()
+            );
+        }
+        _ => {
+            return Err(CliError::Arguments(format!(
+                "Unexpected arguments, usage: {}",
+                USAGE
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the values of the days within a date range as comma separated values, e.g. to redirect
+/// them into a file for a spreadsheet. The current month is the default.
+fn export_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let (first_date, last_date) = parse_date_range(arguments, today)?;
+
+    println!("{}", to_csv(&storage.load(today)?, first_date, last_date));
+
+    Ok(())
+}
+
+/// Adds, lists or removes the factors recorded per day, or sets a day's value. The application
+/// should be closed, as it would overwrite the changes when it saves.
+fn factor_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let mut application_data = storage.load(today)?;
+
+    let factor_index = |application_data: &ApplicationData, name: &str| {
+        application_data
+            .factors
+            .iter()
+            .position(|factor| factor.name == name.trim())
+            .ok_or_else(|| CliError::Arguments(format!("There is no factor \"{}\"", name.trim())))
+    };
+
+    match arguments {
+        [subcommand, name, kind, choices @ ..] if subcommand == "add" => {
+            if factor_index(&application_data, name).is_ok() {
+                return Err(CliError::Arguments(format!(
+                    "The factor \"{}\" already exists",
+                    name.trim()
+                )));
+            }
+
+            let kind = match (kind.as_str(), choices) {
+                ("boolean", []) => FactorKind::Boolean,
+                ("number", []) => FactorKind::Number,
+                ("choice", choices) => FactorKind::Choice {
+                    choices: choices.to_vec(),
+                },
+                _ => {
+                    return Err(CliError::Arguments(format!(
+                        "Unexpected factor type \"{}\", usage: {}",
+                        kind, USAGE
+                    )))
+                }
+            };
+
+            let factor = Factor::new(name, kind)?;
+
+            println!(
+                "Added the factor \"{}\" with {} per day.",
+                factor.name, factor.kind
+            );
+
+            application_data.factors.push(factor);
+            storage.save(&application_data)?;
+        }
+        [subcommand] if subcommand == "list" => {
+            for factor in &application_data.factors {
+                println!(
+                    "{}: {}, {} days",
+                    factor.name,
+                    factor.kind,
+                    factor.values_per_date.len()
+                );
+            }
+        }
+        [subcommand, name] if subcommand == "remove" => {
+            let index = factor_index(&application_data, name)?;
+            let factor = application_data.factors.remove(index);
+            storage.save(&application_data)?;
+
+            println!(
+                "Removed the factor \"{}\" and its values of {} days.",
+                factor.name,
+                factor.values_per_date.len()
+            );
+        }
+        [subcommand, name, date, value] if subcommand == "set" => {
+            let index = factor_index(&application_data, name)?;
+            let date = parse_date(date)?;
+            let factor = &mut application_data.factors[index];
+
+            // A dash removes the day's value.
+            let value = match value.trim() {
+                "-" => None,
+                value => Some(factor.parse_value(value)?),
+            };
+
+            match &value {
+                Some(value) => println!("Set \"{}\" on {} to {}.", factor.name, date, value),
+                None => println!("Removed the value of \"{}\" on {}.", factor.name, date),
+            }
+
+            factor.set_value(date, value);
+            storage.save(&application_data)?;
+        }
+        _ => {
+            return Err(CliError::Arguments(format!(
+                "Unexpected arguments, usage: {}",
+                USAGE
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds, lists, changes or removes medications and records their intakes. The application should
+/// be closed, as it would overwrite the changes when it saves.
+fn medication_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let mut application_data = storage.load(today)?;
+
+    let medication_index = |application_data: &ApplicationData, name: &str| {
+        application_data
+            .medications
+            .iter()
+            .position(|medication| medication.name == name.trim())
+            .ok_or_else(|| {
+                CliError::Arguments(format!("There is no medication \"{}\"", name.trim()))
+            })
+    };
+
+    match arguments {
+        [subcommand, name, dose, start_date, schedule @ ..] if subcommand == "add" => {
+            if medication_index(&application_data, name).is_ok() {
+                return Err(CliError::Arguments(format!(
+                    "The medication \"{}\" already exists",
+                    name.trim()
+                )));
+            }
+
+            let schedule = match schedule {
+                [schedule] if schedule == "daily" => Schedule::Daily,
+                [schedule] if schedule == "as-needed" => Schedule::AsNeeded,
+                [schedule, interval] if schedule == "every" => Schedule::EveryNDays {
+                    interval: parse_number(interval)?,
+                },
+                _ => {
+                    return Err(CliError::Arguments(format!(
+                        "Unexpected schedule \"{}\", usage: {}",
+                        schedule.join(" "),
+                        USAGE
+                    )))
+                }
+            };
+
+            let medication = Medication::new(name, dose, schedule, parse_date(start_date)?)?;
+
+            println!(
+                "Added the medication \"{}\" with {} {} from {} on.",
+                medication.name, medication.dose, medication.schedule, medication.start_date
+            );
+
+            application_data.medications.push(medication);
+            storage.save(&application_data)?;
+        }
+        [subcommand, name, date, dose] if subcommand == "dose" => {
+            let index = medication_index(&application_data, name)?;
+            let date = parse_date(date)?;
+            let medication = &mut application_data.medications[index];
+
+            medication.change_dose(date, dose)?;
+            storage.save(&application_data)?;
+
+            println!(
+                "Changed the dose of \"{}\" to {} from {} on.",
+                name.trim(),
+                dose.trim(),
+                date
+            );
+        }
+        [subcommand] if subcommand == "list" => {
+            for medication in &application_data.medications {
+                let doses: Vec<String> =
+                    std::iter::once((&medication.start_date, &medication.dose))
+                        .chain(&medication.dose_changes)
+                        .map(|(date, dose)| format!("{} from {}", dose, date))
+                        .collect();
+
+                println!(
+                    "{}: {}, {}{}, taken on {} days",
+                    medication.name,
+                    medication.schedule,
+                    doses.join(", "),
+                    medication
+                        .stop_date
+                        .map(|stop_date| format!(", stopped on {}", stop_date))
+                        .unwrap_or_default(),
+                    medication.intake_dates.len()
+                );
+            }
+        }
+        [subcommand, name] if subcommand == "remove" => {
+            let index = medication_index(&application_data, name)?;
+            let medication = application_data.medications.remove(index);
+            storage.save(&application_data)?;
+
+            println!(
+                "Removed the medication \"{}\" and its intakes on {} days.",
+                medication.name,
+                medication.intake_dates.len()
+            );
+        }
+        [subcommand, name, date] if subcommand == "stop" => {
+            let index = medication_index(&application_data, name)?;
+            let date = parse_date(date)?;
+
+            application_data.medications[index].stop(date)?;
+            storage.save(&application_data)?;
+
+            println!("Stopped \"{}\" on {}.", name.trim(), date);
+        }
+        [subcommand, name, date] if subcommand == "take" || subcommand == "skip" => {
+            let index = medication_index(&application_data, name)?;
+            let date = parse_date(date)?;
+            let medication = &mut application_data.medications[index];
+
+            if !medication.is_active_on(date) {
+                return Err(CliError::Arguments(format!(
+                    "\"{}\" is not taken on {}",
+                    medication.name, date
+                )));
+            }
+
+            let is_taken = subcommand == "take";
+            medication.set_intake(date, is_taken);
+            storage.save(&application_data)?;
+
+            if is_taken {
+                println!("Recorded that \"{}\" was taken on {}.", name.trim(), date);
+            } else {
+                println!("Recorded that \"{}\" was skipped on {}.", name.trim(), date);
+            }
+        }
+        _ => {
+            return Err(CliError::Arguments(format!(
+                "Unexpected arguments, usage: {}",
+                USAGE
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the application data from the given storage to the storage at the path in the
+/// arguments.
+fn migrate_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let [target_path] = arguments else {
+        return Err(CliError::Arguments(format!(
+            "Expected the output file, usage: {}",
+            USAGE
+        )));
+    };
+
+    let target_path = PathBuf::from(target_path);
+
+    // Do not overwrite existing data.
+    if target_path.exists() {
+        return Err(CliError::Arguments(format!(
+            "\"{}\" already exists, usage: {}",
+            target_path.display(),
+            USAGE
+        )));
+    }
+
+    let application_data = storage.load(today)?;
+    open_storage(target_path.clone()).save(&application_data)?;
+
+    println!(
+        "Copied the data from \"{}\" to \"{}\". Set `data_path` in the settings to use it.",
+        storage.path().display(),
+        target_path.display()
+    );
+
+    Ok(())
+}
+
+/// Sets or removes the sleep of the night after a day. The application should be closed, as it
+/// would overwrite the changes when it saves.
+fn sleep_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let mut application_data = storage.load(today)?;
+
+    match arguments {
+        [subcommand, date, bedtime, wake_time, quality, awakenings @ ..]
+            if subcommand == "set" && awakenings.len() <= 1 =>
+        {
+            let date = parse_date(date)?;
+
+            let sleep = SleepEntry::new(
+                parse_time(bedtime)?,
+                parse_time(wake_time)?,
+                parse_number(quality)?,
+                awakenings
+                    .first()
+                    .map_or(Ok(0), |awakenings| parse_number(awakenings))?,
+            )?;
+
+            println!(
+                "Set the night after {} to {} hours.",
+                date,
+                format_hours(sleep.hours())
+            );
+
+            application_data.sleep_per_date.insert(date, sleep);
+            storage.save(&application_data)?;
+        }
+        [subcommand, date] if subcommand == "remove" => {
+            let date = parse_date(date)?;
+
+            if application_data.sleep_per_date.remove(&date).is_none() {
+                return Err(CliError::Arguments(format!(
+                    "There is no sleep in the night after {}",
+                    date
+                )));
+            }
+
+            storage.save(&application_data)?;
+
+            println!("Removed the night after {}.", date);
+        }
+        _ => {
+            return Err(CliError::Arguments(format!(
+                "Unexpected arguments, usage: {}",
+                USAGE
+            )))
+        }
+    }
+
+    Ok(())
+}
+
+/// Formats hours like `7:30`.
+fn format_hours(hours: f64) -> String {
+    let minutes = (hours * 60.0).round() as i64;
+
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Prints the summary statistics of the mood and the dimensions within a date range, how they
+/// correlate, how often the days were tagged with each tag, how the factors and the sleep relate
+/// to the mood and how well the medications were taken. The current month is the default.
+fn statistics_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    today: NaiveDate,
+) -> Result<(), CliError> {
+    let (first_date, last_date) = parse_date_range(arguments, today)?;
+
+    let application_data = storage.load(today)?;
+    let tracks: Vec<(Track, &_)> = application_data
+        .tracks()
+        .filter_map(|track| Some((track, application_data.values_per_date(track)?)))
+        .collect();
+
+    println!("From {} to {}:", first_date, last_date);
+
+    for (track, values_per_date) in &tracks {
+        let statistics = MoodStatistics::new(values_per_date, first_date, last_date);
+
+        println!(
+            "{}: values on {} of {} days",
+            track_name(&application_data, *track),
+            statistics.number_of_days_with_mood_values,
+            statistics.number_of_days
+        );
+
+        if let (Some(average), Some(minimum), Some(maximum)) = (
+            statistics.average_mood_value,
+            statistics.minimum_mood_value,
+            statistics.maximum_mood_value,
+        ) {
+            println!(
+                "  {:.1} on average, from {} to {} of {}",
+                average,
+                minimum,
+                maximum,
+                application_data.number_of_values(*track) - 1
+            );
+        }
+    }
+
+    // Compare each pair of tracks.
+    if tracks.len() > 1 {
+        println!("Correlations:");
+    }
+
+    for (index, (track, values_per_date)) in tracks.iter().enumerate() {
+        for (other_track, other_values_per_date) in &tracks[index + 1..] {
+            let names = format!(
+                "{} and {}",
+                track_name(&application_data, *track),
+                track_name(&application_data, *other_track)
+            );
+
+            match Correlation::new(
+                values_per_date,
+                other_values_per_date,
+                first_date,
+                last_date,
+            ) {
+                Some(correlation) => println!(
+                    "  {}: {:+.2} on {} days",
+                    names, correlation.coefficient, correlation.number_of_days
+                ),
+                None => println!("  {}: not enough varying values on the same days", names),
+            }
+        }
+    }
+
+    let tag_frequencies = tag_frequencies(&application_data.tags_per_date, first_date, last_date);
+
+    if !tag_frequencies.is_empty() {
+        println!("Tags:");
+    }
+
+    for (tag, frequency) in tag_frequencies {
+        println!("  {}: {} days", tag, frequency);
+    }
+
+    // Relate each factor to the mood: numbers are correlated, the other values averaged.
+    if !application_data.factors.is_empty() {
+        println!("Factors and mood:");
+    }
+
+    for factor in &application_data.factors {
+        match factor.kind {
+            FactorKind::Number => {
+                match Correlation::with_factor(
+                    &application_data.mood_per_date,
+                    factor,
+                    first_date,
+                    last_date,
+                ) {
+                    Some(correlation) => println!(
+                        "  {}: {:+.2} on {} days",
+                        factor.name, correlation.coefficient, correlation.number_of_days
+                    ),
+                    None => println!(
+                        "  {}: not enough varying values on days with mood values",
+                        factor.name
+                    ),
+                }
+            }
+            FactorKind::Boolean | FactorKind::Choice { .. } => {
+                let averages = averages_per_factor_value(
+                    &application_data.mood_per_date,
+                    factor,
+                    first_date,
+                    last_date,
+                );
+
+                if averages.is_empty() {
+                    println!("  {}: no values on days with mood values", factor.name);
+                }
+
+                for average in averages {
+                    println!(
+                        "  {} {}: {:.1} on average on {} days",
+                        factor.name,
+                        average.value,
+                        average.average_mood_value,
+                        average.number_of_days
+                    );
+                }
+            }
+        }
+    }
+
+    let sleep_statistics = SleepStatistics::new(
+        &application_data.sleep_per_date,
+        &application_data.mood_per_date,
+        first_date,
+        last_date,
+    );
+
+    if let (Some(average_hours), Some(average_quality), Some(average_awakenings)) = (
+        sleep_statistics.average_hours,
+        sleep_statistics.average_quality,
+        sleep_statistics.average_awakenings,
+    ) {
+        println!("Sleep:");
+        println!(
+            "  {} hours on average on {} nights, quality {:.1} of {}, {:.1} awakenings",
+            format_hours(average_hours),
+            sleep_statistics.number_of_nights,
+            average_quality,
+            MAXIMUM_SLEEP_QUALITY,
+            average_awakenings
+        );
+
+        for (name, correlation) in [
+            ("Hours", &sleep_statistics.hours_correlation),
+            ("Quality", &sleep_statistics.quality_correlation),
+        ] {
+            match correlation {
+                Some(correlation) => println!(
+                    "  {} and the next day's mood: {:+.2} on {} days",
+                    name, correlation.coefficient, correlation.number_of_days
+                ),
+                None => println!(
+                    "  {} and the next day's mood: not enough varying values",
+                    name
+                ),
+            }
+        }
+    }
+
+    // Tell how well each medication was taken as scheduled.
+    if !application_data.medications.is_empty() {
+        println!("Medications:");
+    }
+
+    for medication in &application_data.medications {
+        // The days after today could not be taken yet.
+        let adherence = Adherence::new(medication, first_date, last_date.min(today));
+
+        match adherence.ratio() {
+            Some(ratio) => println!(
+                "  {}: taken on {} of {} scheduled days ({:.0} %)",
+                medication.name,
+                adherence.number_of_days_taken,
+                adherence.number_of_scheduled_days,
+                ratio * 100.0
+            ),
+            None => println!(
+                "  {}: taken on {} days",
+                medication.name, adherence.number_of_intakes
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+// =================================================================================================
+
+pub fn main() {
+    if let Err(error) = try_main() {
+        eprintln!("Error: {}", error);
+        std::process::exit(EXIT_CODE_ERROR);
+    }
+}
+
+/// Runs the command given in the arguments.
+fn try_main() -> Result<(), CliError> {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    let today = Local::now().naive_local().date();
+
+    let Some(command) = arguments.first() else {
+        return Err(CliError::Arguments(format!(
+            "The command is missing, usage: {}",
+            USAGE
+        )));
+    };
+
+    let mut storage = open_storage(data_path()?);
+
+    match command.as_str() {
+        "dimension" => dimension_command(&arguments[1..], storage.as_mut(), today),
+        "export" => export_command(&arguments[1..], storage.as_mut(), today),
+        "factor" => factor_command(&arguments[1..], storage.as_mut(), today),
+        "medication" => medication_command(&arguments[1..], storage.as_mut(), today),
+        "migrate" => migrate_command(&arguments[1..], storage.as_mut(), today),
+        "sleep" => sleep_command(&arguments[1..], storage.as_mut(), today),
+        "statistics" => statistics_command(&arguments[1..], storage.as_mut(), today),
+        _ => Err(CliError::Arguments(format!(
+            "Unknown command \"{}\", usage: {}",
+            command, USAGE
+        ))),
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use freundchen_core::data::FactorValue;
+    use freundchen_core::storage::JsonStorage;
+    use std::env;
+
+    /// A command of the command line interface.
+    type CommandFunction = fn(&[String], &mut dyn Storage, NaiveDate) -> Result<(), CliError>;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns the given arguments as strings.
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments.iter().map(ToString::to_string).collect()
+    }
+
+    /// Runs the given commands on an empty data file and returns the data afterwards, with the
+    /// commands' results in order.
+    fn run(
+        name: &str,
+        commands: &[(CommandFunction, &[&str])],
+    ) -> (ApplicationData, Vec<Result<(), CliError>>) {
+        let directory =
+            env::temp_dir().join(format!("freundchen-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        let mut storage = JsonStorage::new(directory.join("freundchen.json"));
+
+        let results = commands
+            .iter()
+            .map(|(command, command_arguments)| {
+                command(&arguments(command_arguments), &mut storage, september(15))
+            })
+            .collect();
+        let application_data = storage.load(september(15)).unwrap();
+        let _ = fs::remove_dir_all(&directory);
+
+        (application_data, results)
+    }
+
+    #[test]
+    fn the_date_range_is_the_current_month_by_default() {
+        assert_eq!(
+            parse_date_range(&[], september(15)).unwrap(),
+            (september(1), september(30))
+        );
+        assert_eq!(
+            parse_date_range(&arguments(&["--to", "2026-09-10"]), september(15)).unwrap(),
+            (september(1), september(10))
+        );
+    }
+
+    #[test]
+    fn invalid_date_ranges_are_rejected() {
+        for range_arguments in [
+            &["--from"][..],
+            &["--from", "2026-09-31"],
+            &["--from", "2026-09-10", "--to", "2026-09-09"],
+            &["--since", "2026-09-10"],
+        ] {
+            assert!(matches!(
+                parse_date_range(&arguments(range_arguments), september(15)),
+                Err(CliError::Arguments(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn dates_times_and_numbers_are_parsed() {
+        assert_eq!(parse_date(" 2026-09-01 ").unwrap(), september(1));
+        assert_eq!(
+            parse_time("23:30").unwrap(),
+            NaiveTime::from_hms_opt(23, 30, 0).unwrap()
+        );
+        assert_eq!(parse_number::<u8>("4").unwrap(), 4);
+        assert!(parse_date("01.09.2026").is_err());
+        assert!(parse_time("25:00").is_err());
+        assert!(parse_number::<u8>("-1").is_err());
+    }
+
+    #[test]
+    fn factors_are_added_set_and_removed() {
+        let (application_data, results) = run(
+            "factor",
+            &[
+                (
+                    factor_command,
+                    &["add", "Weather", "choice", "sunny", "rainy"],
+                ),
+                (factor_command, &["add", "Sleep", "number"]),
+                (factor_command, &["set", "Weather", "2026-09-01", "Rainy"]),
+                (factor_command, &["set", "Sleep", "2026-09-01", "7,5"]),
+                (factor_command, &["set", "Sleep", "2026-09-01", "-"]),
+                (factor_command, &["add", "Alcohol", "boolean"]),
+                (factor_command, &["remove", "Alcohol"]),
+            ],
+        );
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(
+            application_data
+                .factors
+                .iter()
+                .map(|factor| factor.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Weather", "Sleep"]
+        );
+        assert_eq!(
+            application_data.factors[0]
+                .values_per_date
+                .get(&september(1)),
+            Some(&FactorValue::Choice("rainy".to_string()))
+        );
+        assert!(application_data.factors[1].values_per_date.is_empty());
+    }
+
+    #[test]
+    fn invalid_factor_arguments_are_rejected() {
+        let (application_data, results) = run(
+            "invalid-factor",
+            &[
+                (factor_command, &["add", "Sleep", "number"]),
+                (factor_command, &["add", "Sleep", "boolean"]),
+                (factor_command, &["add", "Mood", "colour"]),
+                (factor_command, &["add", "Alcohol", "boolean", "yes"]),
+                (factor_command, &["set", "Sleep", "2026-09-01", "long"]),
+                (factor_command, &["set", "Walk", "2026-09-01", "yes"]),
+            ],
+        );
+
+        assert!(results[0].is_ok());
+        assert!(results[1..].iter().all(Result::is_err));
+        assert_eq!(application_data.factors.len(), 1);
+    }
+
+    #[test]
+    fn medications_are_added_with_a_schedule_and_changed() {
+        let (application_data, results) = run(
+            "medication",
+            &[
+                (
+                    medication_command,
+                    &["add", "Sertraline", "50 mg", "2026-09-01", "every", "2"],
+                ),
+                (
+                    medication_command,
+                    &["add", "Ibuprofen", "400 mg", "2026-09-01", "as-needed"],
+                ),
+                (
+                    medication_command,
+                    &["dose", "Sertraline", "2026-09-11", "100 mg"],
+                ),
+                (medication_command, &["take", "Sertraline", "2026-09-03"]),
+                (medication_command, &["stop", "Sertraline", "2026-09-21"]),
+            ],
+        );
+
+        assert!(results.iter().all(Result::is_ok));
+
+        let sertraline = &application_data.medications[0];
+        assert_eq!(sertraline.schedule, Schedule::EveryNDays { interval: 2 });
+        assert_eq!(sertraline.dose_on(september(12)), Some("100 mg"));
+        assert_eq!(sertraline.dose_on(september(21)), None);
+        assert!(sertraline.intake_dates.contains(&september(3)));
+        assert_eq!(application_data.medications[1].schedule, Schedule::AsNeeded);
+    }
+
+    #[test]
+    fn invalid_medication_arguments_are_rejected() {
+        let (application_data, results) = run(
+            "invalid-medication",
+            &[
+                (
+                    medication_command,
+                    &["add", "Sertraline", "50 mg", "2026-09-01", "weekly"],
+                ),
+                (
+                    medication_command,
+                    &["add", "Sertraline", "50 mg", "2026-09-01", "every", "0"],
+                ),
+                (
+                    medication_command,
+                    &["add", "Sertraline", " ", "2026-09-01", "daily"],
+                ),
+                (medication_command, &["take", "Sertraline", "2026-09-03"]),
+            ],
+        );
+
+        assert!(results.iter().all(Result::is_err));
+        assert!(application_data.medications.is_empty());
+    }
+}
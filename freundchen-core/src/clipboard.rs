@@ -0,0 +1,335 @@
+//! The textual representations of mood values to exchange them with spreadsheets and messages:
+//! tab separated values with a date and the space separated mood values per line, or JSON like the
+//! data file's `mood_per_date`.
+
+use crate::mood::MoodValue;
+use crate::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// The header of the tab separated values.
+const TSV_HEADER: &str = "date\tmood values";
+
+/// The JSON representation, shaped like a part of the data file.
+#[derive(Deserialize, Serialize)]
+struct MoodValuesJson {
+    mood_per_date: BTreeMap<NaiveDate, Vec<MoodValue>>,
+}
+
+/// Returns the mood values of the days from `first_date` to `last_date` as tab separated values.
+/// Days without mood values have an empty second column.
+pub fn to_tsv(
+    mood_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> String {
+    let mut tsv = TSV_HEADER.to_string();
+
+    for (date, mood_values) in sorted_mood_values(mood_per_date, first_date, last_date) {
+        let mood_values: Vec<String> = mood_values.iter().map(ToString::to_string).collect();
+
+        tsv.push_str(&format!("\n{}\t{}", date, mood_values.join(" ")));
+    }
+
+    tsv
+}
+
+/// Returns the mood values of the days from `first_date` to `last_date` as JSON. Days without mood
+/// values have an empty list.
+pub fn to_json(
+    mood_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> Result<String, Error> {
+    let mood_values_json = MoodValuesJson {
+        mood_per_date: sorted_mood_values(mood_per_date, first_date, last_date).collect(),
+    };
+
+    Ok(serde_json::to_string_pretty(&mood_values_json)?)
+}
+
+/// Parses the mood values per date from tab separated values or JSON, as written by `to_tsv()` and
+/// `to_json()`. The header line of the tab separated values is optional and the mood values may be
+/// separated by commas, too. Days with empty mood values are kept, so that pasting clears them.
+/// The mood values must be less than the number of values of the track they are pasted to.
+pub fn parse(
+    text: &str,
+    number_of_values: MoodValue,
+) -> Result<BTreeMap<NaiveDate, HashSet<MoodValue>>, Error> {
+    let mood_per_date = if text.trim_start().starts_with('{') {
+        parse_json(text, number_of_values)?
+    } else {
+        parse_tsv(text, number_of_values)?
+    };
+
+    // There is nothing to paste.
+    if mood_per_date.is_empty() {
+        return Err(invalid_mood_text(1, "There are no mood values"));
+    }
+
+    Ok(mood_per_date)
+}
+
+// =================================================================================================
+
+/// Returns an error about the given line.
+fn invalid_mood_text(line: usize, reason: impl Into<String>) -> Error {
+    Error::InvalidMoodText {
+        line,
+        reason: reason.into(),
+    }
+}
+
+/// Parses the mood values per date from JSON.
+fn parse_json(
+    text: &str,
+    number_of_values: MoodValue,
+) -> Result<BTreeMap<NaiveDate, HashSet<MoodValue>>, Error> {
+    let mood_values_json: MoodValuesJson = serde_json::from_str(text)
+        .map_err(|error| invalid_mood_text(error.line(), error.to_string()))?;
+
+    mood_values_json
+        .mood_per_date
+        .into_iter()
+        .map(|(date, mood_values)| {
+            Ok((
+                date,
+                validate_mood_values(1, mood_values, number_of_values)?,
+            ))
+        })
+        .collect()
+}
+
+/// Parses the mood values per date from tab separated values.
+fn parse_tsv(
+    text: &str,
+    number_of_values: MoodValue,
+) -> Result<BTreeMap<NaiveDate, HashSet<MoodValue>>, Error> {
+    let mut mood_per_date = BTreeMap::new();
+
+    for (line_index, line) in text.lines().enumerate() {
+        let line_number = line_index + 1;
+        let line = line.trim_end_matches('\r');
+
+        // Skip empty lines and the header.
+        if line.trim().is_empty() || (line_index == 0 && line.trim() == TSV_HEADER) {
+            continue;
+        }
+
+        let (date, mood_values) = line.split_once('\t').unwrap_or((line, ""));
+
+        let date = date.trim().parse::<NaiveDate>().map_err(|_| {
+            invalid_mood_text(
+                line_number,
+                format!("\"{}\" is not a date like 2026-10-19", date.trim()),
+            )
+        })?;
+
+        let mood_values = mood_values
+            .split([' ', ',', '\t'])
+            .filter(|mood_value| !mood_value.is_empty())
+            .map(|mood_value| {
+                mood_value.parse::<MoodValue>().map_err(|_| {
+                    invalid_mood_text(
+                        line_number,
+                        format!("\"{}\" is not a mood value", mood_value),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if mood_per_date
+            .insert(
+                date,
+                validate_mood_values(line_number, mood_values, number_of_values)?,
+            )
+            .is_some()
+        {
+            return Err(invalid_mood_text(
+                line_number,
+                format!("{} appears more than once", date),
+            ));
+        }
+    }
+
+    Ok(mood_per_date)
+}
+
+/// Returns the mood values of the days from `first_date` to `last_date`, in ascending order.
+fn sorted_mood_values(
+    mood_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> impl Iterator<Item = (NaiveDate, Vec<MoodValue>)> + '_ {
+    first_date
+        .iter_days()
+        .take_while(move |date| *date <= last_date)
+        .map(|date| {
+            let mut mood_values: Vec<MoodValue> = mood_per_date
+                .get(&date)
+                .map(|mood_values| mood_values.iter().copied().collect())
+                .unwrap_or_default();
+            mood_values.sort();
+
+            (date, mood_values)
+        })
+}
+
+/// Checks that the mood values are within the scale of the given number of values.
+fn validate_mood_values(
+    line_number: usize,
+    mood_values: Vec<MoodValue>,
+    number_of_values: MoodValue,
+) -> Result<HashSet<MoodValue>, Error> {
+    match mood_values
+        .iter()
+        .find(|mood_value| **mood_value >= number_of_values)
+    {
+        Some(mood_value) => Err(invalid_mood_text(
+            line_number,
+            format!(
+                "{} is not a mood value from 0 to {}",
+                mood_value,
+                number_of_values.saturating_sub(1)
+            ),
+        )),
+        None => Ok(mood_values.into_iter().collect()),
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mood::NUMBER_OF_MOOD_VALUES_PER_DAY;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns mood values of the 1st and the 3rd of September 2026.
+    fn mood_per_date() -> BTreeMap<NaiveDate, HashSet<MoodValue>> {
+        BTreeMap::from([
+            (september(1), HashSet::from([7, 3])),
+            (september(3), HashSet::from([10])),
+        ])
+    }
+
+    /// Returns the line of the error about invalid mood text.
+    fn error_line(result: Result<BTreeMap<NaiveDate, HashSet<MoodValue>>, Error>) -> usize {
+        match result {
+            Err(Error::InvalidMoodText { line, .. }) => line,
+            result => panic!("expected invalid mood text, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn tsv_has_a_line_per_day_with_sorted_mood_values() {
+        assert_eq!(
+            to_tsv(&mood_per_date(), september(1), september(3)),
+            "date\tmood values\n2026-09-01\t3 7\n2026-09-02\t\n2026-09-03\t10"
+        );
+    }
+
+    #[test]
+    fn tsv_and_json_are_parsed_back() {
+        let mut expected = mood_per_date();
+        // The day without mood values is kept, so that pasting clears it.
+        expected.insert(september(2), HashSet::new());
+
+        let tsv = to_tsv(&mood_per_date(), september(1), september(3));
+        let json = to_json(&mood_per_date(), september(1), september(3)).unwrap();
+
+        assert_eq!(
+            parse(&tsv, NUMBER_OF_MOOD_VALUES_PER_DAY).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse(&json, NUMBER_OF_MOOD_VALUES_PER_DAY).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn tsv_without_a_header_and_with_commas_is_parsed() {
+        assert_eq!(
+            parse(
+                "2026-09-01\t3, 7\r\n\n2026-09-03\t10\n",
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )
+            .unwrap(),
+            mood_per_date()
+        );
+    }
+
+    #[test]
+    fn a_date_appearing_twice_is_rejected() {
+        assert_eq!(
+            error_line(parse(
+                "date\tmood values\n2026-09-01\t3\n2026-09-01\t4",
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )),
+            3
+        );
+    }
+
+    #[test]
+    fn mood_values_outside_of_the_scale_are_rejected() {
+        assert_eq!(
+            error_line(parse(
+                "2026-09-01\t3\n2026-09-02\t11",
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )),
+            2
+        );
+        assert_eq!(
+            error_line(parse(
+                r#"{ "mood_per_date": { "2026-09-01": [11] } }"#,
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )),
+            1
+        );
+    }
+
+    #[test]
+    fn mood_values_outside_of_a_dimensions_scale_are_rejected() {
+        assert_eq!(
+            parse("2026-09-01\t4", 5).unwrap(),
+            BTreeMap::from([(september(1), HashSet::from([4]))])
+        );
+        assert_eq!(error_line(parse("2026-09-01\t2\n2026-09-02\t5", 5)), 2);
+        assert_eq!(
+            error_line(parse(r#"{ "mood_per_date": { "2026-09-01": [7] } }"#, 5)),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_dates_numbers_and_empty_text_are_rejected() {
+        assert_eq!(
+            error_line(parse("2026-09-31\t3", NUMBER_OF_MOOD_VALUES_PER_DAY)),
+            1
+        );
+        assert_eq!(
+            error_line(parse(
+                "2026-09-01\t3\n2026-09-02\tbad",
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )),
+            2
+        );
+        assert_eq!(
+            error_line(parse("date\tmood values\n", NUMBER_OF_MOOD_VALUES_PER_DAY)),
+            1
+        );
+        assert_eq!(
+            error_line(parse(
+                "{ \"mood_per_date\": [",
+                NUMBER_OF_MOOD_VALUES_PER_DAY
+            )),
+            1
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use crate::data::FactorValue;
+use crate::mood::MoodValue;
+use std::collections::HashSet;
+
+/// An edit of several days at once, e.g. of a selected range of days.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BulkEdit {
+    /// Appends the note to the days' notes.
+    AddNote(String),
+    /// Removes the days' mood values.
+    ClearMoodValues,
+    /// Sets the value of the factor at the index in `ApplicationData::factors`. `None` removes the
+    /// days' values.
+    SetFactorValue {
+        factor_index: usize,
+        value: Option<FactorValue>,
+    },
+    /// Replaces the days' mood values, e.g. with the ones copied from another day.
+    SetMoodValues(HashSet<MoodValue>),
+}
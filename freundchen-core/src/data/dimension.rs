@@ -0,0 +1,110 @@
+use crate::mood::{MoodValue, NUMBER_OF_MOOD_VALUES_PER_DAY};
+use crate::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// The least number of values of a dimension's scale.
+pub const MINIMUM_NUMBER_OF_DIMENSION_VALUES: MoodValue = 2;
+
+/// A user-defined dimension tracked besides the mood, e.g. anxiety, energy or irritability.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Dimension {
+    /// The colours of the values from the lowest to the highest, written like `"#ff8000"`. Without
+    /// colours, the theme's mood colours are used.
+    #[serde(default)]
+    pub colors: Vec<String>,
+    pub name: String,
+    /// The values are from 0 to `number_of_values - 1`.
+    pub number_of_values: MoodValue,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub values_per_date: BTreeMap<NaiveDate, HashSet<MoodValue>>,
+}
+
+impl Dimension {
+    /// Creates a dimension without values. The number of values must be within the mood's scale
+    /// and the colours must be written like `"#ff8000"`.
+    pub fn new(
+        name: &str,
+        number_of_values: MoodValue,
+        colors: Vec<String>,
+    ) -> Result<Self, Error> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(Error::InvalidDimension("The name is empty".to_string()));
+        }
+
+        if !(MINIMUM_NUMBER_OF_DIMENSION_VALUES..=NUMBER_OF_MOOD_VALUES_PER_DAY)
+            .contains(&number_of_values)
+        {
+            return Err(Error::InvalidDimension(format!(
+                "The number of values must be from {} to {}",
+                MINIMUM_NUMBER_OF_DIMENSION_VALUES, NUMBER_OF_MOOD_VALUES_PER_DAY
+            )));
+        }
+
+        if let Some(color) = colors.iter().find(|color| !is_color(color)) {
+            return Err(Error::InvalidDimension(format!(
+                "\"{}\" is not a colour like \"#ff8000\"",
+                color
+            )));
+        }
+
+        Ok(Dimension {
+            colors,
+            name: name.to_string(),
+            number_of_values,
+            values_per_date: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the dimension without its values.
+    pub fn definition(&self) -> Self {
+        Dimension {
+            colors: self.colors.clone(),
+            name: self.name.clone(),
+            number_of_values: self.number_of_values,
+            values_per_date: BTreeMap::new(),
+        }
+    }
+}
+
+// =================================================================================================
+
+/// What is tracked per day: the mood or one of the user-defined dimensions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Track {
+    /// The dimension at the index in `ApplicationData::dimensions`.
+    Dimension(usize),
+    #[default]
+    Mood,
+}
+
+// =================================================================================================
+
+/// Returns whether the text is a colour like `"#ff8000"`.
+fn is_color(text: &str) -> bool {
+    text.strip_prefix('#').is_some_and(|hex_digits| {
+        hex_digits.len() == 6 && hex_digits.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_dimension_needs_a_name_a_scale_and_valid_colours() {
+        let dimension = Dimension::new(" Energy ", 5, vec!["#ff8000".to_string()]).unwrap();
+
+        assert_eq!(dimension.name, "Energy");
+        assert!(Dimension::new(" ", 5, vec![]).is_err());
+        assert!(Dimension::new("Energy", MINIMUM_NUMBER_OF_DIMENSION_VALUES - 1, vec![]).is_err());
+        assert!(Dimension::new("Energy", NUMBER_OF_MOOD_VALUES_PER_DAY + 1, vec![]).is_err());
+        assert!(Dimension::new("Energy", 5, vec!["orange".to_string()]).is_err());
+        assert!(Dimension::new("Energy", 5, vec!["#ff80zz".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// The least number of choices of a choice factor.
+pub const MINIMUM_NUMBER_OF_CHOICES: usize = 2;
+
+/// A user-defined factor recorded per day to relate it to the mood, e.g. the hours of sleep, the
+/// caffeine or the weather.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Factor {
+    pub kind: FactorKind,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub values_per_date: BTreeMap<NaiveDate, FactorValue>,
+}
+
+impl Factor {
+    /// Creates a factor without values. A choice factor needs at least
+    /// `MINIMUM_NUMBER_OF_CHOICES` distinct choices.
+    pub fn new(name: &str, kind: FactorKind) -> Result<Self, Error> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(Error::InvalidFactor("The name is empty".to_string()));
+        }
+
+        let kind = match kind {
+            FactorKind::Choice { choices } => {
+                let mut distinct_choices: Vec<String> = vec![];
+
+                for choice in choices.iter().map(|choice| choice.trim()) {
+                    if !choice.is_empty() && !distinct_choices.iter().any(|c| c == choice) {
+                        distinct_choices.push(choice.to_string());
+                    }
+                }
+
+                if distinct_choices.len() < MINIMUM_NUMBER_OF_CHOICES {
+                    return Err(Error::InvalidFactor(format!(
+                        "A choice factor needs at least {} distinct choices",
+                        MINIMUM_NUMBER_OF_CHOICES
+                    )));
+                }
+
+                FactorKind::Choice {
+                    choices: distinct_choices,
+                }
+            }
+            kind => kind,
+        };
+
+        Ok(Factor {
+            kind,
+            name: name.to_string(),
+            values_per_date: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the factor without its values.
+    pub fn definition(&self) -> Self {
+        Factor {
+            kind: self.kind.clone(),
+            name: self.name.clone(),
+            values_per_date: BTreeMap::new(),
+        }
+    }
+
+    /// Returns whether the value is of the factor's kind, and one of its choices for a choice
+    /// factor.
+    pub fn accepts(&self, value: &FactorValue) -> bool {
+        match (&self.kind, value) {
+            (FactorKind::Boolean, FactorValue::Boolean(_)) => true,
+            (FactorKind::Number, FactorValue::Number(number)) => number.is_finite(),
+            (FactorKind::Choice { choices }, FactorValue::Choice(choice)) => {
+                choices.contains(choice)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses a value of the factor's kind: "yes" or "no" for a boolean factor, a number like
+    /// "7.5" or one of the choices, ignoring the case.
+    pub fn parse_value(&self, text: &str) -> Result<FactorValue, Error> {
+        let text = text.trim();
+
+        let value = match &self.kind {
+            FactorKind::Boolean => match text.to_lowercase().as_str() {
+                "yes" | "true" | "1" => Some(FactorValue::Boolean(true)),
+                "no" | "false" | "0" => Some(FactorValue::Boolean(false)),
+                _ => None,
+            },
+            FactorKind::Number => text
+                .replace(',', ".")
+                .parse()
+                .ok()
+                .filter(|number: &f64| number.is_finite())
+                .map(FactorValue::Number),
+            FactorKind::Choice { choices } => choices
+                .iter()
+                .find(|choice| choice.to_lowercase() == text.to_lowercase())
+                .map(|choice| FactorValue::Choice(choice.clone())),
+        };
+
+        value.ok_or_else(|| {
+            Error::InvalidFactor(format!(
+                "\"{}\" is not a value of \"{}\", expected {}",
+                text, self.name, self.kind
+            ))
+        })
+    }
+
+    /// Sets the value of the given date, if the factor accepts it. `None` removes the date's
+    /// value.
+    pub fn set_value(&mut self, date: NaiveDate, value: Option<FactorValue>) {
+        match value {
+            Some(value) if self.accepts(&value) => {
+                self.values_per_date.insert(date, value);
+            }
+            Some(_) => {}
+            None => {
+                self.values_per_date.remove(&date);
+            }
+        }
+    }
+}
+
+// =================================================================================================
+
+/// The kind of a factor's values.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum FactorKind {
+    /// Whether something happened, e.g. alcohol or social contact.
+    Boolean,
+    /// One of the given choices, e.g. the weather.
+    Choice { choices: Vec<String> },
+    /// An amount, e.g. the hours of sleep or the minutes of exercise.
+    Number,
+}
+
+impl Display for FactorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactorKind::Boolean => write!(f, "yes or no"),
+            FactorKind::Choice { choices } => write!(f, "one of {}", choices.join(", ")),
+            FactorKind::Number => write!(f, "a number"),
+        }
+    }
+}
+
+// =================================================================================================
+
+/// A factor's value of a day.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum FactorValue {
+    Boolean(bool),
+    Choice(String),
+    Number(f64),
+}
+
+impl FactorValue {
+    /// Returns the value as a number to correlate it: a boolean is 0 or 1, a choice has none.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            FactorValue::Boolean(value) => Some(if *value { 1.0 } else { 0.0 }),
+            FactorValue::Choice(_) => None,
+            FactorValue::Number(number) => Some(*number),
+        }
+    }
+}
+
+impl Display for FactorValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactorValue::Boolean(true) => write!(f, "yes"),
+            FactorValue::Boolean(false) => write!(f, "no"),
+            FactorValue::Choice(choice) => write!(f, "{}", choice),
+            FactorValue::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns a choice factor of the weather.
+    fn weather() -> Factor {
+        Factor::new(
+            "Weather",
+            FactorKind::Choice {
+                choices: vec!["Sunny".to_string(), "Rainy".to_string()],
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn values_are_parsed_by_the_kind() {
+        let alcohol = Factor::new("Alcohol", FactorKind::Boolean).unwrap();
+        let sleep = Factor::new("Sleep", FactorKind::Number).unwrap();
+
+        assert_eq!(
+            alcohol.parse_value(" Yes ").unwrap(),
+            FactorValue::Boolean(true)
+        );
+        assert_eq!(
+            alcohol.parse_value("0").unwrap(),
+            FactorValue::Boolean(false)
+        );
+        assert_eq!(sleep.parse_value("7,5").unwrap(), FactorValue::Number(7.5));
+        assert_eq!(
+            weather().parse_value("rainy").unwrap(),
+            FactorValue::Choice("Rainy".to_string())
+        );
+    }
+
+    #[test]
+    fn values_not_of_the_kind_are_rejected() {
+        let alcohol = Factor::new("Alcohol", FactorKind::Boolean).unwrap();
+        let sleep = Factor::new("Sleep", FactorKind::Number).unwrap();
+
+        assert!(alcohol.parse_value("maybe").is_err());
+        assert!(sleep.parse_value("seven").is_err());
+        assert!(sleep.parse_value("inf").is_err());
+        assert!(weather().parse_value("Snowy").is_err());
+    }
+
+    #[test]
+    fn a_choice_factor_needs_distinct_choices() {
+        assert_eq!(
+            weather().kind,
+            FactorKind::Choice {
+                choices: vec!["Sunny".to_string(), "Rainy".to_string()]
+            }
+        );
+        assert!(Factor::new(
+            "Weather",
+            FactorKind::Choice {
+                choices: vec!["Sunny".to_string(), " Sunny ".to_string(), "".to_string()]
+            }
+        )
+        .is_err());
+        assert!(Factor::new(" ", FactorKind::Boolean).is_err());
+    }
+
+    #[test]
+    fn only_accepted_values_are_set() {
+        let mut weather = weather();
+
+        weather.set_value(september(1), Some(FactorValue::Choice("Sunny".to_string())));
+        weather.set_value(september(2), Some(FactorValue::Choice("Snowy".to_string())));
+        weather.set_value(september(3), Some(FactorValue::Boolean(true)));
+
+        assert_eq!(weather.values_per_date.len(), 1);
+        assert!(weather.definition().values_per_date.is_empty());
+
+        weather.set_value(september(1), None);
+
+        assert!(weather.values_per_date.is_empty());
+    }
+}
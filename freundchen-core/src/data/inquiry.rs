@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// The number of questions asked about a belief.
+pub const NUMBER_OF_QUESTIONS: usize = 4;
+
+/// An inquiry into a belief, as done in The Work.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Inquiry {
+    /// The belief that was questioned.
+    pub belief: String,
+    /// When the inquiry was completed.
+    pub date_time: NaiveDateTime,
+    /// The answers to the four questions.
+    pub answers: [String; NUMBER_OF_QUESTIONS],
+    /// The turnarounds of the belief.
+    pub turnarounds: Vec<String>,
+}
+
+impl Inquiry {
+    /// Returns whether the inquiry is about the given belief.
+    pub fn is_about(&self, belief: &str) -> bool {
+        self.belief.trim().to_lowercase() == belief.trim().to_lowercase()
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+/// A medication or supplement with its doses over time and the days it was taken on.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Medication {
+    /// The dose from the start date on, e.g. "50 mg".
+    pub dose: String,
+    /// The changed doses from their dates on.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dose_changes: BTreeMap<NaiveDate, String>,
+    /// The dates the medication was taken on.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub intake_dates: BTreeSet<NaiveDate>,
+    pub name: String,
+    pub schedule: Schedule,
+    pub start_date: NaiveDate,
+    /// The first date the medication is not taken anymore, if it was stopped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_date: Option<NaiveDate>,
+}
+
+impl Medication {
+    /// Creates a medication taken from the given date on. The name and the dose must not be empty.
+    pub fn new(
+        name: &str,
+        dose: &str,
+        schedule: Schedule,
+        start_date: NaiveDate,
+    ) -> Result<Self, Error> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(Error::InvalidMedication("The name is empty".to_string()));
+        }
+
+        if let Schedule::EveryNDays { interval: 0 } = schedule {
+            return Err(Error::InvalidMedication(
+                "The interval needs to be at least one day".to_string(),
+            ));
+        }
+
+        Ok(Medication {
+            dose: parse_dose(dose)?,
+            dose_changes: BTreeMap::new(),
+            intake_dates: BTreeSet::new(),
+            name: name.to_string(),
+            schedule,
+            start_date,
+            stop_date: None,
+        })
+    }
+
+    /// Returns the medication without its intakes.
+    pub fn definition(&self) -> Self {
+        Medication {
+            intake_dates: BTreeSet::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Changes the dose from the given date on. The date must be within the time the medication is
+    /// taken, a change on the start date replaces the initial dose.
+    pub fn change_dose(&mut self, date: NaiveDate, dose: &str) -> Result<(), Error> {
+        let dose = parse_dose(dose)?;
+
+        if !self.is_active_on(date) {
+            return Err(Error::InvalidMedication(format!(
+                "\"{}\" is not taken on {}",
+                self.name, date
+            )));
+        }
+
+        if date == self.start_date {
+            self.dose = dose;
+        } else {
+            self.dose_changes.insert(date, dose);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the dates the medication was started, stopped or its dose changed on, in order.
+    pub fn change_dates(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        std::iter::once(self.start_date)
+            .chain(self.dose_changes.keys().copied())
+            .chain(self.stop_date)
+    }
+
+    /// Returns the dose on the given date, if the medication is taken then.
+    pub fn dose_on(&self, date: NaiveDate) -> Option<&str> {
+        if !self.is_active_on(date) {
+            return None;
+        }
+
+        Some(
+            self.dose_changes
+                .range(..=date)
+                .next_back()
+                .map_or(&self.dose, |(_, dose)| dose),
+        )
+    }
+
+    /// Returns whether the medication is taken on the given date: from the start date on and
+    /// before the stop date.
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && self.stop_date.is_none_or(|stop_date| date < stop_date)
+    }
+
+    /// Returns whether the schedule plans an intake on the given date.
+    pub fn is_scheduled_on(&self, date: NaiveDate) -> bool {
+        self.is_active_on(date)
+            && match self.schedule {
+                Schedule::AsNeeded => false,
+                Schedule::Daily => true,
+                Schedule::EveryNDays { interval } => {
+                    (date - self.start_date).num_days() % interval.max(1) as i64 == 0
+                }
+            }
+    }
+
+    /// Records whether the medication was taken on the given date. Dates it is not taken on are
+    /// ignored.
+    pub fn set_intake(&mut self, date: NaiveDate, is_taken: bool) {
+        if !self.is_active_on(date) {
+            return;
+        }
+
+        if is_taken {
+            self.intake_dates.insert(date);
+        } else {
+            self.intake_dates.remove(&date);
+        }
+    }
+
+    /// Stops the medication from the given date on, which must be after the start date. Later dose
+    /// changes are dropped.
+    pub fn stop(&mut self, date: NaiveDate) -> Result<(), Error> {
+        if date <= self.start_date {
+            return Err(Error::InvalidMedication(format!(
+                "\"{}\" was started on {}, it can only be stopped after that",
+                self.name, self.start_date
+            )));
+        }
+
+        self.dose_changes.retain(|dose_date, _| *dose_date < date);
+        self.stop_date = Some(date);
+
+        Ok(())
+    }
+}
+
+/// Trims the dose, which must not be empty.
+fn parse_dose(dose: &str) -> Result<String, Error> {
+    match dose.trim() {
+        "" => Err(Error::InvalidMedication("The dose is empty".to_string())),
+        dose => Ok(dose.to_string()),
+    }
+}
+
+// =================================================================================================
+
+/// When a medication is planned to be taken.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Schedule {
+    /// Only when needed, no day is planned.
+    AsNeeded,
+    Daily,
+    /// Every given number of days from the start date on, e.g. every other day.
+    EveryNDays {
+        interval: u32,
+    },
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Schedule::AsNeeded => write!(f, "as needed"),
+            Schedule::Daily => write!(f, "daily"),
+            Schedule::EveryNDays { interval } => write!(f, "every {} days", interval),
+        }
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns a medication started on the 1st of September 2026.
+    fn medication(schedule: Schedule) -> Medication {
+        Medication::new("Sertraline", "50 mg", schedule, september(1)).unwrap()
+    }
+
+    #[test]
+    fn a_medication_needs_a_name_a_dose_and_an_interval() {
+        assert!(Medication::new(" ", "50 mg", Schedule::Daily, september(1)).is_err());
+        assert!(Medication::new("Sertraline", " ", Schedule::Daily, september(1)).is_err());
+        assert!(Medication::new(
+            "Sertraline",
+            "50 mg",
+            Schedule::EveryNDays { interval: 0 },
+            september(1)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn the_schedule_plans_the_days_from_the_start_date_on() {
+        let every_other_day = medication(Schedule::EveryNDays { interval: 2 });
+        let as_needed = medication(Schedule::AsNeeded);
+
+        assert!(every_other_day.is_scheduled_on(september(1)));
+        assert!(!every_other_day.is_scheduled_on(september(2)));
+        assert!(every_other_day.is_scheduled_on(september(3)));
+        assert!(!every_other_day.is_scheduled_on(NaiveDate::from_ymd_opt(2026, 8, 30).unwrap()));
+        assert!(!as_needed.is_scheduled_on(september(1)));
+    }
+
+    #[test]
+    fn the_dose_changes_from_its_date_on_until_the_stop() {
+        let mut medication = medication(Schedule::Daily);
+        medication.change_dose(september(10), "100 mg").unwrap();
+        medication.change_dose(september(20), "150 mg").unwrap();
+        medication.stop(september(15)).unwrap();
+
+        assert_eq!(medication.dose_on(september(9)), Some("50 mg"));
+        assert_eq!(medication.dose_on(september(14)), Some("100 mg"));
+        assert_eq!(medication.dose_on(september(15)), None);
+        // The change after the stop is dropped.
+        assert_eq!(
+            medication.change_dates().collect::<Vec<_>>(),
+            vec![september(1), september(10), september(15)]
+        );
+        assert!(medication.change_dose(september(16), "50 mg").is_err());
+        assert!(medication.stop(september(1)).is_err());
+    }
+
+    #[test]
+    fn intakes_are_only_recorded_while_the_medication_is_taken() {
+        let mut medication = medication(Schedule::Daily);
+        medication.stop(september(3)).unwrap();
+
+        for day in 1..=3 {
+            medication.set_intake(september(day), true);
+        }
+        medication.set_intake(september(2), false);
+
+        assert_eq!(medication.intake_dates, BTreeSet::from([september(1)]));
+    }
+}
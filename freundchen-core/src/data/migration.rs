@@ -0,0 +1,248 @@
+use crate::mood::number_of_days_in_month;
+use chrono::{Datelike, Months, NaiveDate};
+use serde_json::{Map, Value};
+use tracing::{info, warn};
+
+/// The number of day of month indices of version 0, one per column of the mood widget.
+const NUMBER_OF_VERSION_0_DAYS: u32 = 31;
+
+/// The version of the application data format that is written.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Migrates the application data from the version at its index to the next version.
+type Migration = fn(&mut Map<String, Value>, NaiveDate);
+
+/// The migrations, starting at version 0.
+const MIGRATIONS: [Migration; CURRENT_VERSION as usize] = [migrate_from_version_0];
+
+/// Returns the version of the given serialized application data. Data without a version was
+/// written before versioning was introduced and has version 0.
+pub(crate) fn data_version(application_data: &Value) -> u32 {
+    application_data
+        .get("version")
+        .and_then(Value::as_u64)
+        .map_or(0, |version| version.min(u32::MAX as u64) as u32)
+}
+
+/// Migrates the serialized application data to the current version. Fields that are not known
+/// to a migration are kept as they are. Data of newer versions is expected to be rejected before.
+pub(crate) fn migrate(
+    application_data: Value,
+    today: NaiveDate,
+) -> Result<Value, serde_json::Error> {
+    let version = data_version(&application_data).min(CURRENT_VERSION);
+
+    let mut application_data: Map<String, Value> = serde_json::from_value(application_data)?;
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut application_data, today);
+    }
+
+    if version < CURRENT_VERSION {
+        info!(
+            from_version = version,
+            to_version = CURRENT_VERSION,
+            "Migrated the data"
+        );
+    }
+
+    application_data.insert("version".to_string(), CURRENT_VERSION.into());
+
+    Ok(Value::Object(application_data))
+}
+
+/// Version 0 stored the mood values per day of month index in `mood_per_day`. Version 1 stores
+/// them per date in `mood_per_date`. The days of month are assigned to the latest month up to the
+/// current one that has all of them, e.g. the 31st day to August in September. Invalid indices are
+/// dropped and counted in the log.
+fn migrate_from_version_0(application_data: &mut Map<String, Value>, today: NaiveDate) {
+    let Some(Value::Object(mood_per_day)) = application_data.remove("mood_per_day") else {
+        return;
+    };
+
+    let Value::Object(mood_per_date) = application_data
+        .entry("mood_per_date")
+        .or_insert_with(|| Value::Object(Map::new()))
+    else {
+        return;
+    };
+
+    let mut mood_per_day_of_month_index = vec![];
+    let mut number_of_dropped_days = 0;
+
+    for (day_of_month_index, mood_values) in mood_per_day {
+        match day_of_month_index.parse::<u32>() {
+            Ok(day_of_month_index) if day_of_month_index < NUMBER_OF_VERSION_0_DAYS => {
+                mood_per_day_of_month_index.push((day_of_month_index, mood_values))
+            }
+            _ => number_of_dropped_days += 1,
+        }
+    }
+
+    if number_of_dropped_days > 0 {
+        warn!(
+            number_of_dropped_days,
+            "Dropped days with an invalid day of month while migrating the data"
+        );
+    }
+
+    let Some(last_day_of_month_index) = mood_per_day_of_month_index
+        .iter()
+        .map(|(day_of_month_index, _)| *day_of_month_index)
+        .max()
+    else {
+        return;
+    };
+
+    // Go back to a month that has all the days. Every second month has 31 days.
+    let mut month = today.with_day(1).unwrap_or(today);
+
+    while (number_of_days_in_month(month) as u32) <= last_day_of_month_index {
+        match month.checked_sub_months(Months::new(1)) {
+            Some(previous_month) => month = previous_month,
+            None => return,
+        }
+    }
+
+    if month.month() != today.month() || month.year() != today.year() {
+        info!(
+            month = %month.format("%Y-%m"),
+            "Assigned the days of the data to an earlier month, since the current one is shorter"
+        );
+    }
+
+    for (day_of_month_index, mood_values) in mood_per_day_of_month_index {
+        if let Some(date) = month.with_day0(day_of_month_index) {
+            mood_per_date.entry(date.to_string()).or_insert(mood_values);
+        }
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ApplicationData;
+    use serde_json::json;
+    use std::collections::HashSet;
+    use std::env;
+    use std::fs;
+
+    /// Returns the given date.
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Migrates the given serialized data and deserializes it.
+    fn migrated(application_data: Value, today: NaiveDate) -> ApplicationData {
+        serde_json::from_value(migrate(application_data, today).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn version_0_days_are_assigned_to_the_current_month() {
+        let application_data = migrated(
+            json!({ "mood_per_day": { "0": [3], "29": [7, 8] } }),
+            date(2026, 9, 15),
+        );
+
+        assert_eq!(application_data.version, CURRENT_VERSION);
+        assert_eq!(
+            application_data.mood_per_date.get(&date(2026, 9, 1)),
+            Some(&HashSet::from([3]))
+        );
+        assert_eq!(
+            application_data.mood_per_date.get(&date(2026, 9, 30)),
+            Some(&HashSet::from([7, 8]))
+        );
+    }
+
+    #[test]
+    fn version_0_days_missing_in_the_current_month_go_to_an_earlier_month() {
+        // September has no 31st day, so all days go to August.
+        let application_data = migrated(
+            json!({ "mood_per_day": { "0": [1], "30": [2] } }),
+            date(2026, 9, 15),
+        );
+
+        assert_eq!(
+            application_data
+                .mood_per_date
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![date(2026, 8, 1), date(2026, 8, 31)]
+        );
+
+        // February has neither a 30th nor a 31st day and January has both.
+        let application_data =
+            migrated(json!({ "mood_per_day": { "29": [4] } }), date(2026, 2, 10));
+
+        assert_eq!(
+            application_data
+                .mood_per_date
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![date(2026, 1, 30)]
+        );
+    }
+
+    #[test]
+    fn version_0_days_with_an_invalid_index_are_dropped() {
+        let application_data = migrated(
+            json!({ "mood_per_day": { "31": [1], "x": [2], "4": [5] } }),
+            date(2026, 9, 15),
+        );
+
+        assert_eq!(
+            application_data
+                .mood_per_date
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![date(2026, 9, 5)]
+        );
+    }
+
+    #[test]
+    fn version_0_migration_keeps_the_existing_dates_and_unknown_fields() {
+        let migrated_value = migrate(
+            json!({
+                "mood_per_day": { "0": [1] },
+                "mood_per_date": { "2026-09-01": [9] },
+                "future_field": true
+            }),
+            date(2026, 9, 15),
+        )
+        .unwrap();
+
+        assert_eq!(migrated_value["mood_per_date"]["2026-09-01"], json!([9]));
+        assert_eq!(migrated_value["future_field"], json!(true));
+        assert_eq!(migrated_value["version"], json!(CURRENT_VERSION));
+        assert!(migrated_value.get("mood_per_day").is_none());
+    }
+
+    #[test]
+    fn data_without_a_version_has_version_0() {
+        assert_eq!(data_version(&json!({})), 0);
+        assert_eq!(data_version(&json!({ "version": 1 })), 1);
+    }
+
+    #[test]
+    fn loading_version_0_backs_up_the_original() {
+        let directory =
+            env::temp_dir().join(format!("freundchen-migration-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("freundchen.json");
+        let original = r#"{"mood_per_day":{"0":[3]}}"#;
+        fs::write(&path, original).unwrap();
+
+        let application_data = ApplicationData::load(&path, date(2026, 9, 15)).unwrap();
+        let backup = fs::read_to_string(directory.join("freundchen.v0.backup.json"));
+        let _ = fs::remove_dir_all(&directory);
+
+        assert_eq!(application_data.mood_per_date.len(), 1);
+        assert_eq!(backup.unwrap(), original);
+    }
+}
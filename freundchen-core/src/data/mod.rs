@@ -0,0 +1,561 @@
+mod bulk_edit;
+mod dimension;
+mod factor;
+mod inquiry;
+mod medication;
+mod migration;
+mod quote;
+mod recovery;
+mod sleep;
+mod thought_record;
+
+use crate::feelings::{is_feeling, tag_matches};
+use crate::mood::{MoodValue, NUMBER_OF_MOOD_VALUES_PER_DAY};
+use crate::Error;
+pub use bulk_edit::BulkEdit;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+pub use dimension::{Dimension, Track, MINIMUM_NUMBER_OF_DIMENSION_VALUES};
+pub use factor::{Factor, FactorKind, FactorValue, MINIMUM_NUMBER_OF_CHOICES};
+pub use inquiry::{Inquiry, NUMBER_OF_QUESTIONS};
+pub use medication::{Medication, Schedule};
+pub use migration::CURRENT_VERSION;
+use migration::{data_version, migrate};
+pub use quote::{import_quotes, parse_tags, Quote};
+use recovery::recover;
+pub use recovery::Recovery;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+pub use sleep::{night_duration, SleepEntry, MAXIMUM_SLEEP_QUALITY};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs;
+use std::path::Path;
+pub use thought_record::{ThoughtRecord, MAXIMUM_EMOTION_INTENSITY};
+
+/// The application data.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ApplicationData {
+    /// The user-defined dimensions tracked besides the mood.
+    #[serde(default)]
+    pub dimensions: Vec<Dimension>,
+    /// The user-defined factors recorded per day, e.g. the hours of sleep.
+    #[serde(default)]
+    pub factors: Vec<Factor>,
+    #[serde(default)]
+    pub inquiries: Vec<Inquiry>,
+    /// The medications and supplements with their doses and intakes.
+    #[serde(default)]
+    pub medications: Vec<Medication>,
+    #[serde(default)]
+    pub mood_per_date: BTreeMap<NaiveDate, HashSet<u8>>,
+    /// The notes per date, e.g. added to a range of days.
+    #[serde(default)]
+    pub note_per_date: BTreeMap<NaiveDate, String>,
+    #[serde(default)]
+    pub quotes: Vec<Quote>,
+    /// The sleep per date of the day before the night.
+    #[serde(default)]
+    pub sleep_per_date: BTreeMap<NaiveDate, SleepEntry>,
+    /// The feelings and custom tags per date.
+    #[serde(default)]
+    pub tags_per_date: BTreeMap<NaiveDate, BTreeSet<String>>,
+    #[serde(default)]
+    pub thought_records: Vec<ThoughtRecord>,
+    /// The fields this version does not know, e.g. written by a newer version. They are kept, so
+    /// that saving does not lose them.
+    #[serde(flatten)]
+    pub unknown_fields: Map<String, Value>,
+    /// The version of the data format, see `migration::CURRENT_VERSION`.
+    version: u32,
+}
+
+impl Default for ApplicationData {
+    fn default() -> Self {
+        ApplicationData::new()
+    }
+}
+
+impl ApplicationData {
+    pub fn new() -> Self {
+        ApplicationData {
+            dimensions: vec![],
+            factors: vec![],
+            inquiries: vec![],
+            medications: vec![],
+            mood_per_date: BTreeMap::new(),
+            note_per_date: BTreeMap::new(),
+            quotes: vec![],
+            sleep_per_date: BTreeMap::new(),
+            tags_per_date: BTreeMap::new(),
+            thought_records: vec![],
+            unknown_fields: Map::new(),
+            version: CURRENT_VERSION,
+        }
+    }
+
+    /// Loads the application data from the given path and migrates it to the current version.
+    /// If the file does not exist, empty application data is returned. Data written by an older
+    /// version is backed up before it is migrated, data written by a newer version is backed up and
+    /// rejected.
+    pub fn load(path: &Path, today: NaiveDate) -> Result<Self, Error> {
+        // The application data file does not exist.
+        if !path.exists() {
+            return Ok(ApplicationData::new());
+        }
+
+        let corrupt_data = |error| Error::CorruptData {
+            error,
+            path: path.to_path_buf(),
+        };
+
+        let file_content = fs::read(path)?;
+        let application_data: Value =
+            serde_json::from_slice(&file_content).map_err(corrupt_data)?;
+        let version = data_version(&application_data);
+
+        // The data was written by a newer version of the application.
+        if version > CURRENT_VERSION {
+            let backup_path = path.with_extension(format!("v{}.backup.json", version));
+            fs::copy(path, &backup_path)?;
+
+            return Err(Error::NewerDataVersion {
+                backup_path,
+                supported_version: CURRENT_VERSION,
+                version,
+            });
+        }
+
+        // Deserialize the current version from the file content, so that errors have a position.
+        if version == CURRENT_VERSION {
+            return serde_json::from_slice(&file_content).map_err(corrupt_data);
+        }
+
+        // Keep the original, since the migrated data overwrites it with the next save.
+        fs::copy(
+            path,
+            path.with_extension(format!("v{}.backup.json", version)),
+        )?;
+
+        migrate(application_data, today)
+            .and_then(serde_json::from_value)
+            .map_err(corrupt_data)
+    }
+
+    /// Loads the application data like `load`, but recovers from a corrupt file: it is moved
+    /// aside with the given time in its name and the readable entries are kept.
+    pub fn load_or_recover(
+        path: &Path,
+        now: NaiveDateTime,
+    ) -> Result<(Self, Option<Recovery>), Error> {
+        match ApplicationData::load(path, now.date()) {
+            Err(error @ Error::CorruptData { .. }) => {
+                let backup_path =
+                    path.with_extension(format!("corrupt-{}.json", now.format("%Y%m%d-%H%M%S")));
+                fs::rename(path, &backup_path)?;
+
+                let application_data = recover(
+                    &String::from_utf8_lossy(&fs::read(&backup_path)?),
+                    now.date(),
+                );
+
+                let recovery = Recovery {
+                    backup_path,
+                    number_of_recovered_days: application_data.mood_per_date.len(),
+                    reason: error.to_string(),
+                };
+
+                Ok((application_data, Some(recovery)))
+            }
+            result => result.map(|application_data| (application_data, None)),
+        }
+    }
+
+    /// Removes the track's values of the given date's month.
+    pub fn clear_mood_values_in_month(&mut self, track: Track, date: NaiveDate) {
+        if let Some(values_per_date) = self.values_per_date_mut(track) {
+            values_per_date.retain(|mood_date, _| {
+                mood_date.year() != date.year() || mood_date.month() != date.month()
+            });
+        }
+    }
+
+    /// Returns the track's values of the given date's month per day of month index.
+    pub fn mood_values_in_month(
+        &self,
+        track: Track,
+        date: NaiveDate,
+    ) -> impl Iterator<Item = (u8, &HashSet<u8>)> {
+        self.values_per_date(track)
+            .into_iter()
+            .flatten()
+            .filter(move |(mood_date, _)| {
+                mood_date.year() == date.year() && mood_date.month() == date.month()
+            })
+            .map(|(mood_date, mood_values)| (mood_date.day0() as u8, mood_values))
+    }
+
+    /// Applies the edit to the days from `first_date` to `last_date`, inclusively. The mood values
+    /// are edited in the given track. Values a factor does not accept are ignored.
+    pub fn edit_days(
+        &mut self,
+        track: Track,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+        bulk_edit: &BulkEdit,
+    ) {
+        for date in first_date.iter_days().take_while(|date| *date <= last_date) {
+            match bulk_edit {
+                BulkEdit::AddNote(note) => {
+                    let note = note.trim();
+
+                    if note.is_empty() {
+                        continue;
+                    }
+
+                    self.note_per_date
+                        .entry(date)
+                        .and_modify(|existing_note| {
+                            existing_note.push('\n');
+                            existing_note.push_str(note);
+                        })
+                        .or_insert_with(|| note.to_string());
+                }
+                BulkEdit::ClearMoodValues => self.set_mood_values(track, date, HashSet::new()),
+                BulkEdit::SetFactorValue {
+                    factor_index,
+                    value,
+                } => {
+                    if let Some(factor) = self.factors.get_mut(*factor_index) {
+                        factor.set_value(date, value.clone());
+                    }
+                }
+                BulkEdit::SetMoodValues(mood_values) => {
+                    self.set_mood_values(track, date, mood_values.clone())
+                }
+            }
+        }
+    }
+
+    /// Returns the number of values of the track's scale. A removed dimension has none.
+    pub fn number_of_values(&self, track: Track) -> MoodValue {
+        match track {
+            Track::Dimension(index) => self.dimensions.get(index).map_or(0, |dimension| {
+                dimension
+                    .number_of_values
+                    .min(NUMBER_OF_MOOD_VALUES_PER_DAY)
+            }),
+            Track::Mood => NUMBER_OF_MOOD_VALUES_PER_DAY,
+        }
+    }
+
+    /// Sets the track's values of the given date. Values outside the track's scale are left out
+    /// and empty values remove the date's entry.
+    pub fn set_mood_values(&mut self, track: Track, date: NaiveDate, mood_values: HashSet<u8>) {
+        let number_of_values = self.number_of_values(track);
+
+        let Some(values_per_date) = self.values_per_date_mut(track) else {
+            return;
+        };
+
+        let mood_values: HashSet<u8> = mood_values
+            .into_iter()
+            .filter(|mood_value| *mood_value < number_of_values)
+            .collect();
+
+        if mood_values.is_empty() {
+            values_per_date.remove(&date);
+        } else {
+            values_per_date.insert(date, mood_values);
+        }
+    }
+
+    /// Sets the given value of the given date in the track, if it is not set yet, and unsets it
+    /// otherwise. Values outside the track's scale are ignored.
+    pub fn toggle_mood_value(&mut self, track: Track, date: NaiveDate, mood_value: MoodValue) {
+        let mut mood_values = self
+            .values_per_date(track)
+            .and_then(|values_per_date| values_per_date.get(&date))
+            .cloned()
+            .unwrap_or_default();
+
+        if !mood_values.remove(&mood_value) {
+            mood_values.insert(mood_value);
+        }
+
+        self.set_mood_values(track, date, mood_values);
+    }
+
+    /// Returns the custom tags, which are the used tags that are not feelings of the wheel.
+    pub fn custom_tags(&self) -> BTreeSet<&str> {
+        self.tags_per_date
+            .values()
+            .flatten()
+            .map(String::as_str)
+            .filter(|tag| !is_feeling(tag))
+            .collect()
+    }
+
+    /// Returns the dates with a tag matching the filter tag, see `feelings::tag_matches`.
+    pub fn dates_with_tag<'a>(
+        &'a self,
+        filter_tag: &'a str,
+    ) -> impl Iterator<Item = NaiveDate> + 'a {
+        self.tags_per_date
+            .iter()
+            .filter(move |(_, tags)| tags.iter().any(|tag| tag_matches(tag, filter_tag)))
+            .map(|(date, _)| *date)
+    }
+
+    /// Tags the given date with the tag, if it is not tagged with it yet, and removes the tag
+    /// otherwise. Empty tags are ignored.
+    pub fn toggle_tag(&mut self, date: NaiveDate, tag: &str) {
+        let tag = tag.trim();
+
+        if tag.is_empty() {
+            return;
+        }
+
+        let tags = self.tags_per_date.entry(date).or_default();
+
+        if !tags.remove(tag) {
+            tags.insert(tag.to_string());
+        }
+
+        if tags.is_empty() {
+            self.tags_per_date.remove(&date);
+        }
+    }
+
+    /// Returns the mood and the dimensions.
+    pub fn tracks(&self) -> impl Iterator<Item = Track> {
+        std::iter::once(Track::Mood).chain((0..self.dimensions.len()).map(Track::Dimension))
+    }
+
+    /// Returns the track's values per date. A removed dimension has none.
+    pub fn values_per_date(&self, track: Track) -> Option<&BTreeMap<NaiveDate, HashSet<u8>>> {
+        match track {
+            Track::Dimension(index) => self
+                .dimensions
+                .get(index)
+                .map(|dimension| &dimension.values_per_date),
+            Track::Mood => Some(&self.mood_per_date),
+        }
+    }
+
+    /// Returns the track's values per date to change them. A removed dimension has none.
+    fn values_per_date_mut(
+        &mut self,
+        track: Track,
+    ) -> Option<&mut BTreeMap<NaiveDate, HashSet<u8>>> {
+        match track {
+            Track::Dimension(index) => self
+                .dimensions
+                .get_mut(index)
+                .map(|dimension| &mut dimension.values_per_date),
+            Track::Mood => Some(&mut self.mood_per_date),
+        }
+    }
+
+    /// Returns the distinct beliefs that were inquired into, in the order of their first inquiry.
+    pub fn inquired_beliefs(&self) -> Vec<&str> {
+        let mut beliefs: Vec<&str> = vec![];
+
+        for inquiry in &self.inquiries {
+            if !beliefs.iter().any(|belief| inquiry.is_about(belief)) {
+                beliefs.push(inquiry.belief.trim());
+            }
+        }
+
+        beliefs
+    }
+
+    /// Returns the quote of the given day. The choice only depends on the date and the quotes, so
+    /// that it is stable across restarts.
+    pub fn quote_of_the_day(&self, date: NaiveDate) -> Option<&Quote> {
+        if self.quotes.is_empty() {
+            return None;
+        }
+
+        self.quotes
+            .get(date.num_days_from_ce().unsigned_abs() as usize % self.quotes.len())
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistics::{tag_frequencies, Correlation};
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns data with the dimension "Anxiety" of five values.
+    fn application_data() -> ApplicationData {
+        let mut application_data = ApplicationData::new();
+        application_data
+            .dimensions
+            .push(Dimension::new("Anxiety", 5, vec![]).unwrap());
+
+        application_data
+    }
+
+    #[test]
+    fn a_dimensions_values_are_kept_within_its_scale() {
+        let mut application_data = application_data();
+        let anxiety = Track::Dimension(0);
+
+        application_data.set_mood_values(anxiety, september(1), [2, 4, 7].into());
+        application_data.toggle_mood_value(anxiety, september(2), 5);
+        application_data.toggle_mood_value(anxiety, september(3), 1);
+
+        assert_eq!(application_data.number_of_values(anxiety), 5);
+        assert_eq!(
+            application_data.dimensions[0].values_per_date,
+            BTreeMap::from([
+                (september(1), HashSet::from([2, 4])),
+                (september(3), HashSet::from([1])),
+            ])
+        );
+        assert!(application_data.mood_per_date.is_empty());
+    }
+
+    #[test]
+    fn a_removed_dimension_has_no_values() {
+        let mut application_data = application_data();
+        let removed_dimension = Track::Dimension(1);
+
+        application_data.set_mood_values(removed_dimension, september(1), [2].into());
+
+        assert_eq!(application_data.number_of_values(removed_dimension), 0);
+        assert_eq!(application_data.values_per_date(removed_dimension), None);
+        assert_eq!(
+            application_data.tracks().collect::<Vec<_>>(),
+            vec![Track::Mood, Track::Dimension(0)]
+        );
+    }
+
+    #[test]
+    fn the_mood_and_a_dimension_are_correlated_on_days_with_both() {
+        let mut application_data = application_data();
+
+        for (day, mood_value, anxiety_value) in [(1, 8, 0), (2, 6, 1), (3, 4, 2), (4, 2, 3)] {
+            application_data.set_mood_values(Track::Mood, september(day), [mood_value].into());
+            application_data.set_mood_values(
+                Track::Dimension(0),
+                september(day),
+                [anxiety_value].into(),
+            );
+        }
+        application_data.set_mood_values(Track::Mood, september(5), [10].into());
+
+        let correlation = Correlation::new(
+            &application_data.mood_per_date,
+            &application_data.dimensions[0].values_per_date,
+            september(1),
+            september(30),
+        )
+        .unwrap();
+
+        assert_eq!(correlation.number_of_days, 4);
+        assert!((correlation.coefficient + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn toggling_a_tag_adds_and_removes_it() {
+        let mut application_data = ApplicationData::new();
+
+        application_data.toggle_tag(september(1), " anxious ");
+        application_data.toggle_tag(september(1), "after the concert");
+        application_data.toggle_tag(september(2), "joy");
+        application_data.toggle_tag(september(2), "joy");
+        application_data.toggle_tag(september(3), " ");
+
+        assert_eq!(
+            application_data.tags_per_date,
+            BTreeMap::from([(
+                september(1),
+                BTreeSet::from(["after the concert".to_string(), "anxious".to_string()])
+            )])
+        );
+        assert_eq!(
+            application_data.custom_tags(),
+            BTreeSet::from(["after the concert"])
+        );
+    }
+
+    #[test]
+    fn the_dates_with_a_tag_include_the_nuances_of_a_core_feeling() {
+        let mut application_data = ApplicationData::new();
+        application_data.toggle_tag(september(1), "anxious");
+        application_data.toggle_tag(september(2), "fear");
+        application_data.toggle_tag(september(3), "joy");
+
+        assert_eq!(
+            application_data.dates_with_tag("fear").collect::<Vec<_>>(),
+            vec![september(1), september(2)]
+        );
+        assert_eq!(
+            application_data
+                .dates_with_tag("anxious")
+                .collect::<Vec<_>>(),
+            vec![september(1)]
+        );
+    }
+
+    #[test]
+    fn the_tags_are_counted_the_most_frequent_first() {
+        let mut application_data = ApplicationData::new();
+        application_data.toggle_tag(september(1), "joy");
+        application_data.toggle_tag(september(2), "joy");
+        application_data.toggle_tag(september(2), "tired");
+        application_data.toggle_tag(september(30), "joy");
+
+        assert_eq!(
+            tag_frequencies(&application_data.tags_per_date, september(1), september(29)),
+            vec![("joy", 2), ("tired", 1)]
+        );
+    }
+
+    #[test]
+    fn a_factor_value_is_set_for_a_range_of_days() {
+        let mut application_data = ApplicationData::new();
+        application_data
+            .factors
+            .push(Factor::new("Alcohol", FactorKind::Boolean).unwrap());
+
+        for value in [
+            Some(FactorValue::Boolean(true)),
+            Some(FactorValue::Number(1.0)),
+        ] {
+            application_data.edit_days(
+                Track::Mood,
+                september(1),
+                september(3),
+                &BulkEdit::SetFactorValue {
+                    factor_index: 0,
+                    value,
+                },
+            );
+        }
+        application_data.edit_days(
+            Track::Mood,
+            september(2),
+            september(2),
+            &BulkEdit::SetFactorValue {
+                factor_index: 0,
+                value: None,
+            },
+        );
+
+        assert_eq!(
+            application_data.factors[0].values_per_date,
+            BTreeMap::from([
+                (september(1), FactorValue::Boolean(true)),
+                (september(3), FactorValue::Boolean(true)),
+            ])
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+/// An inspiring quote.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Quote {
+    pub text: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub is_favourite: bool,
+}
+
+impl Quote {
+    /// Parses a quote from a line like `Text — Author`.
+    fn from_line(line: &str) -> Option<Self> {
+        let line = line.trim();
+
+        // Skip empty lines and comments.
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        // Split off the author, if given.
+        let (text, author) = ["—", " - "]
+            .iter()
+            .find_map(|separator| line.rsplit_once(separator))
+            .unwrap_or((line, ""));
+
+        Some(Quote {
+            text: text.trim().trim_matches('"').to_string(),
+            author: author.trim().to_string(),
+            tags: vec![],
+            is_favourite: false,
+        })
+    }
+}
+
+impl Display for Quote {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.author.is_empty() {
+            write!(f, "\"{}\"", self.text)
+        } else {
+            write!(f, "\"{}\" — {}", self.text, self.author)
+        }
+    }
+}
+
+/// Parses the given tags, separated by commas.
+pub fn parse_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// Imports quotes from a JSON file with a list of quotes, or from a plain text file with a quote
+/// per line.
+pub fn import_quotes(path: &Path) -> Result<Vec<Quote>, Error> {
+    let file_content = fs::read_to_string(path)?;
+
+    // The file is a JSON file.
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+    {
+        return Ok(serde_json::from_str(&file_content)?);
+    }
+
+    Ok(file_content.lines().filter_map(Quote::from_line).collect())
+}
@@ -0,0 +1,270 @@
+use crate::data::migration::migrate;
+use crate::data::ApplicationData;
+use crate::mood::NUMBER_OF_MOOD_VALUES_PER_DAY;
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+/// What happened to a corrupt application data file.
+#[derive(Clone, Debug)]
+pub struct Recovery {
+    /// The path the corrupt file was moved to.
+    pub backup_path: PathBuf,
+    /// The number of days with mood values that could be recovered.
+    pub number_of_recovered_days: usize,
+    /// Why the file could not be loaded.
+    pub reason: String,
+}
+
+/// Recovers what can be read from the content of a corrupt application data file.
+pub(crate) fn recover(file_content: &str, today: NaiveDate) -> ApplicationData {
+    let mut application_data = ApplicationData::new();
+
+    match serde_json::from_str(file_content).and_then(|value| migrate(value, today)) {
+        // The JSON is valid, but some entries are not. Keep every entry that can be read.
+        Ok(Value::Object(fields)) => {
+            for (name, value) in fields {
+                match (name.as_str(), value) {
+                    ("dimensions", Value::Array(values)) => {
+                        application_data.dimensions = salvage(values);
+
+                        // Drop the values outside of each dimension's scale.
+                        for dimension in &mut application_data.dimensions {
+                            dimension.values_per_date.retain(|_, values| {
+                                values.retain(|value| *value < dimension.number_of_values);
+                                !values.is_empty()
+                            });
+                        }
+                    }
+                    ("factors", Value::Array(values)) => application_data.factors = salvage(values),
+                    ("inquiries", Value::Array(values)) => {
+                        application_data.inquiries = salvage(values)
+                    }
+                    ("medications", Value::Array(values)) => {
+                        application_data.medications = salvage(values)
+                    }
+                    ("mood_per_date", Value::Object(values)) => {
+                        application_data.mood_per_date = salvage_per_date(values)
+                            .into_iter()
+                            .filter_map(|(date, mood_values)| {
+                                Some((date, valid_mood_values(mood_values)?))
+                            })
+                            .collect()
+                    }
+                    ("note_per_date", Value::Object(values)) => {
+                        application_data.note_per_date = salvage_per_date(values)
+                    }
+                    ("quotes", Value::Array(values)) => application_data.quotes = salvage(values),
+                    ("sleep_per_date", Value::Object(values)) => {
+                        application_data.sleep_per_date = salvage_per_date(values)
+                    }
+                    ("tags_per_date", Value::Object(values)) => {
+                        application_data.tags_per_date = salvage_per_date(values)
+                    }
+                    ("thought_records", Value::Array(values)) => {
+                        application_data.thought_records = salvage(values)
+                    }
+                    (
+                        "dimensions" | "factors" | "inquiries" | "medications" | "mood_per_date"
+                        | "note_per_date" | "quotes" | "sleep_per_date" | "tags_per_date"
+                        | "thought_records" | "version",
+                        _,
+                    ) => {}
+                    (_, value) => {
+                        application_data.unknown_fields.insert(name, value);
+                    }
+                }
+            }
+        }
+        // The JSON itself is broken, e.g. truncated. Look for day entries in the text.
+        _ => application_data.mood_per_date = scan_mood_per_date(file_content),
+    }
+
+    application_data
+}
+
+/// Deserializes the given values, skipping those that cannot be read.
+fn salvage<T: DeserializeOwned>(values: Vec<Value>) -> Vec<T> {
+    values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value(value).ok())
+        .collect()
+}
+
+/// Deserializes the given values per date, skipping those that cannot be read.
+fn salvage_per_date<T: DeserializeOwned>(values: Map<String, Value>) -> BTreeMap<NaiveDate, T> {
+    values
+        .into_iter()
+        .filter_map(|(date, value)| Some((date.parse().ok()?, serde_json::from_value(value).ok()?)))
+        .collect()
+}
+
+/// Returns the mood values within the mood's scale, if there are any.
+fn valid_mood_values(mood_values: HashSet<u8>) -> Option<HashSet<u8>> {
+    let mood_values: HashSet<u8> = mood_values
+        .into_iter()
+        .filter(|mood_value| *mood_value < NUMBER_OF_MOOD_VALUES_PER_DAY)
+        .collect();
+
+    (!mood_values.is_empty()).then_some(mood_values)
+}
+
+/// Finds day entries like `"2026-09-15": [3, 4]` in the `"mood_per_date"` object of the given
+/// text, so that the values of other fields, e.g. of the dimensions, are not taken for moods.
+fn scan_mood_per_date(text: &str) -> BTreeMap<NaiveDate, HashSet<u8>> {
+    let mut mood_per_date = BTreeMap::new();
+
+    let Some((_, mut rest)) = text.split_once(r#""mood_per_date""#) else {
+        return mood_per_date;
+    };
+
+    // Iterate over the quoted strings up to the end of the object. The entries contain no braces.
+    while let Some(start) = rest.find(['"', '}']) {
+        if rest[start..].starts_with('}') {
+            break;
+        }
+
+        rest = &rest[start + 1..];
+
+        let Some(end) = rest.find('"') else {
+            break;
+        };
+
+        let key = &rest[..end];
+        rest = &rest[end + 1..];
+
+        // The string is a date, followed by a colon and a list.
+        if let (Ok(date), Some(list)) = (
+            key.parse::<NaiveDate>(),
+            rest.trim_start()
+                .strip_prefix(':')
+                .map(str::trim_start)
+                .filter(|list| list.starts_with('[')),
+        ) {
+            if let Some(mood_values) = list
+                .find(']')
+                .and_then(|list_end| serde_json::from_str::<HashSet<u8>>(&list[..=list_end]).ok())
+                .and_then(valid_mood_values)
+            {
+                mood_per_date.entry(date).or_insert(mood_values);
+            }
+        }
+    }
+
+    mood_per_date
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use std::env;
+    use std::fs;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    #[test]
+    fn the_readable_entries_of_valid_json_are_kept() {
+        let application_data = recover(
+            r#"{
+                "version": 1,
+                "mood_per_date": { "2026-09-01": [3], "2026-09-02": "broken", "not a date": [4] },
+                "quotes": [{ "text": "Be kind." }, 42],
+                "future_field": [1, 2]
+            }"#,
+            september(15),
+        );
+
+        assert_eq!(
+            application_data.mood_per_date,
+            BTreeMap::from([(september(1), HashSet::from([3]))])
+        );
+        assert_eq!(application_data.quotes.len(), 1);
+        assert!(application_data.unknown_fields.contains_key("future_field"));
+    }
+
+    #[test]
+    fn values_outside_of_the_scales_are_dropped() {
+        let application_data = recover(
+            r#"{
+                "version": 1,
+                "mood_per_date": { "2026-09-01": [3, 11, 200], "2026-09-02": [99] },
+                "dimensions": [{
+                    "name": "Energy",
+                    "number_of_values": 3,
+                    "values_per_date": { "2026-09-01": [2, 3], "2026-09-02": [5] }
+                }]
+            }"#,
+            september(15),
+        );
+
+        assert_eq!(
+            application_data.mood_per_date,
+            BTreeMap::from([(september(1), HashSet::from([3]))])
+        );
+        assert_eq!(
+            application_data.dimensions[0].values_per_date,
+            BTreeMap::from([(september(1), HashSet::from([2]))])
+        );
+    }
+
+    #[test]
+    fn the_mood_values_of_truncated_json_are_scanned() {
+        let application_data = recover(
+            r#"{"dimensions":[{"name":"Energy","number_of_values":3,"values_per_date":{"2026-09-01":[2]}}],"mood_per_date":{"2026-09-02":[5,6],"2026-09-03":[1,x],"2026-09-04":[12],"2026-09-05":[7,12]},"tags_per_date":{"2026-09-06":[1]},"thought_reco"#,
+            september(15),
+        );
+
+        // The dimension's and the tags' dates are left out, as are the values outside the scale.
+        assert_eq!(
+            application_data.mood_per_date,
+            BTreeMap::from([
+                (september(2), HashSet::from([5, 6])),
+                (september(5), HashSet::from([7])),
+            ])
+        );
+    }
+
+    #[test]
+    fn truncated_json_without_mood_values_recovers_nothing() {
+        assert!(scan_mood_per_date(r#"{"quotes":[{"text":"2026-09-01"}],"mood_per"#).is_empty());
+    }
+
+    #[test]
+    fn a_corrupt_file_is_moved_aside_and_recovered() {
+        let directory = env::temp_dir().join(format!("freundchen-recovery-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("freundchen.json");
+        fs::write(
+            &path,
+            r#"{"version":1,"mood_per_date":{"2026-09-01":[3],"2026-09-0"#,
+        )
+        .unwrap();
+
+        let now =
+            NaiveDateTime::parse_from_str("2026-09-15 08:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let result = ApplicationData::load_or_recover(&path, now);
+        let is_moved_aside = !path.exists()
+            && directory
+                .join("freundchen.corrupt-20260915-083000.json")
+                .exists();
+        let _ = fs::remove_dir_all(&directory);
+
+        let (application_data, recovery) = result.unwrap();
+        let recovery = recovery.expect("the file should be recovered");
+
+        assert!(is_moved_aside);
+        assert_eq!(recovery.number_of_recovered_days, 1);
+        assert_eq!(
+            application_data.mood_per_date,
+            BTreeMap::from([(september(1), HashSet::from([3]))])
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::Error;
+use chrono::{Duration, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// The best sleep quality. The qualities are from 1 to it.
+pub const MAXIMUM_SLEEP_QUALITY: u8 = 5;
+
+/// The sleep of a night. It is stored with the date of the day before the night, so that it can
+/// be related to the next day's mood.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SleepEntry {
+    /// How often the sleep was interrupted.
+    #[serde(default)]
+    pub awakenings: u32,
+    pub bedtime: NaiveTime,
+    /// From 1 (bad) to `MAXIMUM_SLEEP_QUALITY` (restful).
+    pub quality: u8,
+    pub wake_time: NaiveTime,
+}
+
+impl SleepEntry {
+    /// Creates a sleep entry. The quality must be from 1 to `MAXIMUM_SLEEP_QUALITY` and the wake
+    /// time must differ from the bedtime.
+    pub fn new(
+        bedtime: NaiveTime,
+        wake_time: NaiveTime,
+        quality: u8,
+        awakenings: u32,
+    ) -> Result<Self, Error> {
+        if !(1..=MAXIMUM_SLEEP_QUALITY).contains(&quality) {
+            return Err(Error::InvalidSleep(format!(
+                "The quality must be from 1 to {}",
+                MAXIMUM_SLEEP_QUALITY
+            )));
+        }
+
+        if bedtime == wake_time {
+            return Err(Error::InvalidSleep(
+                "The wake time is the bedtime".to_string(),
+            ));
+        }
+
+        Ok(SleepEntry {
+            awakenings,
+            bedtime,
+            quality,
+            wake_time,
+        })
+    }
+
+    /// Returns how long the night was, from the bedtime to the wake time.
+    pub fn duration(&self) -> Duration {
+        night_duration(self.bedtime, self.wake_time)
+    }
+
+    /// Returns the duration in hours, e.g. to chart or correlate it.
+    pub fn hours(&self) -> f64 {
+        self.duration().num_minutes() as f64 / 60.0
+    }
+}
+
+/// Returns the duration from the bedtime to the wake time. A wake time before the bedtime is on the
+/// next day, e.g. from 23:30 to 7:00 is 7.5 hours. Equal times are no sleep rather than a whole
+/// day, e.g. of an entry read from a file, as `SleepEntry::new` rejects them.
+pub fn night_duration(bedtime: NaiveTime, wake_time: NaiveTime) -> Duration {
+    let duration = wake_time - bedtime;
+
+    if duration < Duration::zero() {
+        duration + Duration::days(1)
+    } else {
+        duration
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the given time.
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn a_night_can_go_across_midnight() {
+        assert_eq!(
+            night_duration(time(23, 30), time(7, 0)),
+            Duration::minutes(450)
+        );
+        assert_eq!(
+            night_duration(time(0, 15), time(6, 45)),
+            Duration::minutes(390)
+        );
+        assert_eq!(
+            SleepEntry::new(time(22, 0), time(6, 30), 4, 0)
+                .unwrap()
+                .hours(),
+            8.5
+        );
+    }
+
+    #[test]
+    fn an_invalid_quality_is_rejected() {
+        assert!(SleepEntry::new(time(23, 0), time(7, 0), 0, 0).is_err());
+        assert!(SleepEntry::new(time(23, 0), time(7, 0), MAXIMUM_SLEEP_QUALITY + 1, 0).is_err());
+    }
+
+    #[test]
+    fn equal_times_are_no_sleep() {
+        assert_eq!(night_duration(time(23, 0), time(23, 0)), Duration::zero());
+        assert!(SleepEntry::new(time(23, 0), time(23, 0), 3, 0).is_err());
+
+        let entry: SleepEntry = serde_json::from_str(
+            r#"{ "bedtime": "23:00:00", "wake_time": "23:00:00", "quality": 3 }"#,
+        )
+        .unwrap();
+
+        assert_eq!(entry.hours(), 0.0);
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// The maximum intensity of an emotion, in percent.
+pub const MAXIMUM_EMOTION_INTENSITY: u8 = 100;
+
+/// A thought record, as used in cognitive restructuring.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThoughtRecord {
+    /// The day the record refers to.
+    pub date: NaiveDate,
+    /// What happened.
+    pub situation: String,
+    /// The thought that came up automatically.
+    pub automatic_thought: String,
+    /// The emotion that came with the automatic thought.
+    pub emotion: String,
+    /// The emotion's intensity in percent.
+    pub emotion_intensity: u8,
+    /// The evidence that supports the automatic thought.
+    pub evidence_for: String,
+    /// The evidence that does not support the automatic thought.
+    pub evidence_against: String,
+    /// A more balanced thought, taking all the evidence into account.
+    pub balanced_thought: String,
+    /// The emotion's intensity in percent, after the balanced thought.
+    pub emotion_intensity_rerated: u8,
+}
@@ -0,0 +1,144 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// An error of loading, saving or importing data.
+#[derive(Debug)]
+pub enum Error {
+    /// The application data file at the path could not be parsed.
+    CorruptData {
+        error: serde_json::Error,
+        path: PathBuf,
+    },
+    Database(rusqlite::Error),
+    Io(std::io::Error),
+    Deserde(serde_json::Error),
+    /// The dimension's definition is invalid for the reason.
+    InvalidDimension(String),
+    /// The factor's definition or a value of it is invalid for the reason.
+    InvalidFactor(String),
+    /// The medication's definition or a change of it is invalid for the reason.
+    InvalidMedication(String),
+    /// The text to import mood values from is invalid at the line.
+    InvalidMoodText {
+        line: usize,
+        reason: String,
+    },
+    /// The sleep entry is invalid for the reason.
+    InvalidSleep(String),
+    /// The application data was written by a newer version of the application. The file was
+    /// copied to the backup path.
+    NewerDataVersion {
+        backup_path: PathBuf,
+        supported_version: u32,
+        version: u32,
+    },
+}
+
+impl Error {
+    /// Returns a description of the error for the log. Parser messages are left out, as they can
+    /// quote the data.
+    pub fn log_message(&self) -> String {
+        match self {
+            Error::CorruptData { error, path } => format!(
+                "\"{}\" is corrupt: {:?} error at line {}, column {}",
+                path.display(),
+                error.classify(),
+                error.line(),
+                error.column()
+            ),
+            Error::Deserde(error) => format!(
+                "{:?} error at line {}, column {}",
+                error.classify(),
+                error.line(),
+                error.column()
+            ),
+            // The reasons can quote the entered values, e.g. a factor's value, a dose or a time.
+            Error::InvalidFactor(_) => "Invalid factor".to_string(),
+            Error::InvalidMedication(_) => "Invalid medication".to_string(),
+            Error::InvalidMoodText { line, .. } => {
+                format!("Invalid mood values at line {}", line)
+            }
+            Error::InvalidSleep(_) => "Invalid sleep".to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // The error contains the line and column, if the JSON itself is invalid.
+            Error::CorruptData { error, path } => {
+                write!(f, "\"{}\" is corrupt: {}", path.display(), error)
+            }
+            Error::Database(error) => write!(f, "Database error: {}", error),
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Deserde(error) => write!(f, "{}", error),
+            Error::InvalidDimension(reason) => write!(f, "Invalid dimension: {}", reason),
+            Error::InvalidFactor(reason) => write!(f, "Invalid factor: {}", reason),
+            Error::InvalidMedication(reason) => write!(f, "Invalid medication: {}", reason),
+            Error::InvalidMoodText { line, reason } => {
+                write!(f, "Invalid mood values at line {}: {}", line, reason)
+            }
+            Error::InvalidSleep(reason) => write!(f, "Invalid sleep: {}", reason),
+            Error::NewerDataVersion {
+                backup_path,
+                supported_version,
+                version,
+            } => write!(
+                f,
+                "The data has version {}, but this version of freundchen only supports up to \
+                 version {}. Please update freundchen. A backup was written to \"{}\".",
+                version,
+                supported_version,
+                backup_path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Deserde(error)
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Error::Database(error)
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_log_message_leaves_out_the_entered_values() {
+        for error in [
+            Error::InvalidFactor("\"7.5 beers\" is not a number".to_string()),
+            Error::InvalidMedication("The dose \"50 mg Sertraline\" is invalid".to_string()),
+            Error::InvalidMoodText {
+                line: 3,
+                reason: "\"7.5 beers\" is not a mood value".to_string(),
+            },
+            Error::InvalidSleep("\"7.5 beers\" is not a time".to_string()),
+        ] {
+            let log_message = error.log_message();
+
+            assert!(!log_message.contains("7.5"), "{}", log_message);
+            assert!(!log_message.contains("50 mg"), "{}", log_message);
+            assert!(error.to_string().starts_with(&log_message));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! The export of the values per day as comma separated values for spreadsheets and statistics
+//! software: one line per day with the mood, the dimensions, the factors, the doses of the taken
+//! medications, the sleep of the night after the day and the tags.
+
+use crate::data::ApplicationData;
+use crate::mood::MoodValue;
+use chrono::NaiveDate;
+use std::collections::HashSet;
+
+/// Returns the values of the days from `first_date` to `last_date`, inclusively, as comma separated
+/// values with a header line. Mood values are separated by spaces and tags by semicolons, a day
+/// without a value or intake has an empty field.
+pub fn to_csv(
+    application_data: &ApplicationData,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> String {
+    let mut header = vec!["date".to_string(), "mood".to_string()];
+    header.extend(
+        application_data
+            .dimensions
+            .iter()
+            .map(|dimension| dimension.name.clone()),
+    );
+    header.extend(
+        application_data
+            .factors
+            .iter()
+            .map(|factor| factor.name.clone()),
+    );
+    header.extend(
+        application_data
+            .medications
+            .iter()
+            .map(|medication| medication.name.clone()),
+    );
+    header.extend(
+        [
+            "bedtime",
+            "wake time",
+            "sleep hours",
+            "sleep quality",
+            "awakenings",
+            "tags",
+        ]
+        .map(str::to_string),
+    );
+
+    let mut csv = csv_line(&header);
+
+    for date in first_date.iter_days().take_while(|date| *date <= last_date) {
+        let mut fields = vec![
+            date.to_string(),
+            mood_values_field(application_data.mood_per_date.get(&date)),
+        ];
+
+        fields.extend(
+            application_data
+                .dimensions
+                .iter()
+                .map(|dimension| mood_values_field(dimension.values_per_date.get(&date))),
+        );
+        fields.extend(application_data.factors.iter().map(|factor| {
+            factor
+                .values_per_date
+                .get(&date)
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        }));
+        fields.extend(application_data.medications.iter().map(|medication| {
+            medication
+                .dose_on(date)
+                .filter(|_| medication.intake_dates.contains(&date))
+                .map(str::to_string)
+                .unwrap_or_default()
+        }));
+        fields.extend(match application_data.sleep_per_date.get(&date) {
+            Some(sleep) => [
+                sleep.bedtime.format("%H:%M").to_string(),
+                sleep.wake_time.format("%H:%M").to_string(),
+                format!("{:.2}", sleep.hours()),
+                sleep.quality.to_string(),
+                sleep.awakenings.to_string(),
+            ],
+            None => Default::default(),
+        });
+        fields.push(
+            application_data
+                .tags_per_date
+                .get(&date)
+                .map(|tags| tags.iter().cloned().collect::<Vec<_>>().join(";"))
+                .unwrap_or_default(),
+        );
+
+        csv.push('\n');
+        csv.push_str(&csv_line(&fields));
+    }
+
+    csv
+}
+
+/// Joins the fields with commas. Fields with commas, quotes or line breaks are quoted.
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Returns the sorted mood values separated by spaces.
+fn mood_values_field(mood_values: Option<&HashSet<MoodValue>>) -> String {
+    let mut mood_values: Vec<&MoodValue> = mood_values.into_iter().flatten().collect();
+    mood_values.sort();
+
+    mood_values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Factor, FactorKind, FactorValue, Medication, Schedule, SleepEntry, Track};
+    use chrono::NaiveTime;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    #[test]
+    fn the_days_are_exported_with_a_column_per_track() {
+        let mut application_data = ApplicationData::new();
+        application_data.set_mood_values(Track::Mood, september(1), [7, 3].into());
+
+        let mut weather = Factor::new(
+            "Weather",
+            FactorKind::Choice {
+                choices: vec!["sunny, warm".to_string(), "rainy".to_string()],
+            },
+        )
+        .unwrap();
+        weather.set_value(
+            september(2),
+            Some(FactorValue::Choice("sunny, warm".to_string())),
+        );
+        application_data.factors.push(weather);
+
+        let mut medication =
+            Medication::new("Sertraline", "50 mg", Schedule::Daily, september(1)).unwrap();
+        medication.set_intake(september(1), true);
+        application_data.medications.push(medication);
+
+        application_data.sleep_per_date.insert(
+            september(1),
+            SleepEntry::new(
+                NaiveTime::from_hms_opt(23, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                4,
+                2,
+            )
+            .unwrap(),
+        );
+        application_data
+            .tags_per_date
+            .insert(september(2), ["calm".to_string(), "joy".to_string()].into());
+
+        assert_eq!(
+            to_csv(&application_data, september(1), september(2)),
+            "date,mood,Weather,Sertraline,bedtime,wake time,sleep hours,sleep quality,awakenings,tags\n\
+             2026-09-01,3 7,,50 mg,23:30,07:00,7.50,4,2,\n\
+             2026-09-02,,\"sunny, warm\",,,,,,,calm;joy"
+        );
+    }
+
+    #[test]
+    fn fields_with_quotes_are_escaped() {
+        assert_eq!(
+            csv_line(&["a \"b\"".to_string(), "c".to_string()]),
+            "\"a \"\"b\"\"\",c"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! The built-in feelings wheel to tag days with: core feelings, each with more specific feelings.
+//! The tags of feelings are their names, custom tags are any other text.
+
+/// A core feeling with its more specific feelings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Feeling {
+    pub name: &'static str,
+    pub sub_feelings: &'static [&'static str],
+}
+
+/// The feelings wheel.
+pub const FEELINGS_WHEEL: [Feeling; 6] = [
+    Feeling {
+        name: "joy",
+        sub_feelings: &[
+            "content",
+            "proud",
+            "optimistic",
+            "playful",
+            "grateful",
+            "hopeful",
+        ],
+    },
+    Feeling {
+        name: "sadness",
+        sub_feelings: &[
+            "lonely",
+            "hurt",
+            "guilty",
+            "disappointed",
+            "hopeless",
+            "bored",
+        ],
+    },
+    Feeling {
+        name: "anger",
+        sub_feelings: &["frustrated", "irritated", "resentful", "jealous", "annoyed"],
+    },
+    Feeling {
+        name: "fear",
+        sub_feelings: &["anxious", "insecure", "overwhelmed", "worried", "scared"],
+    },
+    Feeling {
+        name: "surprise",
+        sub_feelings: &["amazed", "confused", "startled", "excited"],
+    },
+    Feeling {
+        name: "disgust",
+        sub_feelings: &["disapproving", "embarrassed", "awful", "repelled"],
+    },
+];
+
+/// Returns the core feeling of the tag, which is the tag itself for a core feeling. Custom tags
+/// have none.
+pub fn core_feeling(tag: &str) -> Option<&'static str> {
+    FEELINGS_WHEEL
+        .iter()
+        .find(|feeling| feeling.name == tag || feeling.sub_feelings.contains(&tag))
+        .map(|feeling| feeling.name)
+}
+
+/// Returns whether the tag is a feeling of the wheel.
+pub fn is_feeling(tag: &str) -> bool {
+    core_feeling(tag).is_some()
+}
+
+/// Returns whether the tag matches the filter tag: it is the same tag, or the filter is the tag's
+/// core feeling.
+pub fn tag_matches(tag: &str, filter_tag: &str) -> bool {
+    tag == filter_tag || core_feeling(tag).is_some_and(|core_feeling| core_feeling == filter_tag)
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_feeling_belongs_to_its_core_feeling() {
+        assert_eq!(core_feeling("joy"), Some("joy"));
+        assert_eq!(core_feeling("anxious"), Some("fear"));
+        assert_eq!(core_feeling("after the concert"), None);
+        assert!(!is_feeling("Joy"));
+    }
+
+    #[test]
+    fn a_core_feeling_matches_its_nuances() {
+        assert!(tag_matches("anxious", "fear"));
+        assert!(tag_matches("fear", "fear"));
+        assert!(tag_matches("after the concert", "after the concert"));
+        assert!(!tag_matches("fear", "anxious"));
+        assert!(!tag_matches("content", "fear"));
+    }
+}
@@ -0,0 +1,14 @@
+//! The domain core of freundchen: the application data, its persistence and statistics. It does
+//! not depend on the GUI, so that the application and the command line interface can share it.
+
+pub mod clipboard;
+pub mod data;
+mod error;
+pub mod export;
+pub mod feelings;
+pub mod mood;
+pub mod paths;
+pub mod statistics;
+pub mod storage;
+
+pub use error::Error;
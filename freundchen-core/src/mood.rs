@@ -0,0 +1,100 @@
+use crate::data::{ApplicationData, Track};
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+
+/// A mood value, from 0 (the worst) to `NUMBER_OF_MOOD_VALUES_PER_DAY - 1` (the best).
+pub type MoodValue = u8;
+
+/// The number of mood values per day.
+pub const NUMBER_OF_MOOD_VALUES_PER_DAY: MoodValue = 11;
+
+/// The mood values, or the values of a dimension, of a month per day of month index.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MoodMonth {
+    mood_values_per_day_of_month_index: Vec<HashSet<MoodValue>>,
+    number_of_values: MoodValue,
+}
+
+impl MoodMonth {
+    /// Creates a month with the given number of days without mood values.
+    pub fn new(number_of_days: u8) -> Self {
+        MoodMonth::with_number_of_values(number_of_days, NUMBER_OF_MOOD_VALUES_PER_DAY)
+    }
+
+    /// Creates a month with the given number of days without values, for a scale with the given
+    /// number of values.
+    pub fn with_number_of_values(number_of_days: u8, number_of_values: MoodValue) -> Self {
+        MoodMonth {
+            mood_values_per_day_of_month_index: vec![HashSet::new(); number_of_days as usize],
+            number_of_values: number_of_values.min(NUMBER_OF_MOOD_VALUES_PER_DAY),
+        }
+    }
+
+    /// Returns the track's values of the given date's month.
+    pub fn from_application_data(
+        application_data: &ApplicationData,
+        track: Track,
+        date: NaiveDate,
+    ) -> Self {
+        let mut mood_month = MoodMonth::with_number_of_values(
+            number_of_days_in_month(date),
+            application_data.number_of_values(track),
+        );
+
+        for (day_of_month_index, mood_values) in application_data.mood_values_in_month(track, date)
+        {
+            mood_month.set_mood_values(day_of_month_index as usize, mood_values.clone());
+        }
+
+        mood_month
+    }
+
+    /// Returns the mood values per day of month index.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &HashSet<MoodValue>)> {
+        self.mood_values_per_day_of_month_index.iter().enumerate()
+    }
+
+    /// Returns the mood values of the given day, if it is within the month.
+    pub fn mood_values(&self, day_of_month_index: usize) -> Option<&HashSet<MoodValue>> {
+        self.mood_values_per_day_of_month_index
+            .get(day_of_month_index)
+    }
+
+    /// Returns the number of days in the month.
+    pub fn number_of_days(&self) -> usize {
+        self.mood_values_per_day_of_month_index.len()
+    }
+
+    /// Returns the number of values of the scale.
+    pub fn number_of_values(&self) -> MoodValue {
+        self.number_of_values
+    }
+
+    /// Sets the mood values of the given day, if it is within the month. Mood values outside the
+    /// scale are left out.
+    pub fn set_mood_values(&mut self, day_of_month_index: usize, mood_values: HashSet<MoodValue>) {
+        if let Some(day_mood_values) = self
+            .mood_values_per_day_of_month_index
+            .get_mut(day_of_month_index)
+        {
+            *day_mood_values = mood_values
+                .into_iter()
+                .filter(|mood_value| *mood_value < self.number_of_values)
+                .collect();
+        }
+    }
+}
+
+// =================================================================================================
+
+/// Returns the number of days in the given date's month.
+pub fn number_of_days_in_month(date: NaiveDate) -> u8 {
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first_day_of_next_month| first_day_of_next_month.pred_opt())
+        .map_or(31, |last_day_of_month| last_day_of_month.day() as u8)
+}
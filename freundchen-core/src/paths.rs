@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+/// The name of the application's directories within the platform's directories.
+pub const APPLICATION_DIRECTORY_NAME: &str = "freundchen";
+
+/// Returns the application's configuration directory.
+pub fn config_directory() -> PathBuf {
+    dirs::config_dir()
+        .map(|config_directory| config_directory.join(APPLICATION_DIRECTORY_NAME))
+        .unwrap_or_default()
+}
+
+/// Returns the path of the application data file, if the settings do not name one.
+pub fn default_data_path() -> PathBuf {
+    // Keep using a data file in the working directory, as written by earlier versions.
+    let legacy_data_path = PathBuf::from("freundchen.json");
+
+    if legacy_data_path.exists() {
+        return legacy_data_path;
+    }
+
+    dirs::data_dir()
+        .map(|data_directory| data_directory.join(APPLICATION_DIRECTORY_NAME))
+        .unwrap_or_default()
+        .join("freundchen.json")
+}
+
+/// Returns the path of the settings file.
+pub fn settings_path() -> PathBuf {
+    config_directory().join("settings.toml")
+}
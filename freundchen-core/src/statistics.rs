@@ -0,0 +1,506 @@
+use crate::data::{Factor, FactorValue, Medication, SleepEntry};
+use crate::mood::MoodValue;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// The least number of days with values of both tracks to compute their correlation.
+pub const MINIMUM_NUMBER_OF_CORRELATED_DAYS: usize = 3;
+
+/// The summary statistics of the mood values within a date range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoodStatistics {
+    pub average_mood_value: Option<f64>,
+    pub maximum_mood_value: Option<MoodValue>,
+    pub minimum_mood_value: Option<MoodValue>,
+    pub number_of_days: usize,
+    pub number_of_days_with_mood_values: usize,
+}
+
+impl MoodStatistics {
+    /// Computes the statistics of the values per date from `first_date` to `last_date`,
+    /// inclusively, e.g. of the mood or of a dimension.
+    pub fn new(
+        values_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+    ) -> Self {
+        let mood_values: Vec<MoodValue> = values_per_date
+            .range(first_date..=last_date)
+            .flat_map(|(_, mood_values)| mood_values.iter().copied())
+            .collect();
+
+        MoodStatistics {
+            average_mood_value: average(&mood_values),
+            maximum_mood_value: mood_values.iter().max().copied(),
+            minimum_mood_value: mood_values.iter().min().copied(),
+            number_of_days: ((last_date - first_date).num_days() + 1).max(0) as usize,
+            number_of_days_with_mood_values: values_per_date.range(first_date..=last_date).count(),
+        }
+    }
+}
+
+// =================================================================================================
+
+/// How strongly the daily values of two tracks go together, e.g. the mood and the anxiety.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Correlation {
+    /// The Pearson correlation coefficient of the daily averages, from -1 (opposed) to 1 (alike).
+    pub coefficient: f64,
+    /// The number of days with values of both tracks.
+    pub number_of_days: usize,
+}
+
+impl Correlation {
+    /// Correlates the daily averages of the values per date from `first_date` to `last_date`,
+    /// inclusively, on the days with values of both. There is no correlation if there are fewer
+    /// than `MINIMUM_NUMBER_OF_CORRELATED_DAYS` such days or the values of one do not vary.
+    pub fn new(
+        values_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+        other_values_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+    ) -> Option<Self> {
+        let pairs: Vec<(f64, f64)> = values_per_date
+            .range(first_date..=last_date)
+            .filter_map(|(date, mood_values)| {
+                Some((
+                    daily_average(mood_values)?,
+                    daily_average(other_values_per_date.get(date)?)?,
+                ))
+            })
+            .collect();
+
+        Correlation::from_pairs(&pairs)
+    }
+
+    /// Correlates the daily averages of the values per date with the factor's values from
+    /// `first_date` to `last_date`, inclusively, like `Correlation::new`. A boolean factor counts
+    /// as 0 or 1, a choice factor cannot be correlated.
+    pub fn with_factor(
+        values_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+        factor: &Factor,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+    ) -> Option<Self> {
+        let pairs: Vec<(f64, f64)> = values_per_date
+            .range(first_date..=last_date)
+            .filter_map(|(date, mood_values)| {
+                Some((
+                    daily_average(mood_values)?,
+                    factor.values_per_date.get(date)?.as_number()?,
+                ))
+            })
+            .collect();
+
+        Correlation::from_pairs(&pairs)
+    }
+
+    /// Correlates the pairs of values, see `Correlation::new`.
+    fn from_pairs(pairs: &[(f64, f64)]) -> Option<Self> {
+        if pairs.len() < MINIMUM_NUMBER_OF_CORRELATED_DAYS {
+            return None;
+        }
+
+        let number_of_pairs = pairs.len() as f64;
+        let mean = pairs.iter().map(|(value, _)| value).sum::<f64>() / number_of_pairs;
+        let other_mean = pairs.iter().map(|(_, value)| value).sum::<f64>() / number_of_pairs;
+
+        let mut covariance = 0.0;
+        let mut variance = 0.0;
+        let mut other_variance = 0.0;
+
+        for (value, other_value) in pairs {
+            covariance += (value - mean) * (other_value - other_mean);
+            variance += (value - mean).powi(2);
+            other_variance += (other_value - other_mean).powi(2);
+        }
+
+        // The values of one track do not vary.
+        if variance == 0.0 || other_variance == 0.0 {
+            return None;
+        }
+
+        Some(Correlation {
+            coefficient: covariance / (variance * other_variance).sqrt(),
+            number_of_days: pairs.len(),
+        })
+    }
+}
+
+// =================================================================================================
+
+/// Returns how often each tag was used from `first_date` to `last_date`, inclusively, the most
+/// frequent first.
+pub fn tag_frequencies(
+    tags_per_date: &BTreeMap<NaiveDate, BTreeSet<String>>,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> Vec<(&str, usize)> {
+    let mut frequency_per_tag: BTreeMap<&str, usize> = BTreeMap::new();
+
+    for tag in tags_per_date
+        .range(first_date..=last_date)
+        .flat_map(|(_, tags)| tags)
+    {
+        *frequency_per_tag.entry(tag).or_default() += 1;
+    }
+
+    let mut tag_frequencies: Vec<(&str, usize)> = frequency_per_tag.into_iter().collect();
+    tag_frequencies.sort_by(|(_, frequency), (_, other_frequency)| other_frequency.cmp(frequency));
+
+    tag_frequencies
+}
+
+// =================================================================================================
+
+/// The summary statistics of the nights within a date range and how they relate to the next day's
+/// mood.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SleepStatistics {
+    pub average_awakenings: Option<f64>,
+    pub average_hours: Option<f64>,
+    pub average_quality: Option<f64>,
+    /// The correlation of the hours of sleep with the daily average of the next day's mood values.
+    pub hours_correlation: Option<Correlation>,
+    pub number_of_nights: usize,
+    /// The correlation of the sleep quality with the daily average of the next day's mood values.
+    pub quality_correlation: Option<Correlation>,
+}
+
+impl SleepStatistics {
+    /// Computes the statistics of the nights after the days from `first_date` to `last_date`,
+    /// inclusively. The sleep is stored with the day before the night, so each night is compared
+    /// with the mood values of the date after it.
+    pub fn new(
+        sleep_per_date: &BTreeMap<NaiveDate, SleepEntry>,
+        mood_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+    ) -> Self {
+        let nights: Vec<(NaiveDate, &SleepEntry)> = sleep_per_date
+            .range(first_date..=last_date)
+            .map(|(date, sleep)| (*date, sleep))
+            .collect();
+
+        let mean = |value: fn(&SleepEntry) -> f64| {
+            (!nights.is_empty()).then(|| {
+                nights.iter().map(|(_, sleep)| value(sleep)).sum::<f64>() / nights.len() as f64
+            })
+        };
+
+        // Pair each night's value with the next day's daily average.
+        let correlation = |value: fn(&SleepEntry) -> f64| {
+            let pairs: Vec<(f64, f64)> = nights
+                .iter()
+                .filter_map(|(date, sleep)| {
+                    Some((
+                        value(sleep),
+                        daily_average(mood_per_date.get(&date.succ_opt()?)?)?,
+                    ))
+                })
+                .collect();
+
+            Correlation::from_pairs(&pairs)
+        };
+
+        SleepStatistics {
+            average_awakenings: mean(|sleep| sleep.awakenings as f64),
+            average_hours: mean(SleepEntry::hours),
+            average_quality: mean(|sleep| sleep.quality as f64),
+            hours_correlation: correlation(SleepEntry::hours),
+            number_of_nights: nights.len(),
+            quality_correlation: correlation(|sleep| sleep.quality as f64),
+        }
+    }
+}
+
+// =================================================================================================
+
+/// The average mood on the days with one of a factor's values, e.g. on the rainy days.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FactorValueAverage {
+    /// The average of the daily averages of the mood values.
+    pub average_mood_value: f64,
+    /// The number of days with the value and mood values.
+    pub number_of_days: usize,
+    pub value: FactorValue,
+}
+
+/// Returns the average mood per value of a boolean or choice factor from `first_date` to
+/// `last_date`, inclusively, in the order of the values' first days. Numbers are correlated
+/// instead, see `Correlation::with_factor`.
+pub fn averages_per_factor_value(
+    values_per_date: &BTreeMap<NaiveDate, HashSet<MoodValue>>,
+    factor: &Factor,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+) -> Vec<FactorValueAverage> {
+    let mut daily_averages_per_value: Vec<(&FactorValue, Vec<f64>)> = vec![];
+
+    for (date, value) in factor.values_per_date.range(first_date..=last_date) {
+        let Some(daily_average) = values_per_date.get(date).and_then(daily_average) else {
+            continue;
+        };
+
+        match daily_averages_per_value
+            .iter_mut()
+            .find(|(other_value, _)| *other_value == value)
+        {
+            Some((_, daily_averages)) => daily_averages.push(daily_average),
+            None if matches!(value, FactorValue::Number(_)) => {}
+            None => daily_averages_per_value.push((value, vec![daily_average])),
+        }
+    }
+
+    daily_averages_per_value
+        .into_iter()
+        .map(|(value, daily_averages)| FactorValueAverage {
+            average_mood_value: daily_averages.iter().sum::<f64>() / daily_averages.len() as f64,
+            number_of_days: daily_averages.len(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+// =================================================================================================
+
+/// Returns the average of a day's mood values, if there are any.
+fn daily_average(mood_values: &HashSet<MoodValue>) -> Option<f64> {
+    average(&mood_values.iter().copied().collect::<Vec<_>>())
+}
+
+/// Returns the average of the mood values, if there are any.
+fn average(mood_values: &[MoodValue]) -> Option<f64> {
+    (!mood_values.is_empty()).then(|| {
+        mood_values
+            .iter()
+            .map(|mood_value| *mood_value as f64)
+            .sum::<f64>()
+            / mood_values.len() as f64
+    })
+}
+
+// =================================================================================================
+
+/// How well a medication was taken as scheduled within a date range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Adherence {
+    /// The number of scheduled days the medication was taken on.
+    pub number_of_days_taken: usize,
+    pub number_of_scheduled_days: usize,
+    /// The number of days the medication was taken on, including the unscheduled ones, e.g. of a
+    /// medication taken as needed.
+    pub number_of_intakes: usize,
+}
+
+impl Adherence {
+    /// Counts the scheduled days and the intakes from `first_date` to `last_date`, inclusively.
+    pub fn new(medication: &Medication, first_date: NaiveDate, last_date: NaiveDate) -> Self {
+        let scheduled_dates: Vec<NaiveDate> = first_date
+            .iter_days()
+            .take_while(|date| *date <= last_date)
+            .filter(|date| medication.is_scheduled_on(*date))
+            .collect();
+
+        Adherence {
+            number_of_days_taken: scheduled_dates
+                .iter()
+                .filter(|date| medication.intake_dates.contains(date))
+                .count(),
+            number_of_scheduled_days: scheduled_dates.len(),
+            number_of_intakes: medication
+                .intake_dates
+                .range(first_date..=last_date)
+                .count(),
+        }
+    }
+
+    /// Returns the share of the scheduled days the medication was taken on, from 0 to 1, if days
+    /// were scheduled.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.number_of_scheduled_days > 0)
+            .then(|| self.number_of_days_taken as f64 / self.number_of_scheduled_days as f64)
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{FactorKind, Schedule};
+    use chrono::NaiveTime;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns the given mood value per day of September 2026.
+    fn mood_per_date(
+        mood_value_per_day: &[(u32, MoodValue)],
+    ) -> BTreeMap<NaiveDate, HashSet<MoodValue>> {
+        mood_value_per_day
+            .iter()
+            .map(|(day, mood_value)| (september(*day), HashSet::from([*mood_value])))
+            .collect()
+    }
+
+    #[test]
+    fn correlated_pairs_have_a_coefficient() {
+        let correlation = Correlation::from_pairs(&[(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)]).unwrap();
+        let opposed = Correlation::from_pairs(&[(1.0, 3.0), (2.0, 2.0), (3.0, 1.0)]).unwrap();
+
+        assert!((correlation.coefficient - 1.0).abs() < 1e-9);
+        assert_eq!(correlation.number_of_days, 3);
+        assert!((opposed.coefficient + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn constant_values_have_no_correlation() {
+        assert_eq!(
+            Correlation::from_pairs(&[(5.0, 1.0), (5.0, 2.0), (5.0, 3.0)]),
+            None
+        );
+        assert_eq!(
+            Correlation::from_pairs(&[(1.0, 4.0), (2.0, 4.0), (3.0, 4.0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn too_few_days_have_no_correlation() {
+        assert_eq!(Correlation::from_pairs(&[(1.0, 2.0), (2.0, 4.0)]), None);
+    }
+
+    #[test]
+    fn the_mood_is_correlated_with_number_and_boolean_factors() {
+        let mood_per_date = mood_per_date(&[(1, 8), (2, 6), (3, 4), (4, 2)]);
+        let mut sleep = Factor::new("Sleep", FactorKind::Number).unwrap();
+        let mut alcohol = Factor::new("Alcohol", FactorKind::Boolean).unwrap();
+
+        for (day, hours, had_alcohol) in [(1, 8.0, false), (2, 7.0, false), (3, 6.0, true)] {
+            sleep.set_value(september(day), Some(FactorValue::Number(hours)));
+            alcohol.set_value(september(day), Some(FactorValue::Boolean(had_alcohol)));
+        }
+
+        let sleep_correlation =
+            Correlation::with_factor(&mood_per_date, &sleep, september(1), september(30)).unwrap();
+        let alcohol_correlation =
+            Correlation::with_factor(&mood_per_date, &alcohol, september(1), september(30))
+                .unwrap();
+
+        assert_eq!(sleep_correlation.number_of_days, 3);
+        assert!((sleep_correlation.coefficient - 1.0).abs() < 1e-9);
+        assert!(alcohol_correlation.coefficient < 0.0);
+    }
+
+    #[test]
+    fn a_choice_factor_is_averaged_per_choice_instead() {
+        let mood_per_date = mood_per_date(&[(1, 8), (2, 6), (3, 2), (4, 4)]);
+        let mut weather = Factor::new(
+            "Weather",
+            FactorKind::Choice {
+                choices: vec!["sunny".to_string(), "rainy".to_string()],
+            },
+        )
+        .unwrap();
+
+        for (day, choice) in [(1, "sunny"), (2, "sunny"), (3, "rainy"), (5, "rainy")] {
+            weather.set_value(
+                september(day),
+                Some(FactorValue::Choice(choice.to_string())),
+            );
+        }
+
+        assert_eq!(
+            Correlation::with_factor(&mood_per_date, &weather, september(1), september(30)),
+            None
+        );
+        assert_eq!(
+            averages_per_factor_value(&mood_per_date, &weather, september(1), september(30)),
+            vec![
+                FactorValueAverage {
+                    average_mood_value: 7.0,
+                    number_of_days: 2,
+                    value: FactorValue::Choice("sunny".to_string()),
+                },
+                FactorValueAverage {
+                    average_mood_value: 2.0,
+                    number_of_days: 1,
+                    value: FactorValue::Choice("rainy".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn a_night_is_related_to_the_next_days_mood() {
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let mood_per_date = mood_per_date(&[(2, 8), (3, 6), (4, 4), (5, 10)]);
+
+        // The nights after the 1st to the 3rd, with less sleep each, and the night after the 5th.
+        let sleep_per_date: BTreeMap<NaiveDate, SleepEntry> =
+            [(1, 22, 4), (2, 23, 3), (3, 0, 2), (5, 22, 5)]
+                .into_iter()
+                .map(|(day, bedtime, quality)| {
+                    (
+                        september(day),
+                        SleepEntry::new(time(bedtime), time(7), quality, 1).unwrap(),
+                    )
+                })
+                .collect();
+
+        let sleep_statistics =
+            SleepStatistics::new(&sleep_per_date, &mood_per_date, september(1), september(3));
+
+        assert_eq!(sleep_statistics.number_of_nights, 3);
+        assert_eq!(sleep_statistics.average_hours, Some(8.0));
+        assert_eq!(sleep_statistics.average_quality, Some(3.0));
+        assert_eq!(sleep_statistics.average_awakenings, Some(1.0));
+
+        let hours_correlation = sleep_statistics.hours_correlation.unwrap();
+        assert_eq!(hours_correlation.number_of_days, 3);
+        assert!((hours_correlation.coefficient - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn the_adherence_counts_the_scheduled_days_taken() {
+        let mut medication = Medication::new(
+            "Sertraline",
+            "50 mg",
+            Schedule::EveryNDays { interval: 2 },
+            september(1),
+        )
+        .unwrap();
+
+        // Taken on two of the five scheduled days and on an unscheduled one.
+        for day in [1, 3, 4] {
+            medication.set_intake(september(day), true);
+        }
+
+        let adherence = Adherence::new(&medication, september(1), september(10));
+
+        assert_eq!(
+            adherence,
+            Adherence {
+                number_of_days_taken: 2,
+                number_of_scheduled_days: 5,
+                number_of_intakes: 3,
+            }
+        );
+        assert_eq!(adherence.ratio(), Some(0.4));
+    }
+
+    #[test]
+    fn a_medication_taken_as_needed_has_no_adherence_ratio() {
+        let mut medication =
+            Medication::new("Ibuprofen", "400 mg", Schedule::AsNeeded, september(1)).unwrap();
+        medication.set_intake(september(2), true);
+
+        let adherence = Adherence::new(&medication, september(1), september(30));
+
+        assert_eq!(adherence.number_of_intakes, 1);
+        assert_eq!(adherence.ratio(), None);
+    }
+}
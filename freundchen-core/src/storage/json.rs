@@ -0,0 +1,75 @@
+use crate::data::{ApplicationData, Recovery};
+use crate::storage::Storage;
+use crate::Error;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Stores the application data in a JSON file, which is replaced with every save.
+pub struct JsonStorage {
+    path: PathBuf,
+}
+
+impl JsonStorage {
+    pub fn new(path: PathBuf) -> Self {
+        JsonStorage { path }
+    }
+}
+
+impl Storage for JsonStorage {
+    fn load(&mut self, today: NaiveDate) -> Result<ApplicationData, Error> {
+        ApplicationData::load(&self.path, today)
+    }
+
+    fn load_or_recover(
+        &mut self,
+        now: NaiveDateTime,
+    ) -> Result<(ApplicationData, Option<Recovery>), Error> {
+        ApplicationData::load_or_recover(&self.path, now)
+    }
+
+    fn mood_values_between(
+        &mut self,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+        today: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, HashSet<u8>>, Error> {
+        // The whole file has to be read.
+        let mut application_data = self.load(today)?;
+
+        Ok(application_data
+            .mood_per_date
+            .split_off(&first_date)
+            .into_iter()
+            .take_while(|(date, _)| *date <= last_date)
+            .collect())
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn save(&mut self, application_data: &ApplicationData) -> Result<(), Error> {
+        // Serialize the application data.
+        let serialized_application_data = serde_json::to_string(application_data)?;
+
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        // Write to a temporary file next to the file and replace the file with it, so that a failed
+        // write does not leave a truncated file behind.
+        let mut temporary_path = self.path.clone().into_os_string();
+        temporary_path.push(".tmp");
+        let temporary_path = PathBuf::from(temporary_path);
+
+        let mut file = File::create(&temporary_path)?;
+        file.write_all(serialized_application_data.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary_path, &self.path)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+mod json;
+mod sqlite;
+
+use crate::data::{ApplicationData, Recovery};
+use crate::Error;
+use chrono::{NaiveDate, NaiveDateTime};
+pub use json::JsonStorage;
+pub use sqlite::SqliteStorage;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// The file extensions of SQLite databases.
+const SQLITE_EXTENSIONS: [&str; 3] = ["db", "sqlite", "sqlite3"];
+
+/// Persists the application data.
+pub trait Storage {
+    /// Closes the storage, e.g. before quitting.
+    fn close(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Loads the application data. If nothing was saved yet, empty application data is returned.
+    fn load(&mut self, today: NaiveDate) -> Result<ApplicationData, Error>;
+
+    /// Loads the application data like `load`, but recovers what can be read if the data is
+    /// corrupt.
+    fn load_or_recover(
+        &mut self,
+        now: NaiveDateTime,
+    ) -> Result<(ApplicationData, Option<Recovery>), Error> {
+        self.load(now.date())
+            .map(|application_data| (application_data, None))
+    }
+
+    /// Returns the mood values per date between the given dates, both inclusive. Data of older
+    /// versions is migrated as of `today`, like with `load`.
+    fn mood_values_between(
+        &mut self,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+        today: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, HashSet<u8>>, Error>;
+
+    /// Returns the path of the storage.
+    fn path(&self) -> &Path;
+
+    /// Saves the application data.
+    fn save(&mut self, application_data: &ApplicationData) -> Result<(), Error>;
+}
+
+/// Returns the storage for the given path, depending on its extension.
+pub fn open_storage(path: PathBuf) -> Box<dyn Storage> {
+    if path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| SQLITE_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+    {
+        Box::new(SqliteStorage::new(path))
+    } else {
+        Box::new(JsonStorage::new(path))
+    }
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{
+        Dimension, Factor, FactorKind, FactorValue, Medication, Quote, Schedule, SleepEntry, Track,
+    };
+    use chrono::NaiveTime;
+    use serde_json::json;
+    use std::env;
+    use std::fs;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns a quote with the given text.
+    fn quote(text: &str) -> Quote {
+        Quote {
+            text: text.to_string(),
+            author: String::new(),
+            tags: vec![],
+            is_favourite: false,
+        }
+    }
+
+    /// Returns data with an entry of every kind.
+    fn application_data() -> ApplicationData {
+        let mut application_data = ApplicationData::new();
+
+        application_data.dimensions = vec![Dimension::new("Energy", 5, vec![]).unwrap()];
+        application_data.set_mood_values(Track::Mood, september(1), [3].into());
+        application_data.set_mood_values(Track::Mood, september(2), [5, 7].into());
+        application_data.set_mood_values(Track::Dimension(0), september(1), [4].into());
+
+        let mut walk = Factor::new("Walk", FactorKind::Boolean).unwrap();
+        walk.set_value(september(1), Some(FactorValue::Boolean(true)));
+        walk.set_value(september(2), Some(FactorValue::Boolean(false)));
+        application_data.factors.push(walk);
+
+        let mut medication =
+            Medication::new("Sertraline", "50 mg", Schedule::Daily, september(1)).unwrap();
+        medication.set_intake(september(1), true);
+        medication.set_intake(september(2), true);
+        application_data.medications.push(medication);
+
+        application_data
+            .note_per_date
+            .insert(september(2), "A walk in the park.".to_string());
+        application_data.sleep_per_date.insert(
+            september(1),
+            SleepEntry::new(
+                NaiveTime::from_hms_opt(23, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                4,
+                1,
+            )
+            .unwrap(),
+        );
+        application_data
+            .tags_per_date
+            .insert(september(1), ["joy".to_string()].into());
+        application_data.quotes = vec![quote("Be kind."), quote("Breathe.")];
+        application_data
+            .unknown_fields
+            .insert("future_field".to_string(), json!([1, 2]));
+
+        application_data
+    }
+
+    /// Removes an entry of every kind from the given data.
+    fn remove_entries(application_data: &mut ApplicationData) {
+        application_data.mood_per_date.remove(&september(2));
+        application_data.dimensions[0].values_per_date.clear();
+        application_data.factors.clear();
+        application_data.medications[0].set_intake(september(2), false);
+        application_data.note_per_date.clear();
+        application_data.sleep_per_date.clear();
+        application_data.tags_per_date.clear();
+        application_data.quotes.truncate(1);
+        application_data.unknown_fields.clear();
+    }
+
+    /// Asserts that the given data have the same entries.
+    fn assert_same_entries(application_data: &ApplicationData, expected: &ApplicationData) {
+        assert_eq!(application_data.dimensions, expected.dimensions);
+        assert_eq!(application_data.factors, expected.factors);
+        assert_eq!(application_data.medications, expected.medications);
+        assert_eq!(application_data.mood_per_date, expected.mood_per_date);
+        assert_eq!(application_data.note_per_date, expected.note_per_date);
+        assert_eq!(application_data.sleep_per_date, expected.sleep_per_date);
+        assert_eq!(application_data.tags_per_date, expected.tags_per_date);
+        assert_eq!(application_data.unknown_fields, expected.unknown_fields);
+        assert_eq!(
+            application_data
+                .quotes
+                .iter()
+                .map(|quote| &quote.text)
+                .collect::<Vec<_>>(),
+            expected
+                .quotes
+                .iter()
+                .map(|quote| &quote.text)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// Saves the data, then the data with removed entries, and checks that each is loaded again,
+    /// also by a newly opened storage.
+    fn assert_round_trip(file_name: &str) {
+        let directory = env::temp_dir().join(format!(
+            "freundchen-storage-{}-{}",
+            file_name,
+            std::process::id()
+        ));
+        let path = directory.join(file_name);
+        // Remove what a failed run left behind.
+        let _ = fs::remove_dir_all(&directory);
+
+        let mut application_data = application_data();
+        let mut storage = open_storage(path.clone());
+
+        assert!(storage
+            .load(september(15))
+            .unwrap()
+            .mood_per_date
+            .is_empty());
+
+        storage.save(&application_data).unwrap();
+        assert_same_entries(&storage.load(september(15)).unwrap(), &application_data);
+
+        remove_entries(&mut application_data);
+        storage.save(&application_data).unwrap();
+        storage.close().unwrap();
+
+        let mut storage = open_storage(path.clone());
+        assert_same_entries(&storage.load(september(15)).unwrap(), &application_data);
+        assert_eq!(
+            storage
+                .mood_values_between(september(1), september(30), september(15))
+                .unwrap(),
+            application_data.mood_per_date
+        );
+        storage.close().unwrap();
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    /// Saves factors, dimensions and medications of equal names, then renames one of each, and
+    /// checks that their values are loaded again by a newly opened storage.
+    fn assert_round_trip_of_equal_names(file_name: &str) {
+        let directory = env::temp_dir().join(format!(
+            "freundchen-storage-equal-names-{}-{}",
+            file_name,
+            std::process::id()
+        ));
+        let path = directory.join(file_name);
+        // Remove what a failed run left behind.
+        let _ = fs::remove_dir_all(&directory);
+
+        let mut application_data = ApplicationData::new();
+        application_data.dimensions = vec![
+            Dimension::new("Energy", 5, vec![]).unwrap(),
+            Dimension::new("Energy", 5, vec![]).unwrap(),
+        ];
+        application_data.set_mood_values(Track::Dimension(0), september(1), [1].into());
+        application_data.set_mood_values(Track::Dimension(1), september(1), [3].into());
+
+        for value in [true, false] {
+            let mut walk = Factor::new("Walk", FactorKind::Boolean).unwrap();
+            walk.set_value(september(1), Some(FactorValue::Boolean(value)));
+            application_data.factors.push(walk);
+        }
+
+        for day in [1, 2] {
+            let mut medication =
+                Medication::new("Sertraline", "50 mg", Schedule::Daily, september(1)).unwrap();
+            medication.set_intake(september(day), true);
+            application_data.medications.push(medication);
+        }
+
+        let mut storage = open_storage(path.clone());
+        storage.save(&application_data).unwrap();
+        storage.close().unwrap();
+
+        let mut storage = open_storage(path.clone());
+        let loaded_with_equal_names = storage.load(september(15)).unwrap();
+
+        application_data.dimensions[0].name = "Calm".to_string();
+        application_data.factors[1].name = "Run".to_string();
+        application_data.medications[0].name = "Vitamin D".to_string();
+        storage.save(&application_data).unwrap();
+        storage.close().unwrap();
+
+        let mut storage = open_storage(path.clone());
+        let loaded_after_renaming = storage.load(september(15)).unwrap();
+        storage.close().unwrap();
+
+        let _ = fs::remove_dir_all(&directory);
+
+        application_data.dimensions[0].name = "Energy".to_string();
+        application_data.factors[1].name = "Walk".to_string();
+        application_data.medications[0].name = "Sertraline".to_string();
+        assert_same_entries(&loaded_with_equal_names, &application_data);
+
+        application_data.dimensions[0].name = "Calm".to_string();
+        application_data.factors[1].name = "Run".to_string();
+        application_data.medications[0].name = "Vitamin D".to_string();
+        assert_same_entries(&loaded_after_renaming, &application_data);
+    }
+
+    #[test]
+    fn json_storage_round_trips_with_removed_entries() {
+        assert_round_trip("freundchen.json");
+    }
+
+    #[test]
+    fn sqlite_storage_round_trips_with_removed_entries() {
+        assert_round_trip("freundchen.sqlite");
+    }
+
+    #[test]
+    fn json_storage_keeps_the_values_of_equal_and_renamed_names() {
+        assert_round_trip_of_equal_names("freundchen.json");
+    }
+
+    #[test]
+    fn sqlite_storage_keeps_the_values_of_equal_and_renamed_names() {
+        assert_round_trip_of_equal_names("freundchen.sqlite");
+    }
+
+    #[test]
+    fn json_storage_replaces_the_file_without_leaving_a_temporary_file() {
+        let directory =
+            env::temp_dir().join(format!("freundchen-json-storage-{}", std::process::id()));
+        let path = directory.join("freundchen.json");
+        let mut storage = JsonStorage::new(path.clone());
+
+        storage.save(&application_data()).unwrap();
+        storage.save(&ApplicationData::new()).unwrap();
+        let file_names: Vec<_> = fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        let mood_per_date = storage.load(september(15)).map(|data| data.mood_per_date);
+        let _ = fs::remove_dir_all(&directory);
+
+        assert_eq!(file_names, vec!["freundchen.json"]);
+        assert!(mood_per_date.unwrap().is_empty());
+    }
+}
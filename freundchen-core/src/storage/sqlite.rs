@@ -0,0 +1,966 @@
+use crate::data::{ApplicationData, Dimension, Factor, Medication};
+use crate::storage::Storage;
+use crate::Error;
+use chrono::NaiveDate;
+use rusqlite::{params, Connection, Transaction};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// The version of the database schema that is written, stored in `user_version`. It is counted
+/// separately from the version of the data format, which only changed before there was a database.
+const SCHEMA_VERSION: u32 = 2;
+
+/// Migrates the database from the schema version at its index to the next version.
+type SchemaMigration = fn(&Transaction) -> Result<(), Error>;
+
+/// The migrations, starting at version 0, the empty database.
+const SCHEMA_MIGRATIONS: [SchemaMigration; SCHEMA_VERSION as usize] =
+    [create_schema_version_1, migrate_from_schema_version_1];
+
+/// The statements creating the tables of schema version 1. The values per date are stored per date
+/// and the lists per position, with the date to query them by, if they have one. The values of the
+/// dimensions and the factors and the intakes of the medications are stored per name and date.
+const SCHEMA_VERSION_1: &str = "
+CREATE TABLE IF NOT EXISTS mood_values (
+    date TEXT PRIMARY KEY NOT NULL,
+    mood_values TEXT NOT NULL
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS dimensions (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS dimension_values (
+    dimension TEXT NOT NULL,
+    date TEXT NOT NULL,
+    mood_values TEXT NOT NULL,
+    PRIMARY KEY (dimension, date)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS factors (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS factor_values (
+    factor TEXT NOT NULL,
+    date TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (factor, date)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS medications (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS medication_intakes (
+    medication TEXT NOT NULL,
+    date TEXT NOT NULL,
+    PRIMARY KEY (medication, date)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS notes (
+    date TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS sleep (
+    date TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS tags (
+    date TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS inquiries (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS inquiries_by_date ON inquiries (date);
+CREATE TABLE IF NOT EXISTS quotes (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS thought_records (
+    position INTEGER PRIMARY KEY NOT NULL,
+    date TEXT,
+    value TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS thought_records_by_date ON thought_records (date);
+CREATE TABLE IF NOT EXISTS unknown_fields (
+    name TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+);
+";
+
+/// The statements of schema version 2, which stores the values of the dimensions and the factors
+/// and the intakes of the medications per position instead of per name, so that neither equal
+/// names nor renaming lose them. The tables of version 1 are renamed to be copied.
+const SCHEMA_VERSION_2: &str = "
+ALTER TABLE dimension_values RENAME TO dimension_values_by_name;
+ALTER TABLE factor_values RENAME TO factor_values_by_name;
+ALTER TABLE medication_intakes RENAME TO medication_intakes_by_name;
+CREATE TABLE dimension_values (
+    dimension_position INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    mood_values TEXT NOT NULL,
+    PRIMARY KEY (dimension_position, date)
+) WITHOUT ROWID;
+CREATE TABLE factor_values (
+    factor_position INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (factor_position, date)
+) WITHOUT ROWID;
+CREATE TABLE medication_intakes (
+    medication_position INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    PRIMARY KEY (medication_position, date)
+) WITHOUT ROWID;
+";
+
+/// Stores the application data in an SQLite database. Saving only writes the rows that changed
+/// since the data was loaded or last saved.
+pub struct SqliteStorage {
+    /// The connection, opened with the first access.
+    connection: Option<Connection>,
+    path: PathBuf,
+    /// The data as it was loaded or last saved, `None` before either.
+    saved_application_data: Option<ApplicationData>,
+}
+
+impl SqliteStorage {
+    pub fn new(path: PathBuf) -> Self {
+        SqliteStorage {
+            connection: None,
+            path,
+            saved_application_data: None,
+        }
+    }
+
+    /// Returns the connection to the database, which is created if it does not exist.
+    fn connection(&mut self) -> Result<&mut Connection, Error> {
+        let connection = match self.connection.take() {
+            Some(connection) => connection,
+            None => self.open()?,
+        };
+
+        Ok(self.connection.insert(connection))
+    }
+
+    /// Opens the database and creates or migrates the tables.
+    fn open(&self) -> Result<Connection, Error> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let mut connection = Connection::open(&self.path)?;
+        let version: u32 = connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+
+        // The data was written by a newer version of the application.
+        if version > SCHEMA_VERSION {
+            let extension = self
+                .path
+                .extension()
+                .and_then(OsStr::to_str)
+                .unwrap_or("sqlite");
+            let backup_path = self
+                .path
+                .with_extension(format!("v{}.backup.{}", version, extension));
+            fs::copy(&self.path, &backup_path)?;
+
+            return Err(Error::NewerDataVersion {
+                backup_path,
+                supported_version: SCHEMA_VERSION,
+                version,
+            });
+        }
+
+        if version < SCHEMA_VERSION {
+            let transaction = connection.transaction()?;
+
+            for migration in &SCHEMA_MIGRATIONS[version as usize..] {
+                migration(&transaction)?;
+            }
+
+            transaction.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+            transaction.commit()?;
+
+            // The database was not just created.
+            if version > 0 {
+                info!(
+                    path = %self.path.display(),
+                    from_version = version,
+                    to_version = SCHEMA_VERSION,
+                    "Migrated the database"
+                );
+            }
+        }
+
+        debug!(path = %self.path.display(), version, "Opened the database");
+
+        Ok(connection)
+    }
+
+    /// Reads the application data from the database.
+    fn read(&mut self) -> Result<ApplicationData, Error> {
+        // Nothing was saved yet.
+        if !self.path.exists() {
+            return Ok(ApplicationData::new());
+        }
+
+        let connection = self.connection()?;
+        let mut application_data = ApplicationData::new();
+
+        application_data.mood_per_date =
+            read_mood_values(connection, "SELECT date, mood_values FROM mood_values", [])?;
+        application_data.note_per_date = read_values_per_date(connection, "notes")?;
+        application_data.sleep_per_date = read_values_per_date(connection, "sleep")?;
+        application_data.tags_per_date = read_values_per_date(connection, "tags")?;
+        application_data.dimensions = read_values(connection, "dimensions")?;
+
+        // Read the values of the dimensions.
+        {
+            let mut statement = connection
+                .prepare("SELECT dimension_position, date, mood_values FROM dimension_values")?;
+            let mut rows = statement.query([])?;
+
+            while let Some(row) = rows.next()? {
+                // The dimension was removed.
+                let Some(dimension) = application_data.dimensions.get_mut(row.get::<_, usize>(0)?)
+                else {
+                    continue;
+                };
+
+                dimension.values_per_date.insert(
+                    row.get(1)?,
+                    serde_json::from_str(&row.get::<_, String>(2)?)?,
+                );
+            }
+        }
+        application_data.factors = read_values(connection, "factors")?;
+
+        // Read the values of the factors.
+        {
+            let mut statement =
+                connection.prepare("SELECT factor_position, date, value FROM factor_values")?;
+            let mut rows = statement.query([])?;
+
+            while let Some(row) = rows.next()? {
+                // The factor was removed.
+                let Some(factor) = application_data.factors.get_mut(row.get::<_, usize>(0)?) else {
+                    continue;
+                };
+
+                factor.values_per_date.insert(
+                    row.get(1)?,
+                    serde_json::from_str(&row.get::<_, String>(2)?)?,
+                );
+            }
+        }
+        application_data.medications = read_values(connection, "medications")?;
+
+        // Read the intakes of the medications.
+        {
+            let mut statement =
+                connection.prepare("SELECT medication_position, date FROM medication_intakes")?;
+            let mut rows = statement.query([])?;
+
+            while let Some(row) = rows.next()? {
+                // The medication was removed.
+                let Some(medication) = application_data
+                    .medications
+                    .get_mut(row.get::<_, usize>(0)?)
+                else {
+                    continue;
+                };
+
+                medication.intake_dates.insert(row.get(1)?);
+            }
+        }
+        application_data.inquiries = read_values(connection, "inquiries")?;
+        application_data.quotes = read_values(connection, "quotes")?;
+        application_data.thought_records = read_values(connection, "thought_records")?;
+
+        let mut statement = connection.prepare("SELECT name, value FROM unknown_fields")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            application_data.unknown_fields.insert(
+                row.get(0)?,
+                serde_json::from_str(&row.get::<_, String>(1)?)?,
+            );
+        }
+
+        Ok(application_data)
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn close(&mut self) -> Result<(), Error> {
+        if let Some(connection) = self.connection.take() {
+            connection.close().map_err(|(_, error)| error)?;
+        }
+
+        Ok(())
+    }
+
+    fn load(&mut self, _today: NaiveDate) -> Result<ApplicationData, Error> {
+        let application_data = self.read()?;
+        self.saved_application_data = Some(application_data.clone());
+
+        Ok(application_data)
+    }
+
+    fn mood_values_between(
+        &mut self,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+        _today: NaiveDate,
+    ) -> Result<BTreeMap<NaiveDate, HashSet<u8>>, Error> {
+        // Nothing was saved yet.
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        read_mood_values(
+            self.connection()?,
+            "SELECT date, mood_values FROM mood_values WHERE date BETWEEN ?1 AND ?2",
+            params![first_date, last_date],
+        )
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn save(&mut self, application_data: &ApplicationData) -> Result<(), Error> {
+        // Compare with the stored data, if it was neither loaded nor saved yet.
+        let saved_application_data = match self.saved_application_data.take() {
+            Some(saved_application_data) => saved_application_data,
+            None => self.read()?,
+        };
+
+        let transaction = self.connection()?.transaction()?;
+        write_changes(&transaction, application_data, &saved_application_data)?;
+        transaction.commit()?;
+
+        self.saved_application_data = Some(application_data.clone());
+
+        Ok(())
+    }
+}
+
+// =================================================================================================
+
+/// Reads the mood values per date with the given query, which selects the date and the mood values.
+fn read_mood_values(
+    connection: &Connection,
+    sql: &str,
+    parameters: impl rusqlite::Params,
+) -> Result<BTreeMap<NaiveDate, HashSet<u8>>, Error> {
+    let mut statement = connection.prepare_cached(sql)?;
+    let mut rows = statement.query(parameters)?;
+    let mut mood_per_date = BTreeMap::new();
+
+    while let Some(row) = rows.next()? {
+        mood_per_date.insert(
+            row.get(0)?,
+            serde_json::from_str(&row.get::<_, String>(1)?)?,
+        );
+    }
+
+    Ok(mood_per_date)
+}
+
+/// Reads the values of the given table per date.
+fn read_values_per_date<T: DeserializeOwned>(
+    connection: &Connection,
+    table: &str,
+) -> Result<BTreeMap<NaiveDate, T>, Error> {
+    let mut statement = connection.prepare(&format!("SELECT date, value FROM {}", table))?;
+    let mut rows = statement.query([])?;
+    let mut values_per_date = BTreeMap::new();
+
+    while let Some(row) = rows.next()? {
+        values_per_date.insert(
+            row.get(0)?,
+            serde_json::from_str(&row.get::<_, String>(1)?)?,
+        );
+    }
+
+    Ok(values_per_date)
+}
+
+/// Reads the values of the given list table, in the order of their position.
+fn read_values<T: DeserializeOwned>(connection: &Connection, table: &str) -> Result<Vec<T>, Error> {
+    let mut statement =
+        connection.prepare(&format!("SELECT value FROM {} ORDER BY position", table))?;
+    let mut rows = statement.query([])?;
+    let mut values = vec![];
+
+    while let Some(row) = rows.next()? {
+        values.push(serde_json::from_str(&row.get::<_, String>(0)?)?);
+    }
+
+    Ok(values)
+}
+
+/// The rows of a table by their key, with their serialized values.
+type Rows<K, V = String> = BTreeMap<K, V>;
+
+/// Writes the rows of the application data that differ from the saved application data and deletes
+/// the rows that are not there anymore.
+fn write_changes(
+    transaction: &Transaction,
+    application_data: &ApplicationData,
+    saved_application_data: &ApplicationData,
+) -> Result<(), Error> {
+    for (table, value_column, values_per_date) in [
+        (
+            "mood_values",
+            "mood_values",
+            mood_rows as fn(&ApplicationData) -> _,
+        ),
+        ("notes", "value", |application_data| {
+            rows_per_date(&application_data.note_per_date)
+        }),
+        ("sleep", "value", |application_data| {
+            rows_per_date(&application_data.sleep_per_date)
+        }),
+        ("tags", "value", |application_data| {
+            rows_per_date(&application_data.tags_per_date)
+        }),
+    ] {
+        write_rows_per_date(
+            transaction,
+            table,
+            value_column,
+            &values_per_date(application_data)?,
+            &values_per_date(saved_application_data)?,
+        )?;
+    }
+
+    // The values of the dimensions, the factors and the medications are written per position and
+    // date, their definitions as a list.
+    write_rows_per_position(
+        transaction,
+        "dimension_values",
+        "dimension_position",
+        "mood_values",
+        &dimension_value_rows(&application_data.dimensions)?,
+        &dimension_value_rows(&saved_application_data.dimensions)?,
+    )?;
+    write_rows_per_position(
+        transaction,
+        "factor_values",
+        "factor_position",
+        "value",
+        &factor_value_rows(&application_data.factors)?,
+        &factor_value_rows(&saved_application_data.factors)?,
+    )?;
+    write_medication_intakes(
+        transaction,
+        &medication_intake_rows(&application_data.medications),
+        &medication_intake_rows(&saved_application_data.medications),
+    )?;
+
+    for (table, rows) in [
+        ("dimensions", dimension_rows as fn(&ApplicationData) -> _),
+        ("factors", |application_data| {
+            list_rows(
+                &application_data
+                    .factors
+                    .iter()
+                    .map(Factor::definition)
+                    .collect::<Vec<_>>(),
+                |_| None,
+            )
+        }),
+        ("medications", |application_data| {
+            list_rows(
+                &application_data
+                    .medications
+                    .iter()
+                    .map(Medication::definition)
+                    .collect::<Vec<_>>(),
+                |medication| Some(medication.start_date),
+            )
+        }),
+        ("inquiries", |application_data| {
+            list_rows(&application_data.inquiries, |inquiry| {
+                Some(inquiry.date_time.date())
+            })
+        }),
+        ("quotes", |application_data| {
+            list_rows(&application_data.quotes, |_| None)
+        }),
+        ("thought_records", |application_data| {
+            list_rows(&application_data.thought_records, |thought_record| {
+                Some(thought_record.date)
+            })
+        }),
+    ] {
+        write_list_rows(
+            transaction,
+            table,
+            &rows(application_data)?,
+            &rows(saved_application_data)?,
+        )?;
+    }
+
+    // Replace the unknown fields, if they changed.
+    if application_data.unknown_fields != saved_application_data.unknown_fields {
+        transaction.execute("DELETE FROM unknown_fields", [])?;
+
+        for (name, value) in &application_data.unknown_fields {
+            transaction.execute(
+                "INSERT INTO unknown_fields (name, value) VALUES (?1, ?2)",
+                params![name, value.to_string()],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calls `upsert` for the rows that are new or differ from the saved rows and `delete` for the
+/// keys of the saved rows that are not there anymore.
+fn for_each_change<K: Ord, V: PartialEq>(
+    rows: &Rows<K, V>,
+    saved_rows: &Rows<K, V>,
+    mut upsert: impl FnMut(&K, &V) -> Result<(), Error>,
+    mut delete: impl FnMut(&K) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for (key, value) in rows {
+        if saved_rows.get(key) != Some(value) {
+            upsert(key, value)?;
+        }
+    }
+
+    for key in saved_rows.keys() {
+        if !rows.contains_key(key) {
+            delete(key)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Serializes the mood values in order, so that equal values give equal rows.
+fn mood_values_json(mood_values: &HashSet<u8>) -> Result<String, Error> {
+    let mut mood_values: Vec<&u8> = mood_values.iter().collect();
+    mood_values.sort();
+
+    Ok(serde_json::to_string(&mood_values)?)
+}
+
+/// Returns the rows of the mood values per date.
+fn mood_rows(application_data: &ApplicationData) -> Result<Rows<NaiveDate>, Error> {
+    application_data
+        .mood_per_date
+        .iter()
+        .map(|(date, mood_values)| Ok((*date, mood_values_json(mood_values)?)))
+        .collect()
+}
+
+/// Returns the rows of the given values per date.
+fn rows_per_date<T: Serialize>(
+    values_per_date: &BTreeMap<NaiveDate, T>,
+) -> Result<Rows<NaiveDate>, Error> {
+    values_per_date
+        .iter()
+        .map(|(date, value)| Ok((*date, serde_json::to_string(value)?)))
+        .collect()
+}
+
+/// Writes the changed rows to the given table per date and deletes the ones of dates that have
+/// none anymore.
+fn write_rows_per_date(
+    transaction: &Transaction,
+    table: &str,
+    value_column: &str,
+    rows: &Rows<NaiveDate>,
+    saved_rows: &Rows<NaiveDate>,
+) -> Result<(), Error> {
+    let mut upsert_statement = transaction.prepare_cached(&format!(
+        "INSERT INTO {} (date, {}) VALUES (?1, ?2)
+         ON CONFLICT (date) DO UPDATE SET {} = excluded.{}",
+        table, value_column, value_column, value_column
+    ))?;
+    let mut delete_statement =
+        transaction.prepare_cached(&format!("DELETE FROM {} WHERE date = ?1", table))?;
+
+    for_each_change(
+        rows,
+        saved_rows,
+        |date, value| {
+            upsert_statement.execute(params![date, value])?;
+            Ok(())
+        },
+        |date| {
+            delete_statement.execute(params![date])?;
+            Ok(())
+        },
+    )
+}
+
+/// Returns the rows of the dimensions' values per position and date.
+fn dimension_value_rows(dimensions: &[Dimension]) -> Result<Rows<(usize, NaiveDate)>, Error> {
+    let mut rows = BTreeMap::new();
+
+    for (position, dimension) in dimensions.iter().enumerate() {
+        for (date, mood_values) in &dimension.values_per_date {
+            rows.insert((position, *date), mood_values_json(mood_values)?);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Returns the rows of the factors' values per position and date.
+fn factor_value_rows(factors: &[Factor]) -> Result<Rows<(usize, NaiveDate)>, Error> {
+    let mut rows = BTreeMap::new();
+
+    for (position, factor) in factors.iter().enumerate() {
+        for (date, value) in &factor.values_per_date {
+            rows.insert((position, *date), serde_json::to_string(value)?);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Writes the changed rows to the given table per position and date and deletes the ones of dates
+/// that have none anymore, or of positions beyond the list's end.
+fn write_rows_per_position(
+    transaction: &Transaction,
+    table: &str,
+    position_column: &str,
+    value_column: &str,
+    rows: &Rows<(usize, NaiveDate)>,
+    saved_rows: &Rows<(usize, NaiveDate)>,
+) -> Result<(), Error> {
+    let mut upsert_statement = transaction.prepare_cached(&format!(
+        "INSERT INTO {} ({}, date, {}) VALUES (?1, ?2, ?3)
+         ON CONFLICT ({}, date) DO UPDATE SET {} = excluded.{}",
+        table, position_column, value_column, position_column, value_column, value_column
+    ))?;
+    let mut delete_statement = transaction.prepare_cached(&format!(
+        "DELETE FROM {} WHERE {} = ?1 AND date = ?2",
+        table, position_column
+    ))?;
+
+    for_each_change(
+        rows,
+        saved_rows,
+        |(position, date), value| {
+            upsert_statement.execute(params![position, date, value])?;
+            Ok(())
+        },
+        |(position, date)| {
+            delete_statement.execute(params![position, date])?;
+            Ok(())
+        },
+    )
+}
+
+/// Returns the intakes of the medications per position and date.
+fn medication_intake_rows(medications: &[Medication]) -> Rows<(usize, NaiveDate), ()> {
+    medications
+        .iter()
+        .enumerate()
+        .flat_map(|(position, medication)| {
+            medication
+                .intake_dates
+                .iter()
+                .map(move |date| ((position, *date), ()))
+        })
+        .collect()
+}
+
+/// Writes the new intakes of the medications and deletes the removed ones, or the ones of
+/// positions beyond the list's end.
+fn write_medication_intakes(
+    transaction: &Transaction,
+    rows: &Rows<(usize, NaiveDate), ()>,
+    saved_rows: &Rows<(usize, NaiveDate), ()>,
+) -> Result<(), Error> {
+    let mut insert_statement = transaction.prepare_cached(
+        "INSERT INTO medication_intakes (medication_position, date) VALUES (?1, ?2)
+         ON CONFLICT (medication_position, date) DO NOTHING",
+    )?;
+    let mut delete_statement = transaction.prepare_cached(
+        "DELETE FROM medication_intakes WHERE medication_position = ?1 AND date = ?2",
+    )?;
+
+    for_each_change(
+        rows,
+        saved_rows,
+        |(position, date), _| {
+            insert_statement.execute(params![position, date])?;
+            Ok(())
+        },
+        |(position, date)| {
+            delete_statement.execute(params![position, date])?;
+            Ok(())
+        },
+    )
+}
+
+/// Returns the rows of the dimensions' definitions.
+fn dimension_rows(
+    application_data: &ApplicationData,
+) -> Result<Rows<usize, (Option<NaiveDate>, String)>, Error> {
+    list_rows(
+        &application_data
+            .dimensions
+            .iter()
+            .map(Dimension::definition)
+            .collect::<Vec<_>>(),
+        |_| None,
+    )
+}
+
+/// Returns the rows of the given list per position, with the date to query them by.
+fn list_rows<T: Serialize>(
+    values: &[T],
+    date: impl Fn(&T) -> Option<NaiveDate>,
+) -> Result<Rows<usize, (Option<NaiveDate>, String)>, Error> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(position, value)| Ok((position, (date(value), serde_json::to_string(value)?))))
+        .collect()
+}
+
+/// Writes the changed rows to the given list table and deletes the ones beyond the list's end.
+fn write_list_rows(
+    transaction: &Transaction,
+    table: &str,
+    rows: &Rows<usize, (Option<NaiveDate>, String)>,
+    saved_rows: &Rows<usize, (Option<NaiveDate>, String)>,
+) -> Result<(), Error> {
+    let mut upsert_statement = transaction.prepare_cached(&format!(
+        "INSERT INTO {} (position, date, value) VALUES (?1, ?2, ?3)
+         ON CONFLICT (position) DO UPDATE SET date = excluded.date, value = excluded.value",
+        table
+    ))?;
+    let mut delete_statement =
+        transaction.prepare_cached(&format!("DELETE FROM {} WHERE position = ?1", table))?;
+
+    for_each_change(
+        rows,
+        saved_rows,
+        |position, (date, value)| {
+            upsert_statement.execute(params![position, date, value])?;
+            Ok(())
+        },
+        |position| {
+            delete_statement.execute(params![position])?;
+            Ok(())
+        },
+    )
+}
+
+// =================================================================================================
+
+/// Creates the tables of schema version 1. They may exist, if the database was written before it
+/// had a version.
+fn create_schema_version_1(transaction: &Transaction) -> Result<(), Error> {
+    transaction.execute_batch(SCHEMA_VERSION_1)?;
+
+    Ok(())
+}
+
+/// Version 1 stored the values of the dimensions and the factors and the intakes of the
+/// medications per name. Version 2 stores them per position. The rows of a name are assigned to the
+/// first definition with that name, the ones of names without a definition are dropped.
+fn migrate_from_schema_version_1(transaction: &Transaction) -> Result<(), Error> {
+    transaction.execute_batch(SCHEMA_VERSION_2)?;
+
+    for (list_table, table, name_column, position_column, value_columns) in [
+        (
+            "dimensions",
+            "dimension_values",
+            "dimension",
+            "dimension_position",
+            ", mood_values",
+        ),
+        (
+            "factors",
+            "factor_values",
+            "factor",
+            "factor_position",
+            ", value",
+        ),
+        (
+            "medications",
+            "medication_intakes",
+            "medication",
+            "medication_position",
+            "",
+        ),
+    ] {
+        let mut position_per_name = HashMap::new();
+
+        // Find the first position of each name.
+        {
+            let mut statement = transaction.prepare(&format!(
+                "SELECT position, value FROM {} ORDER BY position",
+                list_table
+            ))?;
+            let mut rows = statement.query([])?;
+
+            while let Some(row) = rows.next()? {
+                let definition: serde_json::Value =
+                    serde_json::from_str(&row.get::<_, String>(1)?)?;
+
+                if let Some(name) = definition.get("name").and_then(serde_json::Value::as_str) {
+                    position_per_name
+                        .entry(name.to_string())
+                        .or_insert(row.get::<_, i64>(0)?);
+                }
+            }
+        }
+
+        // Copy the rows of each name to its position.
+        {
+            let mut statement = transaction.prepare(&format!(
+                "INSERT INTO {} ({}, date{}) SELECT ?1, date{} FROM {}_by_name WHERE {} = ?2",
+                table, position_column, value_columns, value_columns, table, name_column
+            ))?;
+
+            for (name, position) in position_per_name {
+                statement.execute(params![position, name])?;
+            }
+        }
+
+        transaction.execute_batch(&format!("DROP TABLE {}_by_name", table))?;
+    }
+
+    Ok(())
+}
+
+// =================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{FactorValue, Track};
+    use std::env;
+
+    /// Returns the date of the given day in September 2026.
+    fn september(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+    }
+
+    /// Returns the number of rows changed since the connection was opened.
+    fn total_changes(storage: &mut SqliteStorage) -> i64 {
+        storage
+            .connection()
+            .unwrap()
+            .query_row("SELECT total_changes()", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn saving_writes_only_the_changed_rows() {
+        let directory = env::temp_dir().join(format!("freundchen-sqlite-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        let mut storage = SqliteStorage::new(directory.join("freundchen.sqlite"));
+
+        let mut application_data = ApplicationData::new();
+        for day in 1..=30 {
+            application_data.set_mood_values(Track::Mood, september(day), [5].into());
+        }
+        storage.save(&application_data).unwrap();
+        let changes_after_first_save = total_changes(&mut storage);
+
+        storage.save(&application_data).unwrap();
+        let changes_of_unchanged_save = total_changes(&mut storage) - changes_after_first_save;
+
+        application_data.set_mood_values(Track::Mood, september(3), [8].into());
+        application_data.mood_per_date.remove(&september(4));
+        storage.save(&application_data).unwrap();
+        let changes_of_two_days = total_changes(&mut storage) - changes_after_first_save;
+
+        storage.close().unwrap();
+
+        // A newly opened storage compares with the stored rows.
+        let mut storage = SqliteStorage::new(directory.join("freundchen.sqlite"));
+        application_data.mood_per_date.remove(&september(5));
+        storage.save(&application_data).unwrap();
+        let changes_after_reopening = total_changes(&mut storage);
+        let mood_per_date = storage.load(september(15)).unwrap().mood_per_date;
+        storage.close().unwrap();
+        let _ = fs::remove_dir_all(&directory);
+
+        assert_eq!(changes_after_first_save, 30);
+        assert_eq!(changes_of_unchanged_save, 0);
+        assert_eq!(changes_of_two_days, 2);
+        assert_eq!(changes_after_reopening, 1);
+        assert_eq!(mood_per_date, application_data.mood_per_date);
+    }
+
+    #[test]
+    fn a_version_1_database_is_migrated_to_values_per_position() {
+        let directory = env::temp_dir().join(format!(
+            "freundchen-sqlite-version-1-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("freundchen.sqlite");
+
+        // Two factors named "Walk", one named "Run" and the values of a removed factor.
+        let connection = Connection::open(&path).unwrap();
+        connection.execute_batch(SCHEMA_VERSION_1).unwrap();
+        connection
+            .execute_batch(
+                r#"
+                INSERT INTO factors (position, value) VALUES
+                    (0, '{"name":"Run","kind":{"type":"boolean"}}'),
+                    (1, '{"name":"Walk","kind":{"type":"boolean"}}'),
+                    (2, '{"name":"Walk","kind":{"type":"boolean"}}');
+                INSERT INTO factor_values (factor, date, value) VALUES
+                    ('Run', '2026-09-01', 'true'),
+                    ('Walk', '2026-09-02', 'false'),
+                    ('Swim', '2026-09-03', 'true');
+                "#,
+            )
+            .unwrap();
+        connection.pragma_update(None, "user_version", 1).unwrap();
+        connection.close().unwrap();
+
+        let mut storage = SqliteStorage::new(path.clone());
+        let application_data = storage.load(september(15)).unwrap();
+        let version: u32 = storage
+            .connection()
+            .unwrap()
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        storage.close().unwrap();
+        let _ = fs::remove_dir_all(&directory);
+
+        let values: Vec<_> = application_data
+            .factors
+            .iter()
+            .map(|factor| factor.values_per_date.clone())
+            .collect();
+
+        assert_eq!(version, SCHEMA_VERSION);
+        assert_eq!(
+            values,
+            vec![
+                [(september(1), FactorValue::Boolean(true))].into(),
+                [(september(2), FactorValue::Boolean(false))].into(),
+                BTreeMap::new(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,287 @@
+## Dates
+
+date = { $day }. { $month } { $year }
+date-time = { $date }, { $time } Uhr
+day-of-month = { $day }.
+list = { $items } und { $last }
+month-year = { $month } { $year }
+month-1 = Januar
+month-2 = Februar
+month-3 = März
+month-4 = April
+month-5 = Mai
+month-6 = Juni
+month-7 = Juli
+month-8 = August
+month-9 = September
+month-10 = Oktober
+month-11 = November
+month-12 = Dezember
+
+## Common
+
+back = Zurück
+next = Weiter
+previous = Vorherige
+save = Speichern
+saved = Gespeichert.
+
+## Toolbar
+
+toolbar-mood = Stimmung
+toolbar-thought-records = Gedankenprotokolle
+toolbar-the-work = The Work
+toolbar-quotes = Zitate
+toolbar-tags = Gefühle
+toolbar-sleep = Schlaf
+toolbar-medications = Medikamente
+toolbar-settings = Einstellungen
+
+## Banner
+
+banner-dismiss = Ausblenden
+error-occurred = Etwas ist schiefgelaufen: { $reason }
+data-recovered = Deine Daten konnten nicht gelesen werden und wurden nach „{ $path }“ verschoben, die lesbaren Einträge wurden übernommen. { $count ->
+    [0] Es konnten keine Stimmungswerte wiederhergestellt werden.
+    [one] Ein Tag mit Stimmungswerten wurde wiederhergestellt.
+   *[other] { $count } Tage mit Stimmungswerten wurden wiederhergestellt.
+} ({ $reason })
+
+## Save failure
+
+save-failure-title = Deine Daten konnten nicht gespeichert werden
+save-failure-message = Das Speichern nach „{ $path }“ ist fehlgeschlagen: { $reason }
+save-failure-retry = Erneut versuchen
+save-failure-save-as-label = In eine andere Datei speichern
+save-failure-save-as = Speichern und beenden
+save-failure-quit = Ohne Speichern beenden
+
+## Dashboard
+
+dashboard-clear = Leeren
+dashboard-close = Schließen
+selection-days = { $count ->
+    [one] Ein Tag ausgewählt
+   *[other] { $count } Tage ausgewählt
+}
+selection-clear = Tage leeren
+selection-copy = Ersten Tag kopieren
+selection-paste = Einfügen
+selection-note-label = Notiz:
+selection-add-note = Notiz hinzufügen
+selection-deselect = Auswahl aufheben
+dimension-mood = Stimmung
+dimension-switch = Nächste Dimension
+dashboard-filter = Nur Tage mit: { $tag }
+dashboard-show-all-days = Alle Tage zeigen
+factor-panel-day = Faktoren am { $date }
+factor-panel-days = Faktoren vom { $first-date } bis { $last-date }
+factor-set = Setzen
+factor-yes = Ja
+factor-no = Nein
+factor-none = –
+greeting-morning = Guten Morgen
+greeting-afternoon = Guten Tag
+greeting-evening = Guten Abend
+greeting-night = Hallo
+greeting-with-name = { $salutation }, { $name }
+greeting-not-logged = { $address } — wie geht es dir heute?
+greeting-logged = { $address } — du hast heute { $count ->
+    [one] eine { $mood-values }
+   *[other] { $mood-values }
+} eingetragen
+
+## Thought records
+
+thought-record-title = Gedankenprotokoll vom { $date }
+thought-record-situation = Situation
+thought-record-automatic-thought = Automatischer Gedanke
+thought-record-emotion = Gefühl
+thought-record-emotion-intensity = Intensität (0-100 %)
+thought-record-evidence-for = Was für den Gedanken spricht
+thought-record-evidence-against = Was gegen den Gedanken spricht
+thought-record-balanced-thought = Ausgewogener Gedanke
+thought-record-emotion-intensity-rerated = Intensität jetzt (0-100 %)
+thought-record-past-records = Frühere Gedankenprotokolle
+thought-record-automatic-thought-missing = Bitte schreib den automatischen Gedanken auf.
+thought-record-intensity-invalid = „{ $field }“ braucht eine Zahl von 0 bis { $maximum }.
+thought-record-none = Es gibt noch keine Gedankenprotokolle.
+thought-record-header = { $date } ({ $index }/{ $count })
+thought-record-show-situation = Situation: { $situation }
+thought-record-show-automatic-thought = Automatischer Gedanke: { $automatic-thought }
+thought-record-show-emotion = Gefühl: { $emotion } { $intensity } % → { $intensity-rerated } %
+thought-record-show-evidence-for = Dafür spricht: { $evidence }
+thought-record-show-evidence-against = Dagegen spricht: { $evidence }
+thought-record-show-balanced-thought = Ausgewogener Gedanke: { $balanced-thought }
+thought-record-show-mood = Stimmung an dem Tag: { $mood-values }
+
+## The Work
+
+inquiry-title = The Work
+inquiry-step = Schritt { $index } von { $count }
+inquiry-prompt-belief = Welchen Glaubenssatz möchtest du hinterfragen?
+inquiry-prompt-question-1 = 1. Ist das wahr?
+inquiry-prompt-question-2 = 2. Kannst du mit absoluter Sicherheit wissen, dass das wahr ist?
+inquiry-prompt-question-3 = 3. Wie reagierst du, was passiert, wenn du diesen Gedanken glaubst?
+inquiry-prompt-question-4 = 4. Wer wärst du ohne den Gedanken?
+inquiry-prompt-turnaround-opposite = Kehre den Gedanken ins Gegenteil um. Ist das genauso wahr oder wahrer?
+inquiry-prompt-turnaround-self = Kehre den Gedanken zu dir selbst um. Ist das genauso wahr oder wahrer?
+inquiry-prompt-turnaround-other = Kehre den Gedanken zum anderen um. Ist das genauso wahr oder wahrer?
+inquiry-belief-missing = Bitte schreib zuerst einen Glaubenssatz auf.
+inquiry-beliefs = Deine Glaubenssätze im Lauf der Zeit
+inquiry-previous-belief = Vorheriger Glaubenssatz
+inquiry-next-belief = Nächster Glaubenssatz
+inquiry-again = Erneut hinterfragen
+inquiry-none = Du hast noch keine Glaubenssätze hinterfragt.
+inquiry-belief-header = „{ $belief }“ ({ $index }/{ $count }), { $inquiries ->
+    [one] einmal
+   *[other] { $inquiries }-mal
+} hinterfragt:
+inquiry-history = { $date-time }: { $answers } | Umkehrungen: { $turnarounds }
+
+## Quotes
+
+quotes-title = Zitate
+quotes-text = Zitat
+quotes-author = Autor
+quotes-tags = Schlagwörter (durch Kommas getrennt)
+quotes-add = Hinzufügen
+quotes-import-label = Aus einer Text- oder JSON-Datei importieren
+quotes-import = Importieren
+quotes-yours = Deine Zitate
+quotes-favourite = Favorit
+quotes-remove = Entfernen
+quotes-none = Es gibt noch keine Zitate.
+quotes-show-tags = Schlagwörter: { $tags }
+quotes-text-missing = Bitte schreib zuerst das Zitat auf.
+quotes-added = Hinzugefügt.
+quotes-removed = Entfernt.
+quotes-imported = { $count ->
+    [one] Ein Zitat
+   *[other] { $count } Zitate
+} aus „{ $path }“ importiert.
+quotes-import-failed = „{ $path }“ konnte nicht importiert werden: { $reason }
+
+## Feelings
+
+tag-picker-title = Gefühle am { $date }
+tag-picker-custom-label = Eigener Tag
+tag-picker-custom-toggle = Hinzufügen oder entfernen
+tag-picker-custom-tags = { $count ->
+    [0] Keine eigenen Tags
+   *[other] Eigene Tags: { $tags }
+}
+tag-picker-done = Fertig
+tags-title = Gefühle im { $month }
+tags-none = In diesem Monat wurden noch keine Tage getaggt. Klicke einen Tag mit der rechten Maustaste an, um ihn zu taggen.
+tags-filter-label = Gefühl oder Tag
+tags-filter = Nur diese Tage zeigen
+tags-show-all = Alle Tage zeigen
+feeling-joy = Freude
+feeling-content = Zufrieden
+feeling-proud = Stolz
+feeling-optimistic = Optimistisch
+feeling-playful = Verspielt
+feeling-grateful = Dankbar
+feeling-hopeful = Hoffnungsvoll
+feeling-sadness = Traurigkeit
+feeling-lonely = Einsam
+feeling-hurt = Verletzt
+feeling-guilty = Schuldig
+feeling-disappointed = Enttäuscht
+feeling-hopeless = Hoffnungslos
+feeling-bored = Gelangweilt
+feeling-anger = Wut
+feeling-frustrated = Frustriert
+feeling-irritated = Gereizt
+feeling-resentful = Verbittert
+feeling-jealous = Eifersüchtig
+feeling-annoyed = Genervt
+feeling-fear = Angst
+feeling-anxious = Ängstlich
+feeling-insecure = Unsicher
+feeling-overwhelmed = Überfordert
+feeling-worried = Besorgt
+feeling-scared = Verängstigt
+feeling-surprise = Überraschung
+feeling-amazed = Erstaunt
+feeling-confused = Verwirrt
+feeling-startled = Erschrocken
+feeling-excited = Aufgeregt
+feeling-disgust = Ekel
+feeling-disapproving = Missbilligend
+feeling-embarrassed = Beschämt
+feeling-awful = Elend
+feeling-repelled = Abgestoßen
+
+## Sleep
+
+sleep-title = Schlaf in der Nacht nach dem { $date }
+sleep-bedtime = Zubettgehen (HH:MM)
+sleep-wake-time = Aufwachen (HH:MM)
+sleep-quality = Qualität (1 bis 5)
+sleep-awakenings = Wachphasen
+sleep-duration = { $hours } h { $minutes } min Schlaf
+sleep-remove = Entfernen
+sleep-invalid-time = „{ $time }“ ist keine Uhrzeit wie 23:30 für „{ $field }“.
+sleep-same-times = Die Aufwachzeit muss sich von der Zeit des Zubettgehens unterscheiden.
+sleep-invalid-quality = Die Qualität muss eine Zahl von 1 bis { $maximum } sein.
+sleep-invalid-awakenings = Die Wachphasen müssen eine ganze Zahl sein.
+
+## Medications
+
+medications-title = Medikamente
+medications-none = Noch keine Medikamente. Füge sie mit `freundchen-cli medication add` hinzu, während freundchen geschlossen ist.
+medications-today = Heute genommen
+medications-calendar-title = Dieser Monat
+medications-daily = täglich
+medications-as-needed = bei Bedarf
+medications-every-n-days = alle { $interval } Tage
+medications-dose-from = { $dose } ab dem { $date }
+medications-stopped-on = abgesetzt am { $date }
+medications-adherence = An { $taken } von { $scheduled } geplanten Tagen genommen ({ $percent } %)
+medications-intakes = { $count ->
+    [one] An einem Tag genommen
+   *[other] An { $count } Tagen genommen
+}
+medications-legend = Helle Tage wurden genommen, dunkle verpasst. Klicke einen Tag an, um ihn abzuhaken.
+
+## Settings
+
+settings-title = Einstellungen
+settings-preferred-name = Wie möchtest du angesprochen werden?
+settings-locale = Sprache (etwa „de-DE“, leer für die Systemsprache)
+settings-data-path = Datendatei (leer für die Voreinstellung)
+settings-window-width = Fensterbreite
+settings-window-height = Fensterhöhe
+settings-greeting-font-size = Schriftgröße der Begrüßung
+settings-restart-hint = Änderungen an der Fenstergröße werden nach einem Neustart wirksam.
+settings-theme = Farbschema („dark“, „light“, „viridis“, „cividis“ oder ein eigenes)
+settings-log-level = Protokollstufe („off“, „error“, „warn“, „info“, „debug“ oder „trace“)
+settings-invalid-theme = Das Farbschema „{ $path }“ konnte nicht gelesen werden: { $reason }
+settings-unknown-theme = Es gibt kein Farbschema „{ $name }“. Wähle { $themes } oder lege „{ $name }.toml“ in „{ $directory }“ ab.
+settings-not-a-number = „{ $field }“ muss eine Zahl sein.
+settings-invalid-data-path = „{ $path }“ ist kein Dateipfad.
+settings-invalid-font-size = Die Schriftgröße { $size } muss zwischen { $minimum } und { $maximum } liegen.
+settings-invalid-locale = „{ $locale }“ ist keine Sprache wie „en“ oder „de-DE“.
+settings-invalid-log-level = „{ $level }“ ist keine Protokollstufe wie „info“ oder „debug“.
+settings-invalid-window-size = Die Fenstergröße { $width } × { $height } muss in beiden Richtungen zwischen { $minimum } und { $maximum } liegen.
+
+## Report
+
+report-title = Bericht vom { $first-date } bis zum { $last-date }
+report-summary = Zusammenfassung
+report-days-with-mood-values = Stimmungswerte an { $count } von { $days } Tagen
+report-mood-values = Durchschnittlicher Stimmungswert { $average }, niedrigster { $minimum }, höchster { $maximum }
+report-thought-records = Gedankenprotokolle
+report-thought-record =
+    { $date }: { $situation }
+    Automatischer Gedanke: { $automatic-thought } ({ $emotion }, { $intensity } %)
+    Ausgewogener Gedanke: { $balanced-thought } ({ $emotion }, { $intensity-rerated } %)
+report-inquiries = The Work
+report-inquiry = { $date }: „{ $belief }“
+report-turnarounds = Umkehrungen: { $turnarounds }
+report-factors = Faktoren
+report-factor = { $name }: ja an { $count } von { $days } erfassten Tagen
+report-medications = Medikamente
+report-medication = { $name }: { $adherence }
@@ -0,0 +1,287 @@
+## Dates
+
+date = { $month } { $day }, { $year }
+date-time = { $date }, { $time }
+day-of-month = { $day }
+list = { $items } and { $last }
+month-year = { $month } { $year }
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
+
+## Common
+
+back = Back
+next = Next
+previous = Previous
+save = Save
+saved = Saved.
+
+## Toolbar
+
+toolbar-mood = Mood
+toolbar-thought-records = Thought records
+toolbar-the-work = The Work
+toolbar-quotes = Quotes
+toolbar-tags = Feelings
+toolbar-sleep = Sleep
+toolbar-medications = Medications
+toolbar-settings = Settings
+
+## Banner
+
+banner-dismiss = Dismiss
+error-occurred = Something went wrong: { $reason }
+data-recovered = Your data could not be read and was moved to "{ $path }", the readable entries were kept. { $count ->
+    [0] No mood values could be recovered.
+    [one] One day of mood values was recovered.
+   *[other] { $count } days of mood values were recovered.
+} ({ $reason })
+
+## Save failure
+
+save-failure-title = Your data could not be saved
+save-failure-message = Saving to "{ $path }" failed: { $reason }
+save-failure-retry = Try again
+save-failure-save-as-label = Save to another file
+save-failure-save-as = Save and quit
+save-failure-quit = Quit without saving
+
+## Dashboard
+
+dashboard-clear = Clear
+dashboard-close = Close
+selection-days = { $count ->
+    [one] One day selected
+   *[other] { $count } days selected
+}
+selection-clear = Clear days
+selection-copy = Copy first day
+selection-paste = Paste
+selection-note-label = Note:
+selection-add-note = Add note
+selection-deselect = Deselect
+dimension-mood = Mood
+dimension-switch = Next dimension
+dashboard-filter = Only days with: { $tag }
+dashboard-show-all-days = Show all days
+factor-panel-day = Factors on { $date }
+factor-panel-days = Factors from { $first-date } to { $last-date }
+factor-set = Set
+factor-yes = Yes
+factor-no = No
+factor-none = –
+greeting-morning = Good morning
+greeting-afternoon = Good afternoon
+greeting-evening = Good evening
+greeting-night = Hi
+greeting-with-name = { $salutation }, { $name }
+greeting-not-logged = { $address } — how are you today?
+greeting-logged = { $address } — you logged { $count ->
+    [one] a { $mood-values }
+   *[other] { $mood-values }
+} today
+
+## Thought records
+
+thought-record-title = Thought record of { $date }
+thought-record-situation = Situation
+thought-record-automatic-thought = Automatic thought
+thought-record-emotion = Emotion
+thought-record-emotion-intensity = Intensity (0-100 %)
+thought-record-evidence-for = Evidence for the thought
+thought-record-evidence-against = Evidence against the thought
+thought-record-balanced-thought = Balanced thought
+thought-record-emotion-intensity-rerated = Intensity now (0-100 %)
+thought-record-past-records = Past thought records
+thought-record-automatic-thought-missing = Please write down the automatic thought.
+thought-record-intensity-invalid = "{ $field }" needs a number from 0 to { $maximum }.
+thought-record-none = There are no thought records yet.
+thought-record-header = { $date } ({ $index }/{ $count })
+thought-record-show-situation = Situation: { $situation }
+thought-record-show-automatic-thought = Automatic thought: { $automatic-thought }
+thought-record-show-emotion = Emotion: { $emotion } { $intensity } % → { $intensity-rerated } %
+thought-record-show-evidence-for = Evidence for: { $evidence }
+thought-record-show-evidence-against = Evidence against: { $evidence }
+thought-record-show-balanced-thought = Balanced thought: { $balanced-thought }
+thought-record-show-mood = Mood that day: { $mood-values }
+
+## The Work
+
+inquiry-title = The Work
+inquiry-step = Step { $index } of { $count }
+inquiry-prompt-belief = Which belief would you like to question?
+inquiry-prompt-question-1 = 1. Is it true?
+inquiry-prompt-question-2 = 2. Can you absolutely know that it's true?
+inquiry-prompt-question-3 = 3. How do you react, what happens, when you believe that thought?
+inquiry-prompt-question-4 = 4. Who would you be without the thought?
+inquiry-prompt-turnaround-opposite = Turn the thought around to the opposite. Is that as true or truer?
+inquiry-prompt-turnaround-self = Turn the thought around to yourself. Is that as true or truer?
+inquiry-prompt-turnaround-other = Turn the thought around to the other. Is that as true or truer?
+inquiry-belief-missing = Please write down a belief first.
+inquiry-beliefs = Your beliefs over time
+inquiry-previous-belief = Previous belief
+inquiry-next-belief = Next belief
+inquiry-again = Question it again
+inquiry-none = You have not questioned any beliefs yet.
+inquiry-belief-header = "{ $belief }" ({ $index }/{ $count }), questioned { $inquiries ->
+    [one] once
+   *[other] { $inquiries } times
+}:
+inquiry-history = { $date-time }: { $answers } | turnarounds: { $turnarounds }
+
+## Quotes
+
+quotes-title = Quotes
+quotes-text = Quote
+quotes-author = Author
+quotes-tags = Tags (comma separated)
+quotes-add = Add
+quotes-import-label = Import from a text or JSON file
+quotes-import = Import
+quotes-yours = Your quotes
+quotes-favourite = Favourite
+quotes-remove = Remove
+quotes-none = There are no quotes yet.
+quotes-show-tags = Tags: { $tags }
+quotes-text-missing = Please write down the quote first.
+quotes-added = Added.
+quotes-removed = Removed.
+quotes-imported = Imported { $count ->
+    [one] one quote
+   *[other] { $count } quotes
+} from "{ $path }".
+quotes-import-failed = Could not import "{ $path }": { $reason }
+
+## Feelings
+
+tag-picker-title = Feelings on { $date }
+tag-picker-custom-label = Own tag
+tag-picker-custom-toggle = Add or remove
+tag-picker-custom-tags = { $count ->
+    [0] No own tags
+   *[other] Own tags: { $tags }
+}
+tag-picker-done = Done
+tags-title = Feelings in { $month }
+tags-none = No days were tagged this month yet. Right click a day to tag it.
+tags-filter-label = Feeling or tag
+tags-filter = Show only these days
+tags-show-all = Show all days
+feeling-joy = Joy
+feeling-content = Content
+feeling-proud = Proud
+feeling-optimistic = Optimistic
+feeling-playful = Playful
+feeling-grateful = Grateful
+feeling-hopeful = Hopeful
+feeling-sadness = Sadness
+feeling-lonely = Lonely
+feeling-hurt = Hurt
+feeling-guilty = Guilty
+feeling-disappointed = Disappointed
+feeling-hopeless = Hopeless
+feeling-bored = Bored
+feeling-anger = Anger
+feeling-frustrated = Frustrated
+feeling-irritated = Irritated
+feeling-resentful = Resentful
+feeling-jealous = Jealous
+feeling-annoyed = Annoyed
+feeling-fear = Fear
+feeling-anxious = Anxious
+feeling-insecure = Insecure
+feeling-overwhelmed = Overwhelmed
+feeling-worried = Worried
+feeling-scared = Scared
+feeling-surprise = Surprise
+feeling-amazed = Amazed
+feeling-confused = Confused
+feeling-startled = Startled
+feeling-excited = Excited
+feeling-disgust = Disgust
+feeling-disapproving = Disapproving
+feeling-embarrassed = Embarrassed
+feeling-awful = Awful
+feeling-repelled = Repelled
+
+## Sleep
+
+sleep-title = Sleep in the night after { $date }
+sleep-bedtime = Bedtime (HH:MM)
+sleep-wake-time = Wake time (HH:MM)
+sleep-quality = Quality (1 to 5)
+sleep-awakenings = Awakenings
+sleep-duration = { $hours } h { $minutes } min of sleep
+sleep-remove = Remove
+sleep-invalid-time = "{ $time }" is not a time like 23:30 for "{ $field }".
+sleep-same-times = The wake time needs to differ from the bedtime.
+sleep-invalid-quality = The quality needs to be a number from 1 to { $maximum }.
+sleep-invalid-awakenings = The awakenings need to be a whole number.
+
+## Medications
+
+medications-title = Medications
+medications-none = No medications yet. Add them with `freundchen-cli medication add` while freundchen is closed.
+medications-today = Taken today
+medications-calendar-title = This month
+medications-daily = daily
+medications-as-needed = as needed
+medications-every-n-days = every { $interval } days
+medications-dose-from = { $dose } from { $date }
+medications-stopped-on = stopped on { $date }
+medications-adherence = Taken on { $taken } of { $scheduled } scheduled days ({ $percent } %)
+medications-intakes = { $count ->
+    [one] Taken on one day
+   *[other] Taken on { $count } days
+}
+medications-legend = Bright days were taken, dark days were missed. Click a day to check it off.
+
+## Settings
+
+settings-title = Settings
+settings-preferred-name = How would you like to be addressed?
+settings-locale = Language (like "de-DE", empty for the system language)
+settings-data-path = Data file (empty for the default)
+settings-window-width = Window width
+settings-window-height = Window height
+settings-greeting-font-size = Font size of the greeting
+settings-restart-hint = Changes to the window size take effect after a restart.
+settings-theme = Theme ("dark", "light", "viridis", "cividis" or your own)
+settings-log-level = Log level ("off", "error", "warn", "info", "debug" or "trace")
+settings-invalid-theme = The theme "{ $path }" could not be read: { $reason }
+settings-unknown-theme = There is no theme "{ $name }". Choose { $themes } or put "{ $name }.toml" into "{ $directory }".
+settings-not-a-number = "{ $field }" needs to be a number.
+settings-invalid-data-path = "{ $path }" is not a file path.
+settings-invalid-font-size = The font size { $size } needs to be between { $minimum } and { $maximum }.
+settings-invalid-locale = "{ $locale }" is not a language like "en" or "de-DE".
+settings-invalid-log-level = "{ $level }" is not a log level like "info" or "debug".
+settings-invalid-window-size = The window size { $width } × { $height } needs to be between { $minimum } and { $maximum } in both directions.
+
+## Report
+
+report-title = Report from { $first-date } to { $last-date }
+report-summary = Summary
+report-days-with-mood-values = Mood values on { $count } of { $days } days
+report-mood-values = Average mood value { $average }, lowest { $minimum }, highest { $maximum }
+report-thought-records = Thought records
+report-thought-record =
+    { $date }: { $situation }
+    Automatic thought: { $automatic-thought } ({ $emotion }, { $intensity } %)
+    Balanced thought: { $balanced-thought } ({ $emotion }, { $intensity-rerated } %)
+report-inquiries = The Work
+report-inquiry = { $date }: "{ $belief }"
+report-turnarounds = Turnarounds: { $turnarounds }
+report-factors = Factors
+report-factor = { $name }: yes on { $count } of { $days } recorded days
+report-medications = Medications
+report-medication = { $name }: { $adherence }
@@ -1,21 +1,74 @@
+use crate::settings::SettingsError;
 use guiver::widget::WidgetError;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
 pub enum ApplicationError {
+    /// The command line arguments are invalid.
+    Arguments(String),
+    /// Loading, saving or importing data failed.
+    Core(freundchen_core::Error),
     Io(std::io::Error),
-    Deserde(serde_json::Error),
+    Pdf(printpdf::Error),
+    Render(guiver::Error),
+    Settings(SettingsError),
     Widget(WidgetError),
 }
 
+impl ApplicationError {
+    /// Returns a description of the error for the log. Parser messages are left out, as they can
+    /// quote the data.
+    pub(crate) fn log_message(&self) -> String {
+        match self {
+            ApplicationError::Core(error) => error.log_message(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for ApplicationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplicationError::Arguments(message) => write!(f, "{}", message),
+            ApplicationError::Core(error) => write!(f, "{}", error),
+            ApplicationError::Io(error) => write!(f, "{}", error),
+            ApplicationError::Pdf(error) => write!(f, "Could not write the PDF: {}", error),
+            ApplicationError::Render(error) => write!(f, "Could not render: {:?}", error),
+            ApplicationError::Settings(error) => write!(f, "{}", error),
+            ApplicationError::Widget(error) => write!(f, "Widget error: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for ApplicationError {}
+
 impl From<std::io::Error> for ApplicationError {
     fn from(error: std::io::Error) -> Self {
         ApplicationError::Io(error)
     }
 }
 
-impl From<serde_json::Error> for ApplicationError {
-    fn from(error: serde_json::Error) -> Self {
-        ApplicationError::Deserde(error)
+impl From<freundchen_core::Error> for ApplicationError {
+    fn from(error: freundchen_core::Error) -> Self {
+        ApplicationError::Core(error)
+    }
+}
+
+impl From<guiver::Error> for ApplicationError {
+    fn from(error: guiver::Error) -> Self {
+        ApplicationError::Render(error)
+    }
+}
+
+impl From<printpdf::Error> for ApplicationError {
+    fn from(error: printpdf::Error) -> Self {
+        ApplicationError::Pdf(error)
+    }
+}
+
+impl From<SettingsError> for ApplicationError {
+    fn from(error: SettingsError) -> Self {
+        ApplicationError::Settings(error)
     }
 }
 
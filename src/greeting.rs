@@ -0,0 +1,57 @@
+use crate::i18n::Localizer;
+use chrono::{NaiveDateTime, Timelike};
+use std::collections::HashSet;
+
+/// Creates a greeting for the given time, addressing the user by the preferred name, if given, and
+/// mentioning the mood values that were already logged today, if any.
+pub(crate) fn greeting(
+    localizer: &Localizer,
+    preferred_name: Option<&str>,
+    now: NaiveDateTime,
+    mood_values_today: Option<&HashSet<u8>>,
+) -> String {
+    let salutation = localizer.text(match now.hour() {
+        5..=11 => "greeting-morning",
+        12..=17 => "greeting-afternoon",
+        18..=22 => "greeting-evening",
+        _ => "greeting-night",
+    });
+
+    let address = match preferred_name.map(str::trim) {
+        Some(preferred_name) if !preferred_name.is_empty() => localizer.text_with(
+            "greeting-with-name",
+            &[
+                ("salutation", salutation.into()),
+                ("name", preferred_name.into()),
+            ],
+        ),
+        _ => salutation,
+    };
+
+    let mut mood_values_today: Vec<_> = mood_values_today.into_iter().flatten().collect();
+    mood_values_today.sort();
+
+    // Nothing was logged today.
+    if mood_values_today.is_empty() {
+        return localizer.text_with("greeting-not-logged", &[("address", address.into())]);
+    }
+
+    localizer.text_with(
+        "greeting-logged",
+        &[
+            ("address", address.into()),
+            ("count", mood_values_today.len().into()),
+            (
+                "mood-values",
+                localizer
+                    .list(
+                        &mood_values_today
+                            .iter()
+                            .map(|mood_value| mood_value.to_string())
+                            .collect::<Vec<_>>(),
+                    )
+                    .into(),
+            ),
+        ],
+    )
+}
@@ -0,0 +1,175 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use std::env;
+use unic_langid::LanguageIdentifier;
+
+/// The locale that is used if no other locale is available.
+const DEFAULT_LOCALE: &str = "en";
+
+/// The message catalogues, bundled at compile time.
+const CATALOGUES: [(&str, &str); 2] = [
+    ("de", include_str!("../../locales/de.ftl")),
+    ("en", include_str!("../../locales/en.ftl")),
+];
+
+/// Translates messages and formats dates for a locale.
+pub(crate) struct Localizer {
+    bundle: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    /// Creates a localizer for the locale that is closest to the given one.
+    pub(crate) fn new(requested_locale: &str) -> Self {
+        let default_locale: LanguageIdentifier = DEFAULT_LOCALE.parse().unwrap();
+
+        let available_locales: Vec<LanguageIdentifier> = CATALOGUES
+            .iter()
+            .map(|(locale, _)| locale.parse().unwrap())
+            .collect();
+
+        let requested_locales: Vec<LanguageIdentifier> =
+            requested_locale.parse().into_iter().collect();
+
+        // Determine the best available locale.
+        let locale = negotiate_languages(
+            &requested_locales,
+            &available_locales,
+            Some(&default_locale),
+            NegotiationStrategy::Filtering,
+        )
+        .first()
+        .map_or(default_locale.clone(), |locale| (*locale).clone());
+
+        let catalogue = CATALOGUES
+            .iter()
+            .find(|(catalogue_locale, _)| locale.language.as_str() == *catalogue_locale)
+            .map_or(DEFAULT_LOCALE, |(_, catalogue)| catalogue);
+
+        let mut bundle = FluentBundle::new(vec![locale]);
+
+        // Do not wrap arguments in Unicode isolation marks, which the text widgets would show.
+        bundle.set_use_isolating(false);
+
+        // The catalogues are bundled, so errors are programming errors.
+        bundle
+            .add_resource(FluentResource::try_new(catalogue.to_string()).unwrap())
+            .unwrap();
+
+        Localizer { bundle }
+    }
+
+    /// Creates a localizer for the locale given by the environment variables `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG`.
+    pub(crate) fn from_environment() -> Self {
+        let requested_locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|variable| env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+
+        // Turn a POSIX locale like `de_DE.UTF-8` into a language identifier like `de-DE`.
+        let requested_locale = requested_locale
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .replace('_', "-");
+
+        Self::new(&requested_locale)
+    }
+
+    /// Creates a localizer for the given locale, or for the locale of the environment if none is
+    /// given.
+    pub(crate) fn for_locale(locale: Option<&str>) -> Self {
+        match locale {
+            Some(locale) => Self::new(locale),
+            None => Self::from_environment(),
+        }
+    }
+
+    /// Formats the given date.
+    pub(crate) fn date(&self, date: NaiveDate) -> String {
+        self.text_with(
+            "date",
+            &[
+                ("day", date.day().into()),
+                ("month", self.month_name(date).into()),
+                ("year", date.year().into()),
+            ],
+        )
+    }
+
+    /// Formats the given date and time.
+    pub(crate) fn date_time(&self, date_time: NaiveDateTime) -> String {
+        self.text_with(
+            "date-time",
+            &[
+                ("date", self.date(date_time.date()).into()),
+                ("time", date_time.format("%H:%M").to_string().into()),
+            ],
+        )
+    }
+
+    /// Returns the label for the given day of a month, starting at 1.
+    pub(crate) fn day_of_month(&self, day: u8) -> String {
+        self.text_with("day-of-month", &[("day", day.into())])
+    }
+
+    /// Joins the given items into a list like "a, b and c".
+    pub(crate) fn list(&self, items: &[String]) -> String {
+        match items.split_last() {
+            None => String::new(),
+            Some((last, [])) => last.clone(),
+            Some((last, items)) => self.text_with(
+                "list",
+                &[("items", items.join(", ").into()), ("last", last.into())],
+            ),
+        }
+    }
+
+    /// Returns the name of the given date's month.
+    fn month_name(&self, date: NaiveDate) -> String {
+        self.text(&format!("month-{}", date.month()))
+    }
+
+    /// Formats the given date's month and year.
+    pub(crate) fn month_year(&self, date: NaiveDate) -> String {
+        self.text_with(
+            "month-year",
+            &[
+                ("month", self.month_name(date).into()),
+                ("year", date.year().into()),
+            ],
+        )
+    }
+
+    /// Returns the message with the given ID.
+    pub(crate) fn text(&self, message_id: &str) -> String {
+        self.text_with(message_id, &[])
+    }
+
+    /// Returns the message with the given ID, with the given arguments filled in.
+    pub(crate) fn text_with(&self, message_id: &str, arguments: &[(&str, FluentValue)]) -> String {
+        let pattern = match self
+            .bundle
+            .get_message(message_id)
+            .and_then(|message| message.value())
+        {
+            // Show the message ID instead of a missing message.
+            None => return message_id.to_string(),
+            Some(pattern) => pattern,
+        };
+
+        let mut fluent_arguments = FluentArgs::new();
+
+        for (name, value) in arguments {
+            fluent_arguments.set(*name, value.clone());
+        }
+
+        let mut errors = vec![];
+
+        self.bundle
+            .format_pattern(pattern, Some(&fluent_arguments), &mut errors)
+            .into_owned()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::settings::{log_directory, Settings};
+use std::fs;
+use std::io;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::EnvFilter;
+
+/// The number of daily log files that are kept.
+const MAXIMUM_NUMBER_OF_LOG_FILES: usize = 7;
+
+/// Writes the log to a file in the log directory, which is rotated daily. The level is taken from
+/// `RUST_LOG` or else from the settings.
+///
+/// Mood values and notes must never be logged, only paths, counts and versions. Errors are logged
+/// with `ApplicationError::log_message`, which leaves out the data.
+pub(crate) fn init_logging(settings: &Settings) -> Result<(), io::Error> {
+    let log_directory = log_directory();
+
+    // The appender looks for old log files to delete before it creates the directory.
+    fs::create_dir_all(&log_directory)?;
+
+    let log_file_appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("freundchen")
+        .filename_suffix("log")
+        .max_log_files(MAXIMUM_NUMBER_OF_LOG_FILES)
+        .build(log_directory)
+        .map_err(io::Error::other)?;
+
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&settings.log_level));
+
+    tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_env_filter(env_filter)
+        .with_writer(log_file_appender)
+        .init();
+
+    Ok(())
+}
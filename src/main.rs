@@ -1,53 +1,130 @@
 mod error;
+mod greeting;
+mod i18n;
+mod logging;
+mod render;
+mod report;
+mod settings;
+mod theme;
 mod view;
 mod widget;
 
 use crate::error::ApplicationError;
-use crate::widget::{MoodValuesUpdate, MoodWidget};
-use guiver::widget::WidgetError;
+use crate::greeting::greeting;
+use crate::i18n::Localizer;
+use crate::logging::init_logging;
+use crate::settings::Settings;
+use crate::theme::Theme;
+use crate::view::{
+    tag_name, ApplicationCommand, FactorPanel, InquiryView, MedicationsView, QuotesView,
+    SaveFailureView, SelectionBar, SettingsView, SleepView, TagPickerView, TagsView,
+    ThoughtRecordView, View, ViewBox,
+};
+use crate::widget::{
+    MoodCellClicked, MoodCellRightClicked, MoodDayFilter, MoodMarkers, MoodSelection, MoodWidget,
+    SleepMonth, SleepNightClicked, SleepWidget,
+};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use freundchen_core::clipboard;
+use freundchen_core::data::{ApplicationData, BulkEdit, Track};
+use freundchen_core::mood::{MoodMonth, MoodValue};
+use freundchen_core::storage::{open_storage, Storage};
 use guiver::{
-    run, Application, Clipboard, Color, Command, Event, Font, LinearGradient, PaintBrush, Piet,
-    Region, Size, Stroke, StrokeStyle, UnitPoint, WidgetEvent, WidgetId, WidgetManager,
+    run, Application, Clipboard, Color, Command, Event, Font, KbKey, PaintBrush, Piet, Region,
+    RenderContext, Size, Stroke, StrokeStyle, WidgetEvent, WidgetId, WidgetManager,
 };
-use serde::{Deserialize, Serialize};
-use serde_json::json;
-use std::collections::{HashMap, HashSet};
-use std::fs;
-use std::fs::File;
-use std::io::Write;
-use std::path::PathBuf;
-
-/// The application data.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-struct ApplicationData {
-    mood_per_day: HashMap<u8, HashSet<u8>>,
-}
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{error, info, warn};
 
-impl ApplicationData {
-    fn new() -> Self {
-        ApplicationData {
-            mood_per_day: HashMap::new(),
-        }
-    }
-}
+/// The exit code if the application ended with an error.
+const EXIT_CODE_ERROR: i32 = 1;
 
-// =================================================================================================
+/// The exit code if the application was quit without saving the data.
+const EXIT_CODE_DATA_LOST: i32 = 2;
 
 /// The application state.
 struct ApplicationState {
+    /// The currently shown view. `None` means that the dashboard is shown.
+    active_view: Option<ViewBox>,
     application_data: ApplicationData,
+    /// The banner telling the user about problems, e.g. a recovered data file or an error.
+    banner: WidgetId,
+    banner_dismiss_button: WidgetId,
+    banner_text: WidgetId,
     clear_button: WidgetId,
+    /// The button showing all days again, which is hidden if the days are not filtered.
+    clear_filter_button: WidgetId,
+    /// The system clipboard, once guiver provided it.
+    clipboard: Option<Clipboard>,
     close_button: WidgetId,
-    path: PathBuf,
+    /// The mood values copied from a selected day.
+    copied_mood_values: Option<HashSet<MoodValue>>,
+    /// What the mood widget shows and edits: the mood or a dimension.
+    current_track: Track,
+    dashboard: WidgetId,
+    dashboard_button: WidgetId,
+    /// The button switching to the next dimension, which is hidden if there are no dimensions.
+    dimension_button: WidgetId,
+    /// The name of the dimension the mood widget shows.
+    dimension_text: WidgetId,
+    /// The panel next to the mood widget to edit the factors of the selected days.
+    factor_panel: FactorPanel,
+    /// The tag the days in the mood widget are filtered by.
+    filter_text: WidgetId,
+    /// The widget that has the keyboard focus, e.g. a text input.
+    focused_widget_id: Option<WidgetId>,
+    greeting_text: WidgetId,
+    inquiries_button: WidgetId,
+    inquiry_view: ViewBox,
+    localizer: Rc<Localizer>,
+    medications_button: WidgetId,
+    medications_view: ViewBox,
+    /// Set by the Ctrl+C handler, which cannot reach the application state from its thread. The data
+    /// is saved and the application quits with the next event.
+    quit_requested: Arc<AtomicBool>,
+    quote_text: WidgetId,
+    quotes_button: WidgetId,
+    quotes_view: ViewBox,
+    save_failure_view: ViewBox,
+    /// The selected days of the current month.
+    selected_day_of_month_indices: Option<RangeInclusive<u8>>,
+    selection_bar: SelectionBar,
+    settings: Settings,
+    settings_button: WidgetId,
+    settings_view: ViewBox,
+    sleep_button: WidgetId,
+    sleep_view: ViewBox,
+    storage: Box<dyn Storage>,
+    /// Only the days tagged with this tag are shown in the mood widget, if it is set.
+    tag_filter: Option<String>,
+    tag_picker_view: ViewBox,
+    tags_button: WidgetId,
+    tags_view: ViewBox,
+    theme: Theme,
     there_is_unsaved_data: bool,
+    thought_records_button: WidgetId,
+    thought_record_view: ViewBox,
     widget_manager: WidgetManager<()>,
     widget_mood: WidgetId,
+    /// The bar chart of the sleep below the mood widget.
+    widget_sleep: WidgetId,
+    /// The size of the window, to lay out the widgets when they are created anew.
+    window_size: Size,
 }
 
 impl ApplicationState {
-    pub(crate) fn new() -> Result<Self, WidgetError> {
-        // TODO: Use a good path.
-        let path = PathBuf::from("freundchen.json");
+    pub(crate) fn new(settings: Settings, localizer: Localizer) -> Result<Self, ApplicationError> {
+        let localizer = Rc::new(localizer);
+        let storage = open_storage(settings.data_path());
+
+        // The theme was validated with the settings, fall back to the default theme otherwise.
+        let theme = Theme::load(&settings.theme).unwrap_or_default();
 
         let mut widget_manager = WidgetManager::new();
 
@@ -60,24 +137,121 @@ impl ApplicationState {
         // Create the widget.
         let padding = widget_manager.new_padding();
         let column = widget_manager.new_column();
-        let greeting_text = widget_manager.new_text("Hi, how are you today?");
+        let banner = widget_manager.new_row();
+        let banner_text = widget_manager.new_text("");
+        let banner_dismiss_button =
+            widget_manager.new_text_button(localizer.text("banner-dismiss"));
+        let row_toolbar = widget_manager.new_row();
+        let dashboard_button = widget_manager.new_text_button(localizer.text("toolbar-mood"));
+        let thought_records_button =
+            widget_manager.new_text_button(localizer.text("toolbar-thought-records"));
+        let inquiries_button = widget_manager.new_text_button(localizer.text("toolbar-the-work"));
+        let quotes_button = widget_manager.new_text_button(localizer.text("toolbar-quotes"));
+        let tags_button = widget_manager.new_text_button(localizer.text("toolbar-tags"));
+        let sleep_button = widget_manager.new_text_button(localizer.text("toolbar-sleep"));
+        let medications_button =
+            widget_manager.new_text_button(localizer.text("toolbar-medications"));
+        let settings_button = widget_manager.new_text_button(localizer.text("toolbar-settings"));
+        let dashboard = widget_manager.new_column();
+        let greeting_text = widget_manager.new_text("");
+        let quote_text = widget_manager.new_text("");
+        let row_month = widget_manager.new_row();
+        let month_text =
+            widget_manager.new_text(localizer.month_year(Local::now().naive_local().date()));
+        let dimension_text = widget_manager.new_text("");
+        let dimension_button = widget_manager.new_text_button(localizer.text("dimension-switch"));
+        let filter_text = widget_manager.new_text("");
+        let clear_filter_button =
+            widget_manager.new_text_button(localizer.text("dashboard-show-all-days"));
+        let row_mood = widget_manager.new_row();
+        let column_charts = widget_manager.new_column();
         let widget_mood = widget_manager.next_widget_id();
+        let widget_sleep = widget_manager.next_widget_id();
 
         let row_buttons = widget_manager.new_row();
-        let clear_button = widget_manager.new_text_button("Clear");
-        let close_button = widget_manager.new_text_button("Close");
+        let clear_button = widget_manager.new_text_button(localizer.text("dashboard-clear"));
+        let close_button = widget_manager.new_text_button(localizer.text("dashboard-close"));
 
         let greeting_font = Font {
-            font_size: 16.0,
-            ..Default::default()
+            font_size: settings.greeting_font_size,
+            ..theme.font()
         };
 
         // Add the mood widget.
         widget_manager.add_widget(Box::new(MoodWidget::new(
             widget_mood,
+            debug_rendering_stroke.clone(),
+            (1..=31).map(|day| localizer.day_of_month(day)).collect(),
+            theme.mood_widget_style(),
+        )));
+
+        // Add the sleep widget, with a column per day like the mood widget.
+        widget_manager.add_widget(Box::new(SleepWidget::new(
+            widget_sleep,
             debug_rendering_stroke,
+            31,
+            theme.sleep_widget_style(),
         )));
 
+        let selection_bar = SelectionBar::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let factor_panel = FactorPanel::new(localizer.clone(), &theme, &mut widget_manager)?;
+
+        // Create the views.
+        let thought_record_view =
+            ThoughtRecordView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let thought_record_view_widget_id = *thought_record_view.main_widget_id();
+        let thought_record_view: ViewBox = Rc::new(RefCell::new(Box::new(thought_record_view)));
+        let inquiry_view = InquiryView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let inquiry_view_widget_id = *inquiry_view.main_widget_id();
+        let inquiry_view: ViewBox = Rc::new(RefCell::new(Box::new(inquiry_view)));
+        let quotes_view = QuotesView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let quotes_view_widget_id = *quotes_view.main_widget_id();
+        let quotes_view: ViewBox = Rc::new(RefCell::new(Box::new(quotes_view)));
+        let save_failure_view =
+            SaveFailureView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let save_failure_view_widget_id = *save_failure_view.main_widget_id();
+        let save_failure_view: ViewBox = Rc::new(RefCell::new(Box::new(save_failure_view)));
+        let settings_view = SettingsView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let settings_view_widget_id = *settings_view.main_widget_id();
+        let settings_view: ViewBox = Rc::new(RefCell::new(Box::new(settings_view)));
+        let tag_picker_view = TagPickerView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let tag_picker_view_widget_id = *tag_picker_view.main_widget_id();
+        let tag_picker_view: ViewBox = Rc::new(RefCell::new(Box::new(tag_picker_view)));
+        let tags_view = TagsView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let tags_view_widget_id = *tags_view.main_widget_id();
+        let tags_view: ViewBox = Rc::new(RefCell::new(Box::new(tags_view)));
+        let sleep_view = SleepView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let sleep_view_widget_id = *sleep_view.main_widget_id();
+        let sleep_view: ViewBox = Rc::new(RefCell::new(Box::new(sleep_view)));
+        let medications_view =
+            MedicationsView::new(localizer.clone(), &theme, &mut widget_manager)?;
+        let medications_view_widget_id = *medications_view.main_widget_id();
+        let medications_view: ViewBox = Rc::new(RefCell::new(Box::new(medications_view)));
+
+        // Apply the theme.
+        widget_manager.send_commands(theme.text_commands(&[
+            banner_text,
+            quote_text,
+            month_text,
+            dimension_text,
+            filter_text,
+        ]))?;
+        widget_manager.send_commands(theme.button_commands(&[
+            banner_dismiss_button,
+            dashboard_button,
+            thought_records_button,
+            inquiries_button,
+            quotes_button,
+            tags_button,
+            sleep_button,
+            medications_button,
+            settings_button,
+            dimension_button,
+            clear_filter_button,
+            close_button,
+        ]))?;
+        widget_manager.send_commands(theme.destructive_button_commands(&[clear_button]))?;
+
         // Compose the widget.
         widget_manager.send_commands(vec![
             Command::SetMainWidget(padding),
@@ -89,16 +263,200 @@ impl ApplicationState {
             Command::AddChild {
                 parent_widget_id: column,
                 widget_placement: None,
-                child_widget_id: greeting_text,
+                child_widget_id: banner,
+            },
+            Command::SetIsHidden(banner, true),
+            Command::AddChild {
+                parent_widget_id: banner,
+                widget_placement: None,
+                child_widget_id: banner_text,
+            },
+            Command::AddChild {
+                parent_widget_id: banner,
+                widget_placement: None,
+                child_widget_id: banner_dismiss_button,
             },
             Command::AddChild {
                 parent_widget_id: column,
                 widget_placement: None,
-                child_widget_id: widget_mood,
+                child_widget_id: row_toolbar,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: dashboard_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: thought_records_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: inquiries_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: quotes_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: tags_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: sleep_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: medications_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_toolbar,
+                widget_placement: None,
+                child_widget_id: settings_button,
+            },
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: dashboard,
+            },
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: thought_record_view_widget_id,
             },
+            Command::SetIsHidden(thought_record_view_widget_id, true),
             Command::AddChild {
                 parent_widget_id: column,
                 widget_placement: None,
+                child_widget_id: inquiry_view_widget_id,
+            },
+            Command::SetIsHidden(inquiry_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: quotes_view_widget_id,
+            },
+            Command::SetIsHidden(quotes_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: settings_view_widget_id,
+            },
+            Command::SetIsHidden(settings_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: tag_picker_view_widget_id,
+            },
+            Command::SetIsHidden(tag_picker_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: tags_view_widget_id,
+            },
+            Command::SetIsHidden(tags_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: sleep_view_widget_id,
+            },
+            Command::SetIsHidden(sleep_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: medications_view_widget_id,
+            },
+            Command::SetIsHidden(medications_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: save_failure_view_widget_id,
+            },
+            Command::SetIsHidden(save_failure_view_widget_id, true),
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
+                child_widget_id: greeting_text,
+            },
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
+                child_widget_id: quote_text,
+            },
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
+                child_widget_id: row_month,
+            },
+            Command::AddChild {
+                parent_widget_id: row_month,
+                widget_placement: None,
+                child_widget_id: month_text,
+            },
+            Command::AddChild {
+                parent_widget_id: row_month,
+                widget_placement: None,
+                child_widget_id: dimension_text,
+            },
+            Command::AddChild {
+                parent_widget_id: row_month,
+                widget_placement: None,
+                child_widget_id: dimension_button,
+            },
+            Command::SetIsHidden(dimension_text, true),
+            Command::SetIsHidden(dimension_button, true),
+            Command::AddChild {
+                parent_widget_id: row_month,
+                widget_placement: None,
+                child_widget_id: filter_text,
+            },
+            Command::AddChild {
+                parent_widget_id: row_month,
+                widget_placement: None,
+                child_widget_id: clear_filter_button,
+            },
+            Command::SetIsHidden(filter_text, true),
+            Command::SetIsHidden(clear_filter_button, true),
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
+                child_widget_id: row_mood,
+            },
+            Command::AddChild {
+                parent_widget_id: row_mood,
+                widget_placement: None,
+                child_widget_id: column_charts,
+            },
+            Command::AddChild {
+                parent_widget_id: column_charts,
+                widget_placement: None,
+                child_widget_id: widget_mood,
+            },
+            Command::AddChild {
+                parent_widget_id: column_charts,
+                widget_placement: None,
+                child_widget_id: widget_sleep,
+            },
+            Command::AddChild {
+                parent_widget_id: row_mood,
+                widget_placement: None,
+                child_widget_id: *factor_panel.main_widget_id(),
+            },
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
+                child_widget_id: *selection_bar.main_widget_id(),
+            },
+            Command::AddChild {
+                parent_widget_id: dashboard,
+                widget_placement: None,
                 child_widget_id: row_buttons,
             },
             Command::AddChild {
@@ -113,82 +471,817 @@ impl ApplicationState {
             },
             //
             Command::SetFont(greeting_text, greeting_font),
-            Command::SetFill(
-                clear_button,
-                Some(PaintBrush::Linear(LinearGradient::new(
-                    UnitPoint::TOP,
-                    UnitPoint::BOTTOM,
-                    (Color::rgb8(255, 0, 0), Color::rgb8(150, 0, 0)),
-                ))),
-            ),
-            Command::SetStroke(
-                clear_button,
-                Some(Stroke {
-                    stroke_brush: PaintBrush::Color(Color::rgb8(220, 0, 0)),
-                    stroke_style: StrokeStyle::default(),
-                    stroke_width: 1.0,
-                }),
-            ),
         ])?;
 
+        let window_size = Size::new(settings.window_width, settings.window_height);
+
         Ok(ApplicationState {
+            active_view: None,
             application_data: ApplicationData::new(),
+            banner,
+            banner_dismiss_button,
+            banner_text,
             clear_button,
+            clear_filter_button,
+            clipboard: None,
             close_button,
-            path,
+            copied_mood_values: None,
+            current_track: Track::Mood,
+            dashboard,
+            dashboard_button,
+            dimension_button,
+            dimension_text,
+            factor_panel,
+            filter_text,
+            focused_widget_id: None,
+            greeting_text,
+            inquiries_button,
+            inquiry_view,
+            localizer,
+            medications_button,
+            medications_view,
+            quit_requested: Arc::new(AtomicBool::new(false)),
+            quote_text,
+            quotes_button,
+            quotes_view,
+            save_failure_view,
+            selected_day_of_month_indices: None,
+            selection_bar,
+            settings,
+            settings_button,
+            settings_view,
+            sleep_button,
+            sleep_view,
+            storage,
+            tag_filter: None,
+            tag_picker_view,
+            tags_button,
+            tags_view,
+            theme,
             there_is_unsaved_data: false,
+            thought_records_button,
+            thought_record_view,
             widget_manager,
             widget_mood,
+            widget_sleep,
+            window_size,
         })
     }
 
-    ///
-    fn load_application_data(&mut self) -> Result<(), ApplicationError> {
-        // The application data file does not exist.
-        if !self.path.exists() {
-            return Ok(());
-        }
+    /// Handles a command from a view.
+    fn handle_application_command(
+        &mut self,
+        application_command: ApplicationCommand,
+    ) -> Result<(), ApplicationError> {
+        match application_command {
+            ApplicationCommand::AddInquiry(inquiry) => {
+                self.application_data.inquiries.push(inquiry);
+                self.there_is_unsaved_data = true;
 
-        // Try to read the data file.
-        let file_content = fs::read_to_string(&self.path)?;
+                // Let the view show the new inquiry.
+                self.show_view(
+                    Some(self.inquiry_view.clone()),
+                    ApplicationCommand::ShowInquiries,
+                )
+            }
+            ApplicationCommand::AddQuotes(quotes) => {
+                self.application_data.quotes.extend(quotes.iter().cloned());
+                self.there_is_unsaved_data = true;
+                self.update_quote_text()?;
 
-        // Try to deserialize the application data.
-        let deserialized_application_data: ApplicationData = serde_json::from_str(&file_content)?;
+                // Let the view show the new quotes.
+                self.show_view(
+                    Some(self.quotes_view.clone()),
+                    ApplicationCommand::AddQuotes(quotes),
+                )
+            }
+            ApplicationCommand::AddThoughtRecord(thought_record) => {
+                let date = thought_record.date;
 
-        self.application_data = deserialized_application_data.clone();
-        self.there_is_unsaved_data = false;
+                self.application_data.thought_records.push(thought_record);
+                self.there_is_unsaved_data = true;
 
-        // Send the new data to the mood widget.
-        {
-            let mut commands = vec![];
+                // Let the view show the new thought record.
+                self.show_view(
+                    Some(self.thought_record_view.clone()),
+                    ApplicationCommand::ShowThoughtRecords(date),
+                )
+            }
+            ApplicationCommand::CopySelectedDay => {
+                if let Some((first_date, _)) = self.selected_dates() {
+                    self.copied_mood_values = Some(
+                        self.application_data
+                            .values_per_date(self.current_track)
+                            .and_then(|values_per_date| values_per_date.get(&first_date))
+                            .cloned()
+                            .unwrap_or_default(),
+                    );
+                }
 
-            // TODO: Erase current data.
+                Ok(())
+            }
+            ApplicationCommand::EditSelectedDays(bulk_edit) => {
+                if let Some((first_date, last_date)) = self.selected_dates() {
+                    self.application_data.edit_days(
+                        self.current_track,
+                        first_date,
+                        last_date,
+                        &bulk_edit,
+                    );
+                    self.there_is_unsaved_data = true;
 
-            for (day_of_month_index, mood_values) in
-                deserialized_application_data.mood_per_day.into_iter()
-            {
-                commands.push(Command::SetValue(
+                    self.update_mood_widget()?;
+                    self.update_greeting_text()?;
+                }
+
+                Ok(())
+            }
+            ApplicationCommand::EditSleep(date) => self.show_view(
+                Some(self.sleep_view.clone()),
+                ApplicationCommand::EditSleep(date),
+            ),
+            ApplicationCommand::FilterByTag(tag_filter) => {
+                self.tag_filter = tag_filter;
+
+                let filter_text = self.tag_filter.as_ref().map_or_else(String::new, |tag| {
+                    self.localizer.text_with(
+                        "dashboard-filter",
+                        &[("tag", tag_name(&self.localizer, tag).into())],
+                    )
+                });
+                let there_is_no_filter = self.tag_filter.is_none();
+
+                self.widget_manager.send_commands(vec![
+                    Command::SetValue(self.filter_text, Box::new(filter_text)),
+                    Command::SetIsHidden(self.filter_text, there_is_no_filter),
+                    Command::SetIsHidden(self.clear_filter_button, there_is_no_filter),
+                ])?;
+
+                self.update_mood_widget()?;
+                self.show_view(None, ApplicationCommand::ShowDashboard)
+            }
+            ApplicationCommand::PasteToSelectedDays => match self.copied_mood_values.clone() {
+                Some(mood_values) => self.handle_application_command(
+                    ApplicationCommand::EditSelectedDays(BulkEdit::SetMoodValues(mood_values)),
+                ),
+                // Nothing was copied.
+                None => Ok(()),
+            },
+            ApplicationCommand::PickTags(date) => self.show_view(
+                Some(self.tag_picker_view.clone()),
+                ApplicationCommand::PickTags(date),
+            ),
+            ApplicationCommand::QuitWithoutSaving => self.quit(EXIT_CODE_DATA_LOST),
+            ApplicationCommand::RemoveQuote(index) => {
+                if index < self.application_data.quotes.len() {
+                    self.application_data.quotes.remove(index);
+                    self.there_is_unsaved_data = true;
+                    self.update_quote_text()?;
+                }
+
+                self.show_view(
+                    Some(self.quotes_view.clone()),
+                    ApplicationCommand::ShowQuotes,
+                )
+            }
+            ApplicationCommand::EditSettings(settings) => self.show_view(
+                Some(self.settings_view.clone()),
+                ApplicationCommand::EditSettings(settings),
+            ),
+            ApplicationCommand::RetrySave => {
+                self.save_and_quit();
+                Ok(())
+            }
+            ApplicationCommand::SaveAs(path) => {
+                // The current storage is kept until the data is in the new file, so that trying
+                // again after a failure saves to the current file.
+                let mut storage = open_storage(path.clone());
+
+                if let Err(error) = storage.save(&self.application_data) {
+                    if let Err(error) = storage.close() {
+                        warn!(
+                            path = %path.display(),
+                            "Could not close the storage: {}",
+                            error.log_message()
+                        );
+                    }
+
+                    self.handle_save_failure(&path, error.into());
+                    return Ok(());
+                }
+
+                if let Err(error) = self.storage.close() {
+                    warn!(
+                        path = %self.storage.path().display(),
+                        "Could not close the storage: {}",
+                        error.log_message()
+                    );
+                }
+
+                self.storage = storage;
+                self.there_is_unsaved_data = false;
+
+                info!(path = %path.display(), "Saved the data");
+
+                // Load the data from the new path with the next start, now that it is there.
+                self.settings.data_path = Some(path);
+
+                if let Err(error) = self.settings.save(&Settings::path()) {
+                    warn!("Could not save the settings: {}", error.log_message());
+                }
+
+                self.quit(0)
+            }
+            ApplicationCommand::SelectDays(day_of_month_indices) => {
+                self.widget_manager.send_command(Command::SetValue(
                     self.widget_mood,
-                    Box::new(MoodValuesUpdate::Update {
-                        day_of_month_index,
-                        mood_values,
+                    Box::new(MoodSelection {
+                        day_of_month_indices: day_of_month_indices.clone(),
                     }),
-                ));
+                ))?;
+
+                self.update_selection(day_of_month_indices)
+            }
+            ApplicationCommand::SetFactorValue {
+                factor_index,
+                value,
+            } => {
+                let today = Local::now().naive_local().date();
+                let (first_date, last_date) = self.selected_dates().unwrap_or((today, today));
+
+                self.application_data.edit_days(
+                    self.current_track,
+                    first_date,
+                    last_date,
+                    &BulkEdit::SetFactorValue {
+                        factor_index,
+                        value,
+                    },
+                );
+                self.there_is_unsaved_data = true;
+
+                self.update_factor_panel()
+            }
+            ApplicationCommand::SetSettings(settings) => {
+                let path = settings.data_path();
+
+                if path != self.storage.path() {
+                    self.switch_storage(path)?;
+                }
+
+                settings.save(&Settings::path())?;
+
+                let widgets_need_rebuild = settings.theme != self.settings.theme
+                    || settings.locale != self.settings.locale;
+
+                self.settings = settings;
+
+                // Create the widgets anew with the new theme and texts.
+                if widgets_need_rebuild {
+                    return self.rebuild_widgets();
+                }
+
+                self.widget_manager.send_command(Command::SetFont(
+                    self.greeting_text,
+                    Font {
+                        font_size: self.settings.greeting_font_size,
+                        ..self.theme.font()
+                    },
+                ))?;
+
+                self.update_greeting_text()
             }
+            ApplicationCommand::SetSleep { date, sleep } => {
+                match sleep {
+                    Some(sleep) => self.application_data.sleep_per_date.insert(date, sleep),
+                    None => self.application_data.sleep_per_date.remove(&date),
+                };
+                self.there_is_unsaved_data = true;
 
-            self.widget_manager.send_commands(commands)?;
+                self.update_sleep_widget()?;
+                self.show_view(None, ApplicationCommand::ShowDashboard)
+            }
+            ApplicationCommand::ShowDashboard => {
+                self.show_view(None, ApplicationCommand::ShowDashboard)
+            }
+            ApplicationCommand::ShowInquiries => self.show_view(
+                Some(self.inquiry_view.clone()),
+                ApplicationCommand::ShowInquiries,
+            ),
+            ApplicationCommand::ShowMedications => self.show_view(
+                Some(self.medications_view.clone()),
+                ApplicationCommand::ShowMedications,
+            ),
+            ApplicationCommand::ShowQuotes => self.show_view(
+                Some(self.quotes_view.clone()),
+                ApplicationCommand::ShowQuotes,
+            ),
+            ApplicationCommand::ShowSaveFailure { path, reason } => self.show_view(
+                Some(self.save_failure_view.clone()),
+                ApplicationCommand::ShowSaveFailure { path, reason },
+            ),
+            ApplicationCommand::ShowSettings => self.show_view(
+                Some(self.settings_view.clone()),
+                ApplicationCommand::EditSettings(self.settings.clone()),
+            ),
+            ApplicationCommand::ShowTags => {
+                self.show_view(Some(self.tags_view.clone()), ApplicationCommand::ShowTags)
+            }
+            ApplicationCommand::ShowThoughtRecords(date) => self.show_view(
+                Some(self.thought_record_view.clone()),
+                ApplicationCommand::ShowThoughtRecords(date),
+            ),
+            ApplicationCommand::ToggleFavouriteQuote(index) => {
+                if let Some(quote) = self.application_data.quotes.get_mut(index) {
+                    quote.is_favourite = !quote.is_favourite;
+                    self.there_is_unsaved_data = true;
+                }
+
+                self.show_view(
+                    Some(self.quotes_view.clone()),
+                    ApplicationCommand::ShowQuotes,
+                )
+            }
+            ApplicationCommand::ToggleMedicationIntake {
+                date,
+                medication_index,
+            } => {
+                if let Some(medication) =
+                    self.application_data.medications.get_mut(medication_index)
+                {
+                    let is_taken = medication.intake_dates.contains(&date);
+                    medication.set_intake(date, !is_taken);
+                    self.there_is_unsaved_data = true;
+                }
+
+                // Let the view show the intake.
+                self.show_view(
+                    Some(self.medications_view.clone()),
+                    ApplicationCommand::ShowMedications,
+                )
+            }
+            ApplicationCommand::ToggleTag { date, tag } => {
+                self.application_data.toggle_tag(date, &tag);
+                self.there_is_unsaved_data = true;
+
+                // A filtered day could have lost the tag.
+                self.update_mood_widget()?;
+
+                // Let the view show the day's tags.
+                self.show_view(
+                    Some(self.tag_picker_view.clone()),
+                    ApplicationCommand::PickTags(date),
+                )
+            }
         }
+    }
+
+    /// Loads the application data. A corrupt file is moved aside, its readable entries are kept
+    /// and the banner tells the user what happened.
+    fn load_application_data(&mut self) -> Result<(), ApplicationError> {
+        let now = Local::now().naive_local();
+
+        // Try to read and deserialize the application data.
+        let (application_data, recovery) = self.storage.load_or_recover(now)?;
+        self.application_data = application_data;
+
+        info!(
+            path = %self.storage.path().display(),
+            days = self.application_data.mood_per_date.len(),
+            inquiries = self.application_data.inquiries.len(),
+            quotes = self.application_data.quotes.len(),
+            thought_records = self.application_data.thought_records.len(),
+            "Loaded the data"
+        );
+
+        // Write the recovered data with the next save.
+        self.there_is_unsaved_data = recovery.is_some();
+
+        if let Some(recovery) = recovery {
+            warn!(
+                backup_path = %recovery.backup_path.display(),
+                recovered_days = recovery.number_of_recovered_days,
+                "The data was corrupt, kept the readable entries"
+            );
+
+            let message = self.localizer.text_with(
+                "data-recovered",
+                &[
+                    ("path", recovery.backup_path.display().to_string().into()),
+                    ("count", recovery.number_of_recovered_days.into()),
+                    ("reason", recovery.reason.into()),
+                ],
+            );
+
+            self.show_banner(message)?;
+        }
+
+        self.update_dashboard()
+    }
+
+    /// Shows the application data in the dashboard's widgets.
+    fn update_dashboard(&mut self) -> Result<(), ApplicationError> {
+        self.factor_panel.set_factors(
+            &self.application_data.factors,
+            &self.theme,
+            &mut self.widget_manager,
+        )?;
+
+        self.update_dimension()?;
+        self.update_factor_panel()?;
+        self.update_greeting_text()?;
+        self.update_quote_text()?;
+        self.update_sleep_widget()
+    }
+
+    /// Creates the widgets anew with the theme and the locale of the settings and shows the
+    /// settings view again. The application data and the storage are kept.
+    fn rebuild_widgets(&mut self) -> Result<(), ApplicationError> {
+        let mut application_state = ApplicationState::new(
+            self.settings.clone(),
+            Localizer::for_locale(self.settings.locale.as_deref()),
+        )?;
+
+        std::mem::swap(
+            &mut application_state.application_data,
+            &mut self.application_data,
+        );
+        std::mem::swap(&mut application_state.clipboard, &mut self.clipboard);
+        std::mem::swap(
+            &mut application_state.copied_mood_values,
+            &mut self.copied_mood_values,
+        );
+        std::mem::swap(&mut application_state.storage, &mut self.storage);
+        application_state.current_track = self.current_track;
+        application_state.there_is_unsaved_data = self.there_is_unsaved_data;
+        application_state.window_size = self.window_size;
+
+        let tag_filter = self.tag_filter.take();
+
+        *self = application_state;
+
+        self.widget_manager.resize(self.window_size);
+        self.update_dashboard()?;
+        self.handle_application_command(ApplicationCommand::FilterByTag(tag_filter))?;
+
+        info!(
+            theme = %self.settings.theme,
+            locale = ?self.settings.locale,
+            "Applied the theme and the locale"
+        );
+
+        self.show_view(
+            Some(self.settings_view.clone()),
+            ApplicationCommand::EditSettings(self.settings.clone()),
+        )
+    }
+
+    /// Uses the data file at the given path from now on. The changes so far are saved to the
+    /// current file first. An existing file is loaded instead of being overwritten, otherwise the
+    /// current data is written to the new path with the next save.
+    fn switch_storage(&mut self, path: PathBuf) -> Result<(), ApplicationError> {
+        self.save_application_data()?;
+
+        let mut storage = open_storage(path);
+
+        if let Err(error) = self.storage.close() {
+            warn!(
+                path = %self.storage.path().display(),
+                "Could not close the storage: {}",
+                error.log_message()
+            );
+        }
+
+        std::mem::swap(&mut self.storage, &mut storage);
+
+        if self.storage.path().exists() {
+            info!(path = %self.storage.path().display(), "Loading the existing data file");
+
+            // Go back to the previous file, if the new one cannot be read.
+            if let Err(error) = self.load_application_data() {
+                self.storage = storage;
+                return Err(error);
+            }
+        } else {
+            self.there_is_unsaved_data = true;
+        }
+
+        Ok(())
+    }
+
+    /// Shows the name of the current dimension and its values in the mood widget. The dimension
+    /// texts are hidden if there are no dimensions.
+    fn update_dimension(&mut self) -> Result<(), ApplicationError> {
+        let (name, style) = match self.current_track {
+            Track::Dimension(index) => match self.application_data.dimensions.get(index) {
+                Some(dimension) => (
+                    dimension.name.clone(),
+                    self.theme.dimension_mood_widget_style(dimension),
+                ),
+                // The dimension was removed.
+                None => {
+                    self.current_track = Track::Mood;
+                    return self.update_dimension();
+                }
+            },
+            Track::Mood => (
+                self.localizer.text("dimension-mood"),
+                self.theme.mood_widget_style(),
+            ),
+        };
+
+        let there_are_no_dimensions = self.application_data.dimensions.is_empty();
+
+        self.widget_manager.send_commands(vec![
+            Command::SetValue(self.dimension_text, Box::new(name)),
+            Command::SetIsHidden(self.dimension_text, there_are_no_dimensions),
+            Command::SetIsHidden(self.dimension_button, there_are_no_dimensions),
+            Command::SetValue(self.widget_mood, Box::new(style)),
+        ])?;
+
+        self.update_mood_widget()
+    }
+
+    /// Sends the current month's values of the current track to the mood widget, the days with
+    /// the tag filter's tag and the days a medication was started, stopped or its dose changed on.
+    fn update_mood_widget(&mut self) -> Result<(), ApplicationError> {
+        let today = Local::now().naive_local().date();
+
+        let mood_month =
+            MoodMonth::from_application_data(&self.application_data, self.current_track, today);
+
+        let mood_day_filter = MoodDayFilter {
+            day_of_month_indices: self.tag_filter.as_ref().map(|tag_filter| {
+                self.application_data
+                    .dates_with_tag(tag_filter)
+                    .filter(|date| date.year() == today.year() && date.month() == today.month())
+                    .map(|date| date.day0() as u8)
+                    .collect()
+            }),
+        };
+
+        let mood_markers = MoodMarkers {
+            day_of_month_indices: self
+                .application_data
+                .medications
+                .iter()
+                .flat_map(|medication| medication.change_dates())
+                .filter(|date| date.year() == today.year() && date.month() == today.month())
+                .map(|date| date.day0() as u8)
+                .collect(),
+        };
+
+        self.widget_manager.send_commands(vec![
+            Command::SetValue(self.widget_mood, Box::new(mood_month)),
+            Command::SetValue(self.widget_mood, Box::new(mood_day_filter)),
+            Command::SetValue(self.widget_mood, Box::new(mood_markers)),
+        ])?;
+
+        Ok(())
+    }
+
+    /// Sends the sleep of the nights after the current month's days to the sleep widget.
+    fn update_sleep_widget(&mut self) -> Result<(), ApplicationError> {
+        let sleep_month = SleepMonth::new(
+            &self.application_data.sleep_per_date,
+            Local::now().naive_local().date(),
+        );
+
+        self.widget_manager
+            .send_command(Command::SetValue(self.widget_sleep, Box::new(sleep_month)))?;
+
+        Ok(())
+    }
+
+    /// Returns the first and the last of the selected dates, if days are selected.
+    fn selected_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        // The mood widget shows the current month.
+        let today = Local::now().naive_local().date();
+        let day_of_month_indices = self.selected_day_of_month_indices.as_ref()?;
+
+        Some((
+            today.with_day0(*day_of_month_indices.start() as u32)?,
+            today.with_day0(*day_of_month_indices.end() as u32)?,
+        ))
+    }
+
+    /// Puts the mood values of the selected days on the clipboard, as tab separated values for
+    /// spreadsheets or as JSON.
+    fn copy_to_clipboard(&mut self, as_json: bool) -> Result<(), ApplicationError> {
+        let (Some((first_date, last_date)), Some(clipboard)) =
+            (self.selected_dates(), self.clipboard.as_mut())
+        else {
+            return Ok(());
+        };
+
+        let Some(values_per_date) = self.application_data.values_per_date(self.current_track)
+        else {
+            return Ok(());
+        };
+
+        let text = if as_json {
+            clipboard::to_json(values_per_date, first_date, last_date)?
+        } else {
+            clipboard::to_tsv(values_per_date, first_date, last_date)
+        };
+
+        clipboard.put_string(text);
+
+        Ok(())
+    }
+
+    /// Sets the mood values on the clipboard. They are moved to start at the first selected day,
+    /// if days are selected, and set to their dates otherwise.
+    fn paste_from_clipboard(&mut self) -> Result<(), ApplicationError> {
+        let Some(text) = self.clipboard.as_ref().and_then(Clipboard::get_string) else {
+            return Ok(());
+        };
+
+        let mood_per_date = clipboard::parse(
+            &text,
+            self.application_data.number_of_values(self.current_track),
+        )?;
+
+        let offset = match (self.selected_dates(), mood_per_date.keys().next()) {
+            (Some((first_date, _)), Some(first_pasted_date)) => first_date - *first_pasted_date,
+            _ => chrono::Duration::zero(),
+        };
+
+        for (date, mood_values) in &mood_per_date {
+            self.application_data.set_mood_values(
+                self.current_track,
+                *date + offset,
+                mood_values.clone(),
+            );
+        }
+
+        self.there_is_unsaved_data = true;
+
+        info!(days = mood_per_date.len(), "Pasted mood values");
+
+        self.update_mood_widget()?;
+        self.update_greeting_text()
+    }
+
+    /// Remembers the selected days and lets the selection bar show them.
+    fn update_selection(
+        &mut self,
+        day_of_month_indices: Option<RangeInclusive<u8>>,
+    ) -> Result<(), ApplicationError> {
+        let number_of_selected_days = day_of_month_indices
+            .as_ref()
+            .map_or(0, |day_of_month_indices| day_of_month_indices.len());
+
+        self.selected_day_of_month_indices = day_of_month_indices;
+        self.selection_bar
+            .set_number_of_selected_days(number_of_selected_days, &mut self.widget_manager)?;
+
+        self.update_factor_panel()
+    }
+
+    /// Lets the factor panel show the factors of the first selected day, or of today if no days
+    /// are selected.
+    fn update_factor_panel(&mut self) -> Result<(), ApplicationError> {
+        let today = Local::now().naive_local().date();
+        let selected_dates = self.selected_dates();
+        let date = selected_dates.map_or(today, |(first_date, _)| first_date);
+
+        let title = match selected_dates {
+            Some((first_date, last_date)) if first_date != last_date => self.localizer.text_with(
+                "factor-panel-days",
+                &[
+                    ("first-date", self.localizer.date(first_date).into()),
+                    ("last-date", self.localizer.date(last_date).into()),
+                ],
+            ),
+            _ => self.localizer.text_with(
+                "factor-panel-day",
+                &[("date", self.localizer.date(date).into())],
+            ),
+        };
+
+        self.factor_panel.show_values(
+            title,
+            &self.application_data.factors,
+            date,
+            &mut self.widget_manager,
+        )
+    }
+
+    /// Greets the user according to the time of day and today's mood values.
+    fn update_greeting_text(&mut self) -> Result<(), ApplicationError> {
+        let now = Local::now().naive_local();
+
+        let greeting = greeting(
+            &self.localizer,
+            self.settings.preferred_name.as_deref(),
+            now,
+            self.application_data.mood_per_date.get(&now.date()),
+        );
+
+        self.widget_manager
+            .send_command(Command::SetValue(self.greeting_text, Box::new(greeting)))?;
+
+        Ok(())
+    }
+
+    /// Shows the quote of the day, if there are quotes.
+    fn update_quote_text(&mut self) -> Result<(), ApplicationError> {
+        let quote = self
+            .application_data
+            .quote_of_the_day(Local::now().naive_local().date())
+            .map(|quote| quote.to_string())
+            .unwrap_or_default();
+
+        self.widget_manager
+            .send_command(Command::SetValue(self.quote_text, Box::new(quote)))?;
+
+        Ok(())
+    }
+
+    /// Shows the given view, or the dashboard if no view is given.
+    fn show_view(
+        &mut self,
+        view: Option<ViewBox>,
+        application_command: ApplicationCommand,
+    ) -> Result<(), ApplicationError> {
+        // Hide the currently shown view.
+        let widget_id_to_hide = match &self.active_view {
+            None => self.dashboard,
+            Some(active_view) => *active_view.borrow().main_widget_id(),
+        };
+
+        // Show the given view.
+        let widget_id_to_show = match &view {
+            None => self.dashboard,
+            Some(view) => {
+                view.borrow_mut().activate(
+                    application_command,
+                    &self.application_data,
+                    &mut self.widget_manager,
+                )?;
+
+                *view.borrow().main_widget_id()
+            }
+        };
+
+        self.widget_manager.send_commands(vec![
+            Command::SetIsHidden(widget_id_to_hide, true),
+            Command::SetIsHidden(widget_id_to_show, false),
+        ])?;
+
+        self.active_view = view;
 
         Ok(())
     }
 
-    /// Saves and quits the application.
+    /// Closes the storage and ends the process with the given exit code. guiver offers no way to
+    /// end its event loop, so the process is exited after everything was closed.
+    fn quit(&mut self, exit_code: i32) -> ! {
+        if let Err(error) = self.storage.close() {
+            error!(
+                path = %self.storage.path().display(),
+                "Could not close the storage: {}",
+                error.log_message()
+            );
+        }
+
+        info!(exit_code, "Quitting");
+
+        std::process::exit(exit_code);
+    }
+
+    /// Saves the data and quits the application. If saving fails, the user is asked whether to try
+    /// again, to save to another file or to quit without saving.
     fn save_and_quit(&mut self) {
-        // TODO: error handling.
-        let _ = self.save_application_data();
+        match self.save_application_data() {
+            Ok(()) => self.quit(0),
+            Err(error) => {
+                let path = self.storage.path().to_path_buf();
+                self.handle_save_failure(&path, error);
+            }
+        }
+    }
 
-        // End the process.
-        std::process::exit(0);
+    /// Logs the given error of saving the data to the given path and asks the user whether to try
+    /// again, to save to another file or to quit without saving.
+    fn handle_save_failure(&mut self, path: &Path, error: ApplicationError) {
+        error!(
+            path = %path.display(),
+            "Could not save the data: {}",
+            error.log_message()
+        );
+
+        let application_command = ApplicationCommand::ShowSaveFailure {
+            path: path.to_path_buf(),
+            reason: error.to_string(),
+        };
+
+        // The user cannot be asked.
+        if let Err(error) = self.handle_application_command(application_command) {
+            error!("Could not ask what to do: {}", error.log_message());
+            self.quit(EXIT_CODE_DATA_LOST);
+        }
     }
 
     ///
@@ -198,129 +1291,378 @@ impl ApplicationState {
             return Ok(());
         }
 
-        // Serialize the application data.
-        let serialized_application_data: String = format!("{}", json!(self.application_data));
+        self.storage.save(&self.application_data)?;
+        self.there_is_unsaved_data = false;
 
-        // Try to create the serialized application file.
-        let mut application_data_file = File::create(&self.path)?;
+        info!(path = %self.storage.path().display(), "Saved the data");
 
-        // Try to write the serialized application data.
-        write!(application_data_file, "{}", serialized_application_data)?;
+        Ok(())
+    }
 
-        self.there_is_unsaved_data = false;
+    /// Reports the given error: it is logged and shown in the banner.
+    fn report_error(&mut self, error: ApplicationError) {
+        error!("{}", error.log_message());
+
+        let message = self
+            .localizer
+            .text_with("error-occurred", &[("reason", error.to_string().into())]);
+
+        // The error cannot be shown.
+        if let Err(error) = self.show_banner(message) {
+            error!("Could not show the error: {}", error.log_message());
+        }
+    }
 
-        println!("Saved to \"{}\"", self.path.display());
+    /// Shows the given message in the banner.
+    fn show_banner(&mut self, message: String) -> Result<(), ApplicationError> {
+        self.widget_manager.send_commands(vec![
+            Command::SetValue(self.banner_text, Box::new(message)),
+            Command::SetIsHidden(self.banner, false),
+        ])?;
 
         Ok(())
     }
-}
 
-impl Application for ApplicationState {
-    fn handle_event(&mut self, system_event: &Event) {
-        // The app was requested to close.
-        if *system_event == Event::RequestClose {
+    /// Handles the given system event.
+    fn try_handle_event(&mut self, system_event: &Event) -> Result<(), ApplicationError> {
+        // The app was requested to close, by the window or by Ctrl+C in the terminal. The request
+        // of Ctrl+C is taken back, so that the dialog after a failed save is not shown again with
+        // every event.
+        if *system_event == Event::RequestClose || self.quit_requested.swap(false, Ordering::SeqCst)
+        {
             // Save and quit the application.
             self.save_and_quit();
+            return Ok(());
         }
 
-        // TODO: error handling
         let widget_events = self
             .widget_manager
-            .handle_event(system_event, None)
-            .unwrap();
+            .handle_event(system_event, self.clipboard.as_mut())?;
+
+        // Ctrl+C (Cmd+C on macOS) copies the selected days' mood values, with shift as JSON, and
+        // Ctrl+V pastes them, if the dashboard is shown and no text input has the focus.
+        if let Event::KeyDown(key_event) = system_event {
+            let is_shortcut = if cfg!(target_os = "macos") {
+                key_event.mods.meta()
+            } else {
+                key_event.mods.ctrl()
+            };
+
+            if is_shortcut && self.active_view.is_none() && self.focused_widget_id.is_none() {
+                if let KbKey::Character(character) = &key_event.key {
+                    match character.to_lowercase().as_str() {
+                        "c" => self.copy_to_clipboard(key_event.mods.shift())?,
+                        "v" => self.paste_from_clipboard()?,
+                        _ => {}
+                    }
+                }
+            }
+        }
 
         // Iterate over the produced widget events.
-        for widget_event in widget_events {
+        for widget_event in &widget_events {
             match widget_event {
                 WidgetEvent::Clicked(widget_id) => {
                     // The close button was clicked.
-                    if widget_id == self.close_button {
+                    if *widget_id == self.close_button {
                         self.save_and_quit();
                     }
-                    // The clear button was clicked.
-                    else if widget_id == self.clear_button {
-                        // TODO: error handling
+                    // The banner's dismiss button was clicked.
+                    else if *widget_id == self.banner_dismiss_button {
                         self.widget_manager
-                            .send_command(Command::SetValue(
-                                self.widget_mood,
-                                Box::new(MoodValuesUpdate::Clear),
-                            ))
-                            .unwrap();
+                            .send_command(Command::SetIsHidden(self.banner, true))?;
+                    }
+                    // The dashboard button was clicked.
+                    else if *widget_id == self.dashboard_button {
+                        self.handle_application_command(ApplicationCommand::ShowDashboard)?;
+                    }
+                    // The thought records button was clicked, record a thought of the first
+                    // selected day, or of today if no days are selected.
+                    else if *widget_id == self.thought_records_button {
+                        let today = Local::now().naive_local().date();
+                        let date = self
+                            .selected_dates()
+                            .map_or(today, |(first_date, _)| first_date);
 
-                        self.application_data.mood_per_day.clear();
+                        self.handle_application_command(ApplicationCommand::ShowThoughtRecords(
+                            date,
+                        ))?;
+                    }
+                    // The inquiries button was clicked.
+                    else if *widget_id == self.inquiries_button {
+                        self.handle_application_command(ApplicationCommand::ShowInquiries)?;
+                    }
+                    // The quotes button was clicked.
+                    else if *widget_id == self.quotes_button {
+                        self.handle_application_command(ApplicationCommand::ShowQuotes)?;
+                    }
+                    // The tags button was clicked.
+                    else if *widget_id == self.tags_button {
+                        self.handle_application_command(ApplicationCommand::ShowTags)?;
+                    }
+                    // The sleep button was clicked, log the sleep of the night after the first
+                    // selected day, or of last night if no days are selected.
+                    else if *widget_id == self.sleep_button {
+                        let yesterday = Local::now().naive_local().date() - Duration::days(1);
+                        let date = self
+                            .selected_dates()
+                            .map_or(yesterday, |(first_date, _)| first_date);
+
+                        self.handle_application_command(ApplicationCommand::EditSleep(date))?;
+                    }
+                    // The medications button was clicked.
+                    else if *widget_id == self.medications_button {
+                        self.handle_application_command(ApplicationCommand::ShowMedications)?;
+                    }
+                    // The settings button was clicked.
+                    else if *widget_id == self.settings_button {
+                        self.handle_application_command(ApplicationCommand::ShowSettings)?;
+                    }
+                    // The dimension button was clicked, switch to the next dimension and after the
+                    // last one back to the mood.
+                    else if *widget_id == self.dimension_button {
+                        self.current_track = self
+                            .application_data
+                            .tracks()
+                            .skip_while(|track| *track != self.current_track)
+                            .nth(1)
+                            .unwrap_or(Track::Mood);
+
+                        self.update_dimension()?;
+                    }
+                    // The clear filter button was clicked.
+                    else if *widget_id == self.clear_filter_button {
+                        self.handle_application_command(ApplicationCommand::FilterByTag(None))?;
+                    }
+                    // The clear button was clicked.
+                    else if *widget_id == self.clear_button {
+                        self.application_data.clear_mood_values_in_month(
+                            self.current_track,
+                            Local::now().naive_local().date(),
+                        );
                         self.there_is_unsaved_data = true;
+
+                        self.update_mood_widget()?;
+                        self.update_greeting_text()?;
                     }
                 }
-                WidgetEvent::ValueChanged(widget_id, value) => {
-                    // A value of the mood widget has changed.
-                    if widget_id == self.widget_mood {
-                        // The given value is a `MoodValuesUpdate`.
-                        if let Some(mood_values_update) = value.downcast_ref::<MoodValuesUpdate>() {
-                            match mood_values_update {
-                                MoodValuesUpdate::Clear => {
-                                    self.application_data.mood_per_day.clear();
-                                    self.there_is_unsaved_data = true;
-                                }
-                                MoodValuesUpdate::Update {
-                                    day_of_month_index,
-                                    mood_values,
-                                } => {
-                                    // No mood values are given.
-                                    if mood_values.is_empty() {
-                                        // Remove the entry.
-                                        self.application_data
-                                            .mood_per_day
-                                            .remove(day_of_month_index);
-                                    }
-                                    // Mood values are given.
-                                    else {
-                                        // Update the mood values in the application data.
-                                        self.application_data
-                                            .mood_per_day
-                                            .insert(*day_of_month_index, mood_values.clone());
-                                    }
-
-                                    self.there_is_unsaved_data = true;
-                                }
-                            }
-                        } else {
-                            unimplemented!()
+                // A widget gained the keyboard focus.
+                WidgetEvent::GainedFocus(widget_id) => self.focused_widget_id = Some(*widget_id),
+                // The widget lost the keyboard focus.
+                WidgetEvent::LostFocus(widget_id) if self.focused_widget_id == Some(*widget_id) => {
+                    self.focused_widget_id = None
+                }
+                // The mood widget sends `MoodSelection`s, `MoodCellClicked`s and
+                // `MoodCellRightClicked`s.
+                WidgetEvent::ValueChanged(widget_id, value) if *widget_id == self.widget_mood => {
+                    // Days were selected or deselected.
+                    if let Some(mood_selection) = value.downcast_ref::<MoodSelection>() {
+                        self.update_selection(mood_selection.day_of_month_indices.clone())?;
+                    }
+                    // A day was right clicked, let the user tag it.
+                    else if let Some(mood_cell_right_clicked) =
+                        value.downcast_ref::<MoodCellRightClicked>()
+                    {
+                        // The mood widget shows the current month.
+                        let today = Local::now().naive_local().date();
+
+                        // The day exists in the current month.
+                        if let Some(date) =
+                            today.with_day0(mood_cell_right_clicked.day_of_month_index as u32)
+                        {
+                            self.handle_application_command(ApplicationCommand::PickTags(date))?;
                         }
                     }
+                    // A mood cell within the selection was clicked, set its mood value to all
+                    // selected days.
+                    else if let Some(mood_cell_clicked) = value
+                        .downcast_ref::<MoodCellClicked>()
+                        .filter(|mood_cell_clicked| {
+                            self.selected_day_of_month_indices.as_ref().is_some_and(
+                                |day_of_month_indices| {
+                                    day_of_month_indices
+                                        .contains(&mood_cell_clicked.day_of_month_index)
+                                },
+                            )
+                        })
+                    {
+                        self.handle_application_command(ApplicationCommand::EditSelectedDays(
+                            BulkEdit::SetMoodValues([mood_cell_clicked.mood_value].into()),
+                        ))?;
+                    }
+                    // Another mood cell was clicked.
+                    else if let Some(mood_cell_clicked) = value.downcast_ref::<MoodCellClicked>()
+                    {
+                        // The mood widget shows the current month.
+                        let today = Local::now().naive_local().date();
+
+                        // The day exists in the current month.
+                        if let Some(date) =
+                            today.with_day0(mood_cell_clicked.day_of_month_index as u32)
+                        {
+                            self.application_data.toggle_mood_value(
+                                self.current_track,
+                                date,
+                                mood_cell_clicked.mood_value,
+                            );
+                            self.there_is_unsaved_data = true;
+
+                            self.update_mood_widget()?;
+                            self.update_greeting_text()?;
+                        }
+                    }
+                }
+                // A night in the sleep widget was clicked, let the user log its sleep.
+                WidgetEvent::ValueChanged(widget_id, value) if *widget_id == self.widget_sleep => {
+                    // The sleep widget shows the current month.
+                    let today = Local::now().naive_local().date();
+
+                    // The day exists in the current month.
+                    if let Some(date) =
+                        value
+                            .downcast_ref::<SleepNightClicked>()
+                            .and_then(|sleep_night_clicked| {
+                                today.with_day0(sleep_night_clicked.day_of_month_index as u32)
+                            })
+                    {
+                        self.handle_application_command(ApplicationCommand::EditSleep(date))?;
+                    }
                 }
                 _ => {}
             }
         }
+
+        // Let the selection bar and the factor panel handle the widget events, if the dashboard
+        // is shown.
+        if self.active_view.is_none() {
+            if let Some(application_command) = self
+                .selection_bar
+                .handle_event(&mut self.widget_manager, &widget_events)?
+            {
+                self.handle_application_command(application_command)?;
+            }
+
+            if let Some(application_command) = self.factor_panel.handle_event(&widget_events)? {
+                self.handle_application_command(application_command)?;
+            }
+        }
+
+        // Let the active view handle the widget events.
+        if let Some(active_view) = self.active_view.clone() {
+            let application_command = active_view.borrow_mut().handle_event(
+                &self.application_data,
+                &mut self.widget_manager,
+                &widget_events,
+            )?;
+
+            if let Some(application_command) = application_command {
+                self.handle_application_command(application_command)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Application for ApplicationState {
+    fn handle_event(&mut self, system_event: &Event) {
+        if let Err(error) = self.try_handle_event(system_event) {
+            self.report_error(error);
+        }
     }
 
     fn paint(&mut self, piet: &mut Piet, region: &Region) {
-        self.widget_manager.paint(piet, region).unwrap();
+        // Fill the background.
+        piet.fill(
+            region.bounding_box(),
+            &PaintBrush::Color(self.theme.background_color.color()),
+        );
+
+        if let Err(error) = self.widget_manager.paint(piet, region) {
+            self.report_error(error.into());
+        }
     }
 
     fn resize(&mut self, size: Size) {
+        self.window_size = size;
         self.widget_manager.resize(size);
     }
 
-    fn set_clipboard(&mut self, _clipboard: Clipboard) {}
+    fn set_clipboard(&mut self, clipboard: Clipboard) {
+        self.clipboard = Some(clipboard);
+    }
 }
 
-pub fn main() -> Result<(), ApplicationError> {
+pub fn main() {
+    if let Err(error) = try_main() {
+        error!("{}", error.log_message());
+        eprintln!("Error: {}", error);
+        std::process::exit(EXIT_CODE_ERROR);
+    }
+}
+
+/// Runs the application or one of its commands.
+fn try_main() -> Result<(), ApplicationError> {
+    // Try to load the settings.
+    let settings_path = Settings::path();
+
+    let (settings, settings_error) = match Settings::load(&settings_path) {
+        Ok(settings) => (settings, None),
+        Err(error) => (Settings::default(), Some(error)),
+    };
+
+    if let Err(error) = init_logging(&settings) {
+        eprintln!("Could not write a log: {}", error);
+    }
+
+    if let Some(error) = settings_error {
+        warn!(
+            path = %settings_path.display(),
+            "Could not load the settings, using the defaults: {}",
+            error.log_message()
+        );
+    }
+
+    let localizer = Localizer::for_locale(settings.locale.as_deref());
+
+    // Render a chart or write a report without a window.
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Some(command @ ("render" | "report")) = arguments.first().map(String::as_str) {
+        let today = Local::now().naive_local().date();
+        let theme = Theme::load(&settings.theme)?;
+        let mut storage = open_storage(settings.data_path());
+
+        return match command {
+            "render" => {
+                render::render_command(&arguments[1..], storage.as_mut(), &localizer, theme)
+            }
+            _ => report::report_command(&arguments[1..], &storage.load(today)?, &localizer, &theme),
+        };
+    }
+
+    let window_size = (settings.window_width, settings.window_height);
+
     // Create the application state.
-    let mut application_state = ApplicationState::new()?;
+    let mut application_state = ApplicationState::new(settings, localizer)?;
 
     // Try to load the application data.
     application_state.load_application_data()?;
 
-    // TODO: Call `ApplicationState::save_and_quit()`
-    ctrlc::set_handler(move || {
-        println!("TODO: call `ApplicationState::save_and_quit()` from the signal handler somehow")
-    })
-    .expect("Error setting Ctrl+C handler");
+    // Ctrl+C and termination save and quit like closing the window does.
+    let quit_requested = application_state.quit_requested.clone();
+
+    if let Err(error) = ctrlc::set_handler(move || {
+        info!("Saving and quitting after Ctrl+C");
+        quit_requested.store(true, Ordering::SeqCst);
+    }) {
+        warn!("Could not handle Ctrl+C: {}", error);
+    }
 
     run(
         Box::new(application_state),
         "freundchen",
-        (800.0, 600.0).into(),
+        window_size.into(),
     );
 
     Ok(())
@@ -0,0 +1,340 @@
+#[cfg(test)]
+mod tests;
+
+mod svg;
+
+use crate::i18n::Localizer;
+use crate::render::svg::SvgRenderContext;
+use crate::theme::Theme;
+use crate::widget::{
+    text_layout, MoodWidget, MoodWidgetStyle, SleepMonth, SleepWidget, MOOD_WIDGET_HEIGHT,
+    SLEEP_WIDGET_HEIGHT,
+};
+use crate::ApplicationError;
+use chrono::{Datelike, Local, NaiveDate};
+use freundchen_core::data::{ApplicationData, Track};
+use freundchen_core::mood::{number_of_days_in_month, MoodMonth};
+use freundchen_core::storage::Storage;
+use guiver::{
+    Color, PaintBrush, Point, Region, RenderContext, Size, SizeConstraints, Stroke, StrokeStyle,
+    Widget,
+};
+use piet_common::Device;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// The usage of the render command.
+pub(crate) const RENDER_USAGE: &str =
+    "freundchen render [--month YYYY-MM] [--dimension NAME] [--width WIDTH] [--scale SCALE] OUTPUT.png|OUTPUT.svg";
+
+/// The margin around the chart.
+const MARGIN: f64 = 20.0;
+
+/// The height of the title above the mood chart.
+const TITLE_HEIGHT: f64 = 30.0;
+
+/// The spacing between the mood and the sleep chart.
+const CHART_SPACING: f64 = 10.0;
+
+/// The image formats a chart can be rendered to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ImageFormat {
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// Determines the image format from the path's extension.
+    fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "png" => Some(ImageFormat::Png),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+// =================================================================================================
+
+/// The chart of a month: the mood or a dimension, and the sleep below if nights were logged.
+pub(crate) struct MonthChart {
+    day_of_month_labels: Vec<String>,
+    /// The day of month index whose column shows the mood value labels.
+    labelled_day_of_month_index: Option<usize>,
+    mood_month: MoodMonth,
+    mood_widget_style: MoodWidgetStyle,
+    /// `None` if no night of the month was logged.
+    sleep_month: Option<SleepMonth>,
+    theme: Theme,
+    title: String,
+    /// The width of the chart in logical pixels.
+    width: f64,
+}
+
+impl MonthChart {
+    /// Creates the chart of the given track in the given date's month.
+    pub(crate) fn new(
+        application_data: &ApplicationData,
+        track: Track,
+        month: NaiveDate,
+        localizer: &Localizer,
+        theme: Theme,
+        width: f64,
+    ) -> Self {
+        // Label today's column, if the month is the current one.
+        let today = Local::now().naive_local().date();
+
+        let labelled_day_of_month_index = (today.year() == month.year()
+            && today.month() == month.month())
+        .then_some(today.day0() as usize);
+
+        let dimension = match track {
+            Track::Dimension(index) => application_data.dimensions.get(index),
+            Track::Mood => None,
+        };
+
+        let (mood_widget_style, title) = match dimension {
+            Some(dimension) => (
+                theme.dimension_mood_widget_style(dimension),
+                format!("{}: {}", localizer.month_year(month), dimension.name),
+            ),
+            None => (theme.mood_widget_style(), localizer.month_year(month)),
+        };
+
+        let sleep_month = SleepMonth::new(&application_data.sleep_per_date, month);
+
+        MonthChart {
+            day_of_month_labels: (1..=number_of_days_in_month(month))
+                .map(|day| localizer.day_of_month(day))
+                .collect(),
+            labelled_day_of_month_index,
+            mood_month: MoodMonth::from_application_data(application_data, track, month),
+            mood_widget_style,
+            sleep_month: sleep_month
+                .nights
+                .iter()
+                .any(Option::is_some)
+                .then_some(sleep_month),
+            theme,
+            title,
+            width,
+        }
+    }
+
+    /// The size of the chart in logical pixels.
+    pub(crate) fn size(&self) -> Size {
+        let sleep_height = match self.sleep_month {
+            Some(_) => CHART_SPACING + SLEEP_WIDGET_HEIGHT,
+            None => 0.0,
+        };
+
+        Size::new(
+            self.width,
+            MARGIN + TITLE_HEIGHT + MOOD_WIDGET_HEIGHT + sleep_height + MARGIN,
+        )
+    }
+
+    /// Renders the chart to a PNG or SVG file, depending on the path's extension. The scale is
+    /// the number of pixels per logical pixel of a PNG file.
+    pub(crate) fn render(&self, path: &Path, scale: f64) -> Result<(), ApplicationError> {
+        match ImageFormat::from_path(path) {
+            Some(ImageFormat::Png) => self.render_png(path, scale),
+            Some(ImageFormat::Svg) => self.render_svg(path),
+            None => Err(ApplicationError::Arguments(format!(
+                "\"{}\" does not end with \".png\" or \".svg\"",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Renders the chart to a PNG file with piet's headless bitmap target.
+    fn render_png(&self, path: &Path, scale: f64) -> Result<(), ApplicationError> {
+        let size = self.size();
+
+        let mut device = Device::new()?;
+        let mut bitmap_target = device.bitmap_target(
+            (size.width * scale).ceil() as usize,
+            (size.height * scale).ceil() as usize,
+            scale,
+        )?;
+
+        {
+            let mut piet = bitmap_target.render_context();
+            self.paint(&mut piet)?;
+            piet.finish()?;
+        }
+
+        bitmap_target.save_to_file(path)?;
+
+        Ok(())
+    }
+
+    /// Renders the chart to an SVG file with piet-svg.
+    fn render_svg(&self, path: &Path) -> Result<(), ApplicationError> {
+        let mut svg_render_context = SvgRenderContext::new(self.size());
+        self.paint(&mut svg_render_context)?;
+        svg_render_context.finish()?;
+
+        svg_render_context.write(BufWriter::new(File::create(path)?))?;
+
+        Ok(())
+    }
+
+    /// Paints the chart with the widgets of the application window.
+    fn paint<R: RenderContext>(&self, piet: &mut R) -> Result<(), ApplicationError>
+    where
+        R::TextLayout: 'static,
+    {
+        let size = self.size();
+        let widgets_width = size.width - 2.0 * MARGIN;
+        let debug_rendering_stroke = Stroke {
+            stroke_brush: PaintBrush::Color(Color::rgb8(255, 0, 0)),
+            stroke_style: StrokeStyle::default(),
+            stroke_width: 1.0,
+        };
+
+        let mut mood_widget = MoodWidget::new(
+            0,
+            debug_rendering_stroke.clone(),
+            self.day_of_month_labels.clone(),
+            self.mood_widget_style.clone(),
+        );
+
+        mood_widget.set_labelled_day_of_month_index(self.labelled_day_of_month_index);
+        mood_widget.set_value(Box::new(self.mood_month.clone()))?;
+        mood_widget.apply_size_constraints(SizeConstraints::new(
+            Size::ZERO,
+            Size::new(widgets_width, MOOD_WIDGET_HEIGHT),
+        ));
+        mood_widget.set_origin(Point::new(MARGIN, MARGIN + TITLE_HEIGHT));
+
+        let mut region = Region::EMPTY;
+        region.add_rect(size.to_rect());
+
+        piet.fill(
+            size.to_rect(),
+            &PaintBrush::Color(self.theme.background_color.color()),
+        );
+
+        let title_text_layout = text_layout(piet, &self.theme.title_font(), self.title.clone())?;
+        piet.draw_text(&title_text_layout, (MARGIN, MARGIN));
+
+        mood_widget.paint_to(piet, &region)?;
+
+        // The sleep columns are aligned with the mood columns.
+        if let Some(sleep_month) = &self.sleep_month {
+            let mut sleep_widget = SleepWidget::new(
+                0,
+                debug_rendering_stroke,
+                self.day_of_month_labels.len() as u8,
+                self.theme.sleep_widget_style(),
+            );
+
+            sleep_widget.set_value(Box::new(sleep_month.clone()))?;
+            sleep_widget.apply_size_constraints(SizeConstraints::new(
+                Size::ZERO,
+                Size::new(widgets_width, SLEEP_WIDGET_HEIGHT),
+            ));
+            sleep_widget.set_origin(Point::new(
+                MARGIN,
+                MARGIN + TITLE_HEIGHT + MOOD_WIDGET_HEIGHT + CHART_SPACING,
+            ));
+            sleep_widget.paint_to(piet)?;
+        }
+
+        Ok(())
+    }
+}
+
+// =================================================================================================
+
+/// Parses a month like `2026-09` to the month's first day.
+fn parse_month(text: &str) -> Result<NaiveDate, ApplicationError> {
+    NaiveDate::parse_from_str(&format!("{}-01", text.trim()), "%Y-%m-%d").map_err(|_| {
+        ApplicationError::Arguments(format!("\"{}\" is not a month like \"2026-09\"", text))
+    })
+}
+
+/// Parses a positive number given for the option.
+fn parse_positive_number(option: &str, text: &str) -> Result<f64, ApplicationError> {
+    match text.trim().parse::<f64>() {
+        Ok(number) if number > 0.0 && number.is_finite() => Ok(number),
+        _ => Err(ApplicationError::Arguments(format!(
+            "{} needs a positive number, not \"{}\"",
+            option, text
+        ))),
+    }
+}
+
+/// Runs the render command with the given arguments, see `RENDER_USAGE`.
+pub(crate) fn render_command(
+    arguments: &[String],
+    storage: &mut dyn Storage,
+    localizer: &Localizer,
+    theme: Theme,
+) -> Result<(), ApplicationError> {
+    let today = Local::now().naive_local().date();
+    let mut dimension_name = None;
+    let mut month = today.with_day(1).unwrap_or_default();
+    let mut output_path = None;
+    let mut scale = 1.0;
+    let mut width = 800.0;
+
+    let mut arguments = arguments.iter();
+
+    while let Some(argument) = arguments.next() {
+        let mut option_value = || {
+            arguments
+                .next()
+                .ok_or_else(|| ApplicationError::Arguments(format!("{} needs a value", argument)))
+        };
+
+        match argument.as_str() {
+            "--dimension" => dimension_name = Some(option_value()?.trim()),
+            "--month" => month = parse_month(option_value()?)?,
+            "--scale" => scale = parse_positive_number(argument, option_value()?)?,
+            "--width" => width = parse_positive_number(argument, option_value()?)?,
+            _ if output_path.is_none() && !argument.starts_with("--") => {
+                output_path = Some(Path::new(argument))
+            }
+            _ => {
+                return Err(ApplicationError::Arguments(format!(
+                    "Unexpected argument \"{}\", usage: {}",
+                    argument, RENDER_USAGE
+                )))
+            }
+        }
+    }
+
+    let output_path = output_path.ok_or_else(|| {
+        ApplicationError::Arguments(format!(
+            "The output file is missing, usage: {}",
+            RENDER_USAGE
+        ))
+    })?;
+
+    // Besides the mood values, the dimensions and the sleep are charted.
+    let application_data = storage.load(today)?;
+
+    let track = match dimension_name {
+        Some(dimension_name) => application_data
+            .dimensions
+            .iter()
+            .position(|dimension| dimension.name.eq_ignore_ascii_case(dimension_name))
+            .map(Track::Dimension)
+            .ok_or_else(|| {
+                ApplicationError::Arguments(format!("There is no dimension \"{}\"", dimension_name))
+            })?,
+        None => Track::Mood,
+    };
+
+    MonthChart::new(&application_data, track, month, localizer, theme, width)
+        .render(output_path, scale)?;
+
+    println!("Rendered to \"{}\"", output_path.display());
+
+    Ok(())
+}
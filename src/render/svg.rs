@@ -0,0 +1,382 @@
+use guiver::{Color, Error, FontFamily, FontWeight, IntoBrush, Point, Rect, Size};
+use piet_common::kurbo::{Affine, Shape};
+use piet_common::{
+    FixedGradient, HitTestPoint, HitTestPosition, ImageFormat, InterpolationMode, LineMetric,
+    RenderContext, StrokeStyle, Text, TextAttribute, TextLayout, TextLayoutBuilder, TextStorage,
+};
+use std::borrow::Cow;
+use std::io;
+
+/// The estimated width of a character relative to the font size, since there are no font metrics
+/// without a text backend.
+const CHARACTER_WIDTH_RATIO: f64 = 0.6;
+
+/// The height of a line relative to the font size.
+const LINE_HEIGHT_RATIO: f64 = 1.2;
+
+/// A render context that paints to an SVG document with piet-svg. piet-svg cannot write texts, so
+/// they are written as `<text>` elements centered on their estimated layout on top of the shapes.
+pub(crate) struct SvgRenderContext {
+    inner: piet_svg::RenderContext,
+    size: Size,
+    text: SvgText,
+    text_elements: Vec<String>,
+}
+
+impl SvgRenderContext {
+    /// Creates a render context for a document of the given size.
+    pub(crate) fn new(size: Size) -> Self {
+        SvgRenderContext {
+            inner: piet_svg::RenderContext::new(),
+            size,
+            text: SvgText,
+            text_elements: vec![],
+        }
+    }
+
+    /// Writes the document painted so far.
+    pub(crate) fn write(&self, mut writer: impl io::Write) -> io::Result<()> {
+        let mut document = vec![];
+        self.inner.write(&mut document)?;
+        let document = String::from_utf8_lossy(&document);
+
+        let (Some(start), Some(end)) = (document.find("<svg"), document.rfind("</svg>")) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "piet-svg wrote no SVG element",
+            ));
+        };
+
+        // piet-svg writes neither the size nor the view box.
+        write!(
+            writer,
+            r#"{}<svg width="{}" height="{}" viewBox="0 0 {} {}"{}"#,
+            &document[..start],
+            self.size.width,
+            self.size.height,
+            self.size.width,
+            self.size.height,
+            &document[start + "<svg".len()..end]
+        )?;
+
+        for text_element in &self.text_elements {
+            writeln!(writer, "{}", text_element)?;
+        }
+
+        write!(writer, "{}", &document[end..])
+    }
+}
+
+impl RenderContext for SvgRenderContext {
+    type Brush = piet_svg::Brush;
+    type Text = SvgText;
+    type TextLayout = SvgTextLayout;
+    type Image = piet_svg::SvgImage;
+
+    fn status(&mut self) -> Result<(), Error> {
+        self.inner.status()
+    }
+
+    fn solid_brush(&mut self, color: Color) -> Self::Brush {
+        self.inner.solid_brush(color)
+    }
+
+    fn gradient(&mut self, gradient: impl Into<FixedGradient>) -> Result<Self::Brush, Error> {
+        self.inner.gradient(gradient)
+    }
+
+    fn clear(&mut self, region: impl Into<Option<Rect>>, color: Color) {
+        self.inner.clear(region, color)
+    }
+
+    fn stroke(&mut self, shape: impl Shape, brush: &impl IntoBrush<Self>, width: f64) {
+        let brush = brush.make_brush(self, || shape.bounding_box());
+        self.inner.stroke(shape, &*brush, width)
+    }
+
+    fn stroke_styled(
+        &mut self,
+        shape: impl Shape,
+        brush: &impl IntoBrush<Self>,
+        width: f64,
+        style: &StrokeStyle,
+    ) {
+        let brush = brush.make_brush(self, || shape.bounding_box());
+        self.inner.stroke_styled(shape, &*brush, width, style)
+    }
+
+    fn fill(&mut self, shape: impl Shape, brush: &impl IntoBrush<Self>) {
+        let brush = brush.make_brush(self, || shape.bounding_box());
+        self.inner.fill(shape, &*brush)
+    }
+
+    fn fill_even_odd(&mut self, shape: impl Shape, brush: &impl IntoBrush<Self>) {
+        let brush = brush.make_brush(self, || shape.bounding_box());
+        self.inner.fill_even_odd(shape, &*brush)
+    }
+
+    fn clip(&mut self, shape: impl Shape) {
+        self.inner.clip(shape)
+    }
+
+    fn text(&mut self) -> &mut Self::Text {
+        &mut self.text
+    }
+
+    fn draw_text(&mut self, layout: &Self::TextLayout, pos: impl Into<Point>) {
+        let size = layout.size();
+        let center =
+            self.current_transform() * (pos.into() + (size.width / 2.0, size.height / 2.0));
+        let (red, green, blue, alpha) = layout.color.as_rgba8();
+
+        self.text_elements.push(format!(
+            r##"<text x="{}" y="{}" font-family="{}" font-size="{}" font-weight="{}" fill="#{:02x}{:02x}{:02x}" fill-opacity="{}" text-anchor="middle" dominant-baseline="central">{}</text>"##,
+            center.x,
+            center.y,
+            escape_xml(layout.font_family.name()),
+            layout.font_size,
+            layout.font_weight.to_raw(),
+            red,
+            green,
+            blue,
+            alpha as f64 / 255.0,
+            escape_xml(&layout.text)
+        ));
+    }
+
+    fn save(&mut self) -> Result<(), Error> {
+        self.inner.save()
+    }
+
+    fn restore(&mut self) -> Result<(), Error> {
+        self.inner.restore()
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        self.inner.finish()
+    }
+
+    fn transform(&mut self, transform: Affine) {
+        self.inner.transform(transform)
+    }
+
+    fn make_image(
+        &mut self,
+        width: usize,
+        height: usize,
+        buf: &[u8],
+        format: ImageFormat,
+    ) -> Result<Self::Image, Error> {
+        self.inner.make_image(width, height, buf, format)
+    }
+
+    fn draw_image(
+        &mut self,
+        image: &Self::Image,
+        dst_rect: impl Into<Rect>,
+        interp: InterpolationMode,
+    ) {
+        self.inner.draw_image(image, dst_rect, interp)
+    }
+
+    fn draw_image_area(
+        &mut self,
+        image: &Self::Image,
+        src_rect: impl Into<Rect>,
+        dst_rect: impl Into<Rect>,
+        interp: InterpolationMode,
+    ) {
+        self.inner
+            .draw_image_area(image, src_rect, dst_rect, interp)
+    }
+
+    fn capture_image_area(&mut self, src_rect: impl Into<Rect>) -> Result<Self::Image, Error> {
+        self.inner.capture_image_area(src_rect)
+    }
+
+    fn blurred_rect(&mut self, rect: Rect, blur_radius: f64, brush: &impl IntoBrush<Self>) {
+        let brush = brush.make_brush(self, || rect);
+        self.inner.blurred_rect(rect, blur_radius, &*brush)
+    }
+
+    fn current_transform(&self) -> Affine {
+        self.inner.current_transform()
+    }
+}
+
+impl IntoBrush<SvgRenderContext> for piet_svg::Brush {
+    fn make_brush<'a>(
+        &'a self,
+        _piet: &mut SvgRenderContext,
+        _bbox: impl FnOnce() -> Rect,
+    ) -> Cow<'a, piet_svg::Brush> {
+        Cow::Borrowed(self)
+    }
+}
+
+// =================================================================================================
+
+/// The text backend of the `SvgRenderContext`. Fonts are referenced by their family name.
+#[derive(Clone)]
+pub(crate) struct SvgText;
+
+impl Text for SvgText {
+    type TextLayoutBuilder = SvgTextLayoutBuilder;
+    type TextLayout = SvgTextLayout;
+
+    fn font_family(&mut self, family_name: &str) -> Option<FontFamily> {
+        Some(FontFamily::new_unchecked(family_name))
+    }
+
+    fn load_font(&mut self, _data: &[u8]) -> Result<FontFamily, Error> {
+        Err(Error::NotSupported)
+    }
+
+    fn new_text_layout(&mut self, text: impl TextStorage) -> Self::TextLayoutBuilder {
+        SvgTextLayoutBuilder(SvgTextLayout {
+            color: Color::BLACK,
+            font_family: FontFamily::SANS_SERIF,
+            font_size: 12.0,
+            font_weight: FontWeight::NORMAL,
+            text: text.as_str().to_string(),
+        })
+    }
+}
+
+/// Builds an `SvgTextLayout`. Only the default attributes are applied to the whole text.
+pub(crate) struct SvgTextLayoutBuilder(SvgTextLayout);
+
+impl TextLayoutBuilder for SvgTextLayoutBuilder {
+    type Out = SvgTextLayout;
+
+    fn max_width(self, _width: f64) -> Self {
+        self
+    }
+
+    fn alignment(self, _alignment: piet_common::TextAlignment) -> Self {
+        self
+    }
+
+    fn default_attribute(mut self, attribute: impl Into<TextAttribute>) -> Self {
+        match attribute.into() {
+            TextAttribute::FontFamily(font_family) => self.0.font_family = font_family,
+            TextAttribute::FontSize(font_size) => self.0.font_size = font_size,
+            TextAttribute::TextColor(color) => self.0.color = color,
+            TextAttribute::Weight(font_weight) => self.0.font_weight = font_weight,
+            _ => {}
+        }
+
+        self
+    }
+
+    fn range_attribute(
+        self,
+        _range: impl std::ops::RangeBounds<usize>,
+        _attribute: impl Into<TextAttribute>,
+    ) -> Self {
+        self
+    }
+
+    fn build(self) -> Result<Self::Out, Error> {
+        Ok(self.0)
+    }
+}
+
+/// A single line of text, whose size is estimated from the font size.
+#[derive(Clone)]
+pub(crate) struct SvgTextLayout {
+    color: Color,
+    font_family: FontFamily,
+    font_size: f64,
+    font_weight: FontWeight,
+    text: String,
+}
+
+impl SvgTextLayout {
+    /// Returns the estimated width of a character.
+    fn character_width(&self) -> f64 {
+        CHARACTER_WIDTH_RATIO * self.font_size
+    }
+}
+
+impl TextLayout for SvgTextLayout {
+    fn size(&self) -> Size {
+        Size::new(
+            self.text.chars().count() as f64 * self.character_width(),
+            LINE_HEIGHT_RATIO * self.font_size,
+        )
+    }
+
+    fn trailing_whitespace_width(&self) -> f64 {
+        let number_of_trailing_whitespaces =
+            self.text.chars().count() - self.text.trim_end().chars().count();
+
+        self.size().width + number_of_trailing_whitespaces as f64 * self.character_width()
+    }
+
+    fn image_bounds(&self) -> Rect {
+        self.size().to_rect()
+    }
+
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn line_text(&self, line_number: usize) -> Option<&str> {
+        (line_number == 0).then_some(self.text.as_str())
+    }
+
+    fn line_metric(&self, line_number: usize) -> Option<LineMetric> {
+        (line_number == 0).then(|| LineMetric {
+            start_offset: 0,
+            end_offset: self.text.len(),
+            trailing_whitespace: self.text.len() - self.text.trim_end().len(),
+            baseline: self.font_size,
+            height: self.size().height,
+            y_offset: 0.0,
+        })
+    }
+
+    fn line_count(&self) -> usize {
+        1
+    }
+
+    fn hit_test_point(&self, point: Point) -> HitTestPoint {
+        let character_index = (point.x / self.character_width()).round().max(0.0) as usize;
+
+        // The text position is the byte offset of the character.
+        let idx = self
+            .text
+            .char_indices()
+            .nth(character_index)
+            .map_or(self.text.len(), |(idx, _)| idx);
+
+        HitTestPoint::new(idx, self.image_bounds().contains(point))
+    }
+
+    fn hit_test_text_position(&self, idx: usize) -> HitTestPosition {
+        let number_of_characters = self
+            .text
+            .char_indices()
+            .take_while(|(character_idx, _)| *character_idx < idx)
+            .count();
+
+        HitTestPosition::new(
+            Point::new(
+                number_of_characters as f64 * self.character_width(),
+                self.font_size,
+            ),
+            0,
+        )
+    }
+}
+
+// =================================================================================================
+
+/// Escapes the given text for XML.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,140 @@
+use super::*;
+use chrono::NaiveTime;
+use freundchen_core::data::{Dimension, SleepEntry};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// The width of the test charts.
+const WIDTH: f64 = 400.0;
+
+/// Returns the first day of September 2026, which has 30 days.
+fn month() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 9, 1).unwrap()
+}
+
+/// Returns data with mood values, a three-valued dimension and a night in the test month.
+fn application_data() -> ApplicationData {
+    let mut application_data = ApplicationData::new();
+    application_data.dimensions = vec![Dimension::new("Energy", 3, vec![]).unwrap()];
+
+    application_data.set_mood_values(Track::Mood, month(), [2, 9].into());
+    application_data.set_mood_values(Track::Dimension(0), month(), [1].into());
+    application_data.sleep_per_date.insert(
+        month(),
+        SleepEntry::new(
+            NaiveTime::from_hms_opt(23, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            4,
+            0,
+        )
+        .unwrap(),
+    );
+
+    application_data
+}
+
+/// Creates the test chart of the given track, with the mood value labels on the first day.
+fn month_chart(application_data: &ApplicationData, track: Track) -> MonthChart {
+    let mut month_chart = MonthChart::new(
+        application_data,
+        track,
+        month(),
+        &Localizer::new("en"),
+        Theme::default(),
+        WIDTH,
+    );
+    month_chart.labelled_day_of_month_index = Some(0);
+
+    month_chart
+}
+
+/// Returns a path in the temporary directory, unique for the test.
+fn output_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("freundchen-render-{}-{}", std::process::id(), name))
+}
+
+/// Renders the chart of the given track to SVG and returns the document.
+fn render_svg(application_data: &ApplicationData, track: Track) -> String {
+    let path = output_path(&format!("{:?}.svg", track));
+
+    month_chart(application_data, track)
+        .render(&path, 1.0)
+        .expect("the chart should be rendered");
+
+    let svg = fs::read_to_string(&path).expect("the SVG file should be read");
+    let _ = fs::remove_file(&path);
+
+    svg
+}
+
+// =================================================================================================
+
+#[test]
+fn the_chart_includes_the_sleep_only_if_nights_were_logged() {
+    let mut application_data = application_data();
+    let with_sleep = month_chart(&application_data, Track::Mood).size();
+
+    application_data.sleep_per_date.clear();
+    let without_sleep = month_chart(&application_data, Track::Mood).size();
+
+    assert_eq!(with_sleep.width, WIDTH);
+    assert_eq!(
+        with_sleep.height - without_sleep.height,
+        CHART_SPACING + SLEEP_WIDGET_HEIGHT
+    );
+}
+
+#[test]
+fn a_png_is_rendered_headless_with_the_scale() {
+    let month_chart = month_chart(&application_data(), Track::Mood);
+    let path = output_path("chart.png");
+
+    month_chart
+        .render(&path, 2.0)
+        .expect("the chart should be rendered");
+
+    let reader = png::Decoder::new(File::open(&path).expect("the PNG file should be opened"))
+        .read_info()
+        .expect("the PNG file should be valid");
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(
+        (reader.info().width, reader.info().height),
+        (
+            (2.0 * month_chart.size().width) as u32,
+            (2.0 * month_chart.size().height) as u32
+        )
+    );
+}
+
+#[test]
+fn an_svg_is_rendered_through_piet() {
+    let svg = render_svg(&application_data(), Track::Mood);
+
+    assert!(svg.contains(r#"<svg width="400" height="#));
+    assert!(svg.contains(r#"viewBox="0 0 400 "#));
+    assert!(svg.contains(">September 2026</text>"));
+    // The cells of the mood values get their colours from the theme.
+    assert!(svg.contains(&String::from(Theme::default().mood_colors[2])));
+    assert!(svg.contains(&String::from(Theme::default().mood_colors[9])));
+    // The title, the 30 day labels and the 11 mood value labels.
+    assert_eq!(svg.matches("<text ").count(), 1 + 30 + 11);
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn a_dimension_is_charted_with_its_number_of_values() {
+    let svg = render_svg(&application_data(), Track::Dimension(0));
+
+    assert!(svg.contains(">September 2026: Energy</text>"));
+    // The title, the 30 day labels and the 3 dimension value labels.
+    assert_eq!(svg.matches("<text ").count(), 1 + 30 + 3);
+}
+
+#[test]
+fn an_unknown_image_format_is_rejected() {
+    let result = month_chart(&application_data(), Track::Mood).render(Path::new("chart.gif"), 1.0);
+
+    assert!(matches!(result, Err(ApplicationError::Arguments(_))));
+}
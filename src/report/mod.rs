@@ -0,0 +1,711 @@
+#[cfg(test)]
+mod tests;
+
+use crate::i18n::Localizer;
+use crate::theme::{Theme, ThemeColor};
+use crate::ApplicationError;
+use chrono::{Datelike, Local, NaiveDate};
+use freundchen_core::data::{ApplicationData, FactorKind, FactorValue, Track};
+use freundchen_core::mood::{number_of_days_in_month, NUMBER_OF_MOOD_VALUES_PER_DAY};
+use freundchen_core::statistics::{Adherence, MoodStatistics};
+use printpdf::{BuiltinFont, Color, Line, Mm, OffsetDateTime, PdfDocument, Point, Rect, Rgb};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// The usage of the report command.
+pub(crate) const REPORT_USAGE: &str =
+    "freundchen report [--from YYYY-MM-DD] [--to YYYY-MM-DD] OUTPUT.pdf";
+
+/// The width of an A4 page in millimetres.
+const PAGE_WIDTH: f64 = 210.0;
+
+/// The height of an A4 page in millimetres.
+const PAGE_HEIGHT: f64 = 297.0;
+
+/// The margin around the page's content in millimetres.
+const PAGE_MARGIN: f64 = 20.0;
+
+/// The height of a mood cell in millimetres.
+const MOOD_CELL_HEIGHT: f64 = 5.0;
+
+/// The font size of body text in points.
+const TEXT_FONT_SIZE: f64 = 10.0;
+
+/// The maximum number of characters per line of body text.
+const MAXIMUM_LINE_LENGTH: usize = 95;
+
+/// The colour of the grid lines.
+const GRID_COLOR: ThemeColor = ThemeColor(160, 160, 160);
+
+/// The colour of the text.
+const TEXT_COLOR: ThemeColor = ThemeColor(0, 0, 0);
+
+/// Converts points to millimetres.
+fn mm_from_pt(pt: f64) -> f64 {
+    pt * 25.4 / 72.0
+}
+
+/// An element of a report page. Coordinates are in millimetres from the page's top left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ReportElement {
+    Line {
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+        color: ThemeColor,
+    },
+    Rectangle {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: ThemeColor,
+    },
+    /// A line of text whose top is at `y`.
+    Text {
+        x: f64,
+        y: f64,
+        font_size: f64,
+        is_bold: bool,
+        text: String,
+    },
+}
+
+// =================================================================================================
+
+/// A printable report of a date range. The layout only depends on the application data, the date
+/// range, the locale and the theme's mood colours, so that it can be compared in snapshot tests.
+pub(crate) struct Report {
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+    /// The elements per page.
+    pages: Vec<Vec<ReportElement>>,
+    title: String,
+    /// The top of the next element on the current page.
+    y: f64,
+}
+
+impl Report {
+    /// Lays out the report from `first_date` to `last_date`, inclusively.
+    pub(crate) fn new(
+        application_data: &ApplicationData,
+        first_date: NaiveDate,
+        last_date: NaiveDate,
+        localizer: &Localizer,
+        theme: &Theme,
+    ) -> Self {
+        let title = localizer.text_with(
+            "report-title",
+            &[
+                ("first-date", localizer.date(first_date).into()),
+                ("last-date", localizer.date(last_date).into()),
+            ],
+        );
+
+        let mut report = Report {
+            first_date,
+            last_date,
+            pages: vec![vec![]],
+            title: title.clone(),
+            y: PAGE_MARGIN,
+        };
+
+        report.add_text(&title, 18.0, true);
+        report.add_space(4.0);
+        report.add_summary(application_data, localizer);
+        report.add_mood_grids(application_data, localizer, theme);
+        report.add_factors(application_data, localizer);
+        report.add_medications(application_data, localizer);
+        report.add_notes(application_data, localizer);
+
+        report
+    }
+
+    /// Adds how often the yes-or-no factors were yes within the date range.
+    fn add_factors(&mut self, application_data: &ApplicationData, localizer: &Localizer) {
+        let mut lines = vec![];
+
+        for factor in &application_data.factors {
+            if factor.kind != FactorKind::Boolean {
+                continue;
+            }
+
+            let values: Vec<&FactorValue> = factor
+                .values_per_date
+                .range(self.first_date..=self.last_date)
+                .map(|(_, value)| value)
+                .collect();
+
+            if values.is_empty() {
+                continue;
+            }
+
+            lines.push(
+                localizer.text_with(
+                    "report-factor",
+                    &[
+                        ("name", factor.name.as_str().into()),
+                        (
+                            "count",
+                            values
+                                .iter()
+                                .filter(|value| matches!(value, FactorValue::Boolean(true)))
+                                .count()
+                                .into(),
+                        ),
+                        ("days", values.len().into()),
+                    ],
+                ),
+            );
+        }
+
+        self.add_section(&localizer.text("report-factors"), lines);
+    }
+
+    /// Adds how regularly the medications were taken within the date range.
+    fn add_medications(&mut self, application_data: &ApplicationData, localizer: &Localizer) {
+        let mut lines = vec![];
+
+        for medication in &application_data.medications {
+            // The medication was not taken within the date range.
+            if medication.start_date > self.last_date
+                || medication
+                    .stop_date
+                    .is_some_and(|stop_date| stop_date <= self.first_date)
+            {
+                continue;
+            }
+
+            let adherence = Adherence::new(medication, self.first_date, self.last_date);
+
+            let adherence_text = match adherence.ratio() {
+                Some(ratio) => localizer.text_with(
+                    "medications-adherence",
+                    &[
+                        ("taken", adherence.number_of_days_taken.into()),
+                        ("scheduled", adherence.number_of_scheduled_days.into()),
+                        ("percent", ((ratio * 100.0).round() as i64).into()),
+                    ],
+                ),
+                None => localizer.text_with(
+                    "medications-intakes",
+                    &[("count", adherence.number_of_intakes.into())],
+                ),
+            };
+
+            lines.push(localizer.text_with(
+                "report-medication",
+                &[
+                    ("name", medication.name.as_str().into()),
+                    ("adherence", adherence_text.into()),
+                ],
+            ));
+        }
+
+        self.add_section(&localizer.text("report-medications"), lines);
+    }
+
+    /// Adds a heading with the given lines below it, unless there are none.
+    fn add_section(&mut self, heading: &str, lines: Vec<String>) {
+        if lines.is_empty() {
+            return;
+        }
+
+        self.add_heading(heading);
+
+        for line in lines {
+            self.add_paragraph(&line);
+        }
+    }
+
+    /// Adds a heading.
+    fn add_heading(&mut self, heading: &str) {
+        self.add_space(4.0);
+        // Keep the heading together with the first lines below it.
+        self.ensure_space(mm_from_pt(14.0) + 3.0 * mm_from_pt(TEXT_FONT_SIZE) * 1.4);
+        self.add_text(heading, 14.0, true);
+        self.add_space(1.0);
+    }
+
+    /// Adds the mood grid of each month within the date range.
+    fn add_mood_grids(
+        &mut self,
+        application_data: &ApplicationData,
+        localizer: &Localizer,
+        theme: &Theme,
+    ) {
+        let grid_width = PAGE_WIDTH - 2.0 * PAGE_MARGIN;
+        let grid_height = NUMBER_OF_MOOD_VALUES_PER_DAY as f64 * MOOD_CELL_HEIGHT;
+        let day_labels_height = mm_from_pt(7.0) + 1.0;
+
+        let mut month = self.first_date.with_day(1).unwrap_or(self.first_date);
+
+        while month <= self.last_date {
+            self.add_heading(&localizer.month_year(month));
+            self.ensure_space(day_labels_height + grid_height);
+
+            let number_of_days = number_of_days_in_month(month) as usize;
+            let cell_width = grid_width / number_of_days as f64;
+            let grid_y0 = self.y + day_labels_height;
+            let grid_y1 = grid_y0 + grid_height;
+
+            // Label the days.
+            for day_of_month_index in 0..number_of_days {
+                self.push(ReportElement::Text {
+                    x: PAGE_MARGIN + day_of_month_index as f64 * cell_width + 0.5,
+                    y: self.y,
+                    font_size: 7.0,
+                    is_bold: false,
+                    text: localizer.day_of_month(day_of_month_index as u8 + 1),
+                });
+            }
+
+            // Label the mood values.
+            for mood_value in 0..NUMBER_OF_MOOD_VALUES_PER_DAY {
+                self.push(ReportElement::Text {
+                    x: PAGE_MARGIN - 5.0,
+                    y: grid_y1 - (1.0 + mood_value as f64) * MOOD_CELL_HEIGHT + 1.0,
+                    font_size: 7.0,
+                    is_bold: false,
+                    text: mood_value.to_string(),
+                });
+            }
+
+            // Fill the cells within the date range.
+            for (day_of_month_index, mood_values) in
+                application_data.mood_values_in_month(Track::Mood, month)
+            {
+                let Some(date) = month.with_day0(day_of_month_index as u32) else {
+                    continue;
+                };
+
+                if date < self.first_date || date > self.last_date {
+                    continue;
+                }
+
+                let mut mood_values: Vec<u8> = mood_values.iter().copied().collect();
+                mood_values.sort_unstable();
+
+                for mood_value in mood_values {
+                    if let Some(mood_color) = theme.mood_colors.get(mood_value as usize) {
+                        self.push(ReportElement::Rectangle {
+                            x: PAGE_MARGIN + day_of_month_index as f64 * cell_width,
+                            y: grid_y1 - (1.0 + mood_value as f64) * MOOD_CELL_HEIGHT,
+                            width: cell_width,
+                            height: MOOD_CELL_HEIGHT,
+                            color: *mood_color,
+                        });
+                    }
+                }
+            }
+
+            // Draw the grid.
+            for column_index in 0..=number_of_days {
+                let x = PAGE_MARGIN + column_index as f64 * cell_width;
+
+                self.push(ReportElement::Line {
+                    x0: x,
+                    y0: grid_y0,
+                    x1: x,
+                    y1: grid_y1,
+                    color: GRID_COLOR,
+                });
+            }
+
+            for row_index in 0..=NUMBER_OF_MOOD_VALUES_PER_DAY {
+                let y = grid_y0 + row_index as f64 * MOOD_CELL_HEIGHT;
+
+                self.push(ReportElement::Line {
+                    x0: PAGE_MARGIN,
+                    y0: y,
+                    x1: PAGE_MARGIN + grid_width,
+                    y1: y,
+                    color: GRID_COLOR,
+                });
+            }
+
+            self.y = grid_y1 + 2.0;
+
+            // Continue with the next month.
+            month = match month.checked_add_months(chrono::Months::new(1)) {
+                Some(next_month) => next_month,
+                None => break,
+            };
+        }
+    }
+
+    /// Adds the thought records and inquiries within the date range.
+    fn add_notes(&mut self, application_data: &ApplicationData, localizer: &Localizer) {
+        let thought_records: Vec<_> = application_data
+            .thought_records
+            .iter()
+            .filter(|thought_record| {
+                thought_record.date >= self.first_date && thought_record.date <= self.last_date
+            })
+            .collect();
+
+        let inquiries: Vec<_> = application_data
+            .inquiries
+            .iter()
+            .filter(|inquiry| {
+                let date = inquiry.date_time.date();
+                date >= self.first_date && date <= self.last_date
+            })
+            .collect();
+
+        if !thought_records.is_empty() {
+            self.add_heading(&localizer.text("report-thought-records"));
+
+            for thought_record in thought_records {
+                self.add_paragraph(&localizer.text_with(
+                    "report-thought-record",
+                    &[
+                        ("date", localizer.date(thought_record.date).into()),
+                        ("situation", thought_record.situation.as_str().into()),
+                        (
+                            "automatic-thought",
+                            thought_record.automatic_thought.as_str().into(),
+                        ),
+                        (
+                            "balanced-thought",
+                            thought_record.balanced_thought.as_str().into(),
+                        ),
+                        ("emotion", thought_record.emotion.as_str().into()),
+                        ("intensity", thought_record.emotion_intensity.into()),
+                        (
+                            "intensity-rerated",
+                            thought_record.emotion_intensity_rerated.into(),
+                        ),
+                    ],
+                ));
+            }
+        }
+
+        if !inquiries.is_empty() {
+            self.add_heading(&localizer.text("report-inquiries"));
+
+            for inquiry in inquiries {
+                let mut paragraph = localizer.text_with(
+                    "report-inquiry",
+                    &[
+                        ("date", localizer.date(inquiry.date_time.date()).into()),
+                        ("belief", inquiry.belief.as_str().into()),
+                    ],
+                );
+
+                if !inquiry.turnarounds.is_empty() {
+                    paragraph.push('\n');
+                    paragraph.push_str(&localizer.text_with(
+                        "report-turnarounds",
+                        &[("turnarounds", localizer.list(&inquiry.turnarounds).into())],
+                    ));
+                }
+
+                self.add_paragraph(&paragraph);
+            }
+        }
+    }
+
+    /// Adds a paragraph of body text, wrapped into lines.
+    fn add_paragraph(&mut self, paragraph: &str) {
+        for line in paragraph.lines() {
+            for wrapped_line in wrap(line, MAXIMUM_LINE_LENGTH) {
+                self.add_text(&wrapped_line, TEXT_FONT_SIZE, false);
+            }
+        }
+
+        self.add_space(1.5);
+    }
+
+    /// Adds vertical space.
+    fn add_space(&mut self, height: f64) {
+        self.y += height;
+    }
+
+    /// Adds the summary statistics.
+    fn add_summary(&mut self, application_data: &ApplicationData, localizer: &Localizer) {
+        let statistics = MoodStatistics::new(
+            &application_data.mood_per_date,
+            self.first_date,
+            self.last_date,
+        );
+
+        self.add_heading(&localizer.text("report-summary"));
+
+        let mut lines = vec![localizer.text_with(
+            "report-days-with-mood-values",
+            &[
+                ("count", statistics.number_of_days_with_mood_values.into()),
+                ("days", statistics.number_of_days.into()),
+            ],
+        )];
+
+        if let (Some(average), Some(minimum), Some(maximum)) = (
+            statistics.average_mood_value,
+            statistics.minimum_mood_value,
+            statistics.maximum_mood_value,
+        ) {
+            lines.push(localizer.text_with(
+                "report-mood-values",
+                &[
+                    ("average", format!("{:.1}", average).into()),
+                    ("minimum", minimum.into()),
+                    ("maximum", maximum.into()),
+                ],
+            ));
+        }
+
+        for line in lines {
+            self.add_text(&line, TEXT_FONT_SIZE, false);
+        }
+    }
+
+    /// Adds a line of text.
+    fn add_text(&mut self, text: &str, font_size: f64, is_bold: bool) {
+        let height = mm_from_pt(font_size) * 1.4;
+
+        self.ensure_space(height);
+
+        self.push(ReportElement::Text {
+            x: PAGE_MARGIN,
+            y: self.y,
+            font_size,
+            is_bold,
+            text: text.to_string(),
+        });
+
+        self.y += height;
+    }
+
+    /// Starts a new page, if the given height does not fit on the current page.
+    fn ensure_space(&mut self, height: f64) {
+        if self.y + height > PAGE_HEIGHT - PAGE_MARGIN {
+            self.pages.push(vec![]);
+            self.y = PAGE_MARGIN;
+        }
+    }
+
+    /// Adds an element to the current page.
+    fn push(&mut self, element: ReportElement) {
+        if let Some(page) = self.pages.last_mut() {
+            page.push(element);
+        }
+    }
+
+    /// Writes the report as PDF. The dates and the ID of the document are derived from the date
+    /// range, so that the same report yields the same document.
+    pub(crate) fn write_pdf(&self, path: &Path) -> Result<(), ApplicationError> {
+        let (document, first_page_index, first_layer_index) = PdfDocument::new(
+            &self.title,
+            Mm(PAGE_WIDTH as f32),
+            Mm(PAGE_HEIGHT as f32),
+            "Report",
+        );
+
+        let date_time = self
+            .last_date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|date_time| {
+                OffsetDateTime::from_unix_timestamp(date_time.and_utc().timestamp()).ok()
+            })
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+        let document = document
+            .with_creation_date(date_time)
+            .with_document_id(format!(
+                "freundchen-{}-{}",
+                self.first_date.format("%Y%m%d"),
+                self.last_date.format("%Y%m%d")
+            ))
+            .with_metadata_date(date_time)
+            .with_mod_date(date_time)
+            .with_producer("freundchen");
+
+        let font = document.add_builtin_font(BuiltinFont::Helvetica)?;
+        let bold_font = document.add_builtin_font(BuiltinFont::HelveticaBold)?;
+
+        let pdf_color = |theme_color: &ThemeColor| {
+            Color::Rgb(Rgb::new(
+                theme_color.0 as f32 / 255.0,
+                theme_color.1 as f32 / 255.0,
+                theme_color.2 as f32 / 255.0,
+                None,
+            ))
+        };
+
+        // PDF coordinates start at the bottom left corner.
+        let point = |x: f64, y: f64| Point::new(Mm(x as f32), Mm((PAGE_HEIGHT - y) as f32));
+
+        for (page_index, elements) in self.pages.iter().enumerate() {
+            let (pdf_page_index, pdf_layer_index) = if page_index == 0 {
+                (first_page_index, first_layer_index)
+            } else {
+                document.add_page(Mm(PAGE_WIDTH as f32), Mm(PAGE_HEIGHT as f32), "Report")
+            };
+
+            let layer = document.get_page(pdf_page_index).get_layer(pdf_layer_index);
+
+            for element in elements {
+                match element {
+                    ReportElement::Line {
+                        x0,
+                        y0,
+                        x1,
+                        y1,
+                        color,
+                    } => {
+                        layer.set_outline_color(pdf_color(color));
+                        layer.set_outline_thickness(0.5);
+                        layer.add_line(Line {
+                            points: vec![(point(*x0, *y0), false), (point(*x1, *y1), false)],
+                            is_closed: false,
+                        });
+                    }
+                    ReportElement::Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                        color,
+                    } => {
+                        layer.set_fill_color(pdf_color(color));
+                        layer.add_rect(Rect::new(
+                            Mm(*x as f32),
+                            Mm((PAGE_HEIGHT - y - height) as f32),
+                            Mm((x + width) as f32),
+                            Mm((PAGE_HEIGHT - y) as f32),
+                        ));
+                    }
+                    ReportElement::Text {
+                        x,
+                        y,
+                        font_size,
+                        is_bold,
+                        text,
+                    } => {
+                        layer.set_fill_color(pdf_color(&TEXT_COLOR));
+                        layer.use_text(
+                            text,
+                            *font_size as f32,
+                            Mm(*x as f32),
+                            // Text is positioned at its baseline.
+                            Mm((PAGE_HEIGHT - y - mm_from_pt(*font_size)) as f32),
+                            if *is_bold { &bold_font } else { &font },
+                        );
+                    }
+                }
+            }
+        }
+
+        document.save(&mut BufWriter::new(File::create(path)?))?;
+
+        Ok(())
+    }
+}
+
+// =================================================================================================
+
+/// Parses a date like `2026-09-30`.
+fn parse_date(text: &str) -> Result<NaiveDate, ApplicationError> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map_err(|_| {
+        ApplicationError::Arguments(format!("\"{}\" is not a date like \"2026-09-30\"", text))
+    })
+}
+
+/// Runs the report command with the given arguments, see `REPORT_USAGE`. The report covers the
+/// current month by default.
+pub(crate) fn report_command(
+    arguments: &[String],
+    application_data: &ApplicationData,
+    localizer: &Localizer,
+    theme: &Theme,
+) -> Result<(), ApplicationError> {
+    let today = Local::now().naive_local().date();
+    let mut first_date = today.with_day(1).unwrap_or(today);
+    let mut last_date = first_date
+        .with_day(number_of_days_in_month(first_date) as u32)
+        .unwrap_or(today);
+    let mut output_path = None;
+
+    let mut arguments = arguments.iter();
+
+    while let Some(argument) = arguments.next() {
+        let mut option_value = || {
+            arguments
+                .next()
+                .ok_or_else(|| ApplicationError::Arguments(format!("{} needs a value", argument)))
+        };
+
+        match argument.as_str() {
+            "--from" => first_date = parse_date(option_value()?)?,
+            "--to" => last_date = parse_date(option_value()?)?,
+            _ if output_path.is_none() && !argument.starts_with("--") => {
+                output_path = Some(Path::new(argument))
+            }
+            _ => {
+                return Err(ApplicationError::Arguments(format!(
+                    "Unexpected argument \"{}\", usage: {}",
+                    argument, REPORT_USAGE
+                )))
+            }
+        }
+    }
+
+    let output_path = output_path.ok_or_else(|| {
+        ApplicationError::Arguments(format!(
+            "The output file is missing, usage: {}",
+            REPORT_USAGE
+        ))
+    })?;
+
+    if last_date < first_date {
+        return Err(ApplicationError::Arguments(format!(
+            "The report ends on {} before it starts on {}",
+            last_date, first_date
+        )));
+    }
+
+    Report::new(application_data, first_date, last_date, localizer, theme)
+        .write_pdf(output_path)?;
+
+    println!("Wrote the report to \"{}\"", output_path.display());
+
+    Ok(())
+}
+
+/// Wraps the given text into lines of at most the given number of characters, breaking at
+/// whitespace where possible.
+fn wrap(text: &str, maximum_line_length: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let line_length = line.chars().count();
+        let word_length = word.chars().count();
+
+        if line_length > 0 && line_length + 1 + word_length > maximum_line_length {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        if !line.is_empty() {
+            line.push(' ');
+        }
+
+        line.push_str(word);
+
+        // Break words that are longer than a line.
+        while line.chars().count() > maximum_line_length {
+            let rest = line.chars().skip(maximum_line_length).collect();
+            line = line.chars().take(maximum_line_length).collect();
+            lines.push(std::mem::replace(&mut line, rest));
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
@@ -0,0 +1,281 @@
+//! Compares the layout of a report with the snapshot in `goldens/`. Run `./update_goldens.sh` to
+//! write the snapshot anew after an intended change, and check it before committing.
+
+use super::*;
+use freundchen_core::data::{Factor, Medication, Schedule, ThoughtRecord};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+
+/// The environment variable that makes the tests write the snapshots instead of comparing.
+const UPDATE_GOLDENS_VARIABLE: &str = "FREUNDCHEN_UPDATE_GOLDENS";
+
+/// Returns the date of the given day in September 2026.
+fn september(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 9, day).unwrap()
+}
+
+/// Returns a thought record of the given date with the given situation.
+fn thought_record(date: NaiveDate, situation: &str) -> ThoughtRecord {
+    ThoughtRecord {
+        date,
+        situation: situation.to_string(),
+        automatic_thought: "Nobody listens to me.".to_string(),
+        emotion: "Frustration".to_string(),
+        emotion_intensity: 70,
+        evidence_for: String::new(),
+        evidence_against: String::new(),
+        balanced_thought: "Some people listened.".to_string(),
+        emotion_intensity_rerated: 40,
+    }
+}
+
+/// Returns data with mood values, a yes-or-no factor, a medication and a thought record in
+/// September 2026.
+fn application_data() -> ApplicationData {
+    let mut application_data = ApplicationData::new();
+
+    application_data.set_mood_values(Track::Mood, september(1), [3].into());
+    application_data.set_mood_values(Track::Mood, september(2), [5, 7].into());
+    application_data.set_mood_values(Track::Mood, september(30), [10].into());
+
+    let mut walk = Factor::new("Walk", FactorKind::Boolean).unwrap();
+    walk.set_value(september(1), Some(FactorValue::Boolean(true)));
+    walk.set_value(september(2), Some(FactorValue::Boolean(false)));
+    walk.set_value(september(3), Some(FactorValue::Boolean(true)));
+    application_data.factors.push(walk);
+
+    let mut medication =
+        Medication::new("Sertraline", "50 mg", Schedule::Daily, september(1)).unwrap();
+    medication.set_intake(september(1), true);
+    medication.set_intake(september(2), true);
+    application_data.medications.push(medication);
+
+    application_data.thought_records.push(thought_record(
+        september(4),
+        "Ünterbrochen beim Erzählen, während alle über das Wochenende sprachen – schon wieder.",
+    ));
+
+    application_data
+}
+
+/// Returns the report of September 2026 in English.
+fn report(application_data: &ApplicationData) -> Report {
+    Report::new(
+        application_data,
+        september(1),
+        september(30),
+        &Localizer::new("en"),
+        &Theme::default(),
+    )
+}
+
+/// Returns the texts of the report's pages.
+fn texts(report: &Report) -> Vec<String> {
+    report
+        .pages
+        .iter()
+        .flatten()
+        .filter_map(|element| match element {
+            ReportElement::Text { text, .. } => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Describes the report's pages line by line, with the coordinates rounded to hundredths of a
+/// millimetre.
+fn snapshot(report: &Report) -> String {
+    let mut snapshot = String::new();
+
+    for (page_index, elements) in report.pages.iter().enumerate() {
+        // Writing to a `String` does not fail.
+        let _ = writeln!(snapshot, "page {}", page_index + 1);
+
+        for element in elements {
+            let _ = match element {
+                ReportElement::Line {
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    color,
+                } => writeln!(
+                    snapshot,
+                    "  line {:.2} {:.2} {:.2} {:.2} {}",
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    String::from(*color)
+                ),
+                ReportElement::Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => writeln!(
+                    snapshot,
+                    "  rectangle {:.2} {:.2} {:.2} {:.2} {}",
+                    x,
+                    y,
+                    width,
+                    height,
+                    String::from(*color)
+                ),
+                ReportElement::Text {
+                    x,
+                    y,
+                    font_size,
+                    is_bold,
+                    text,
+                } => writeln!(
+                    snapshot,
+                    "  text {:.2} {:.2} {}{} {:?}",
+                    x,
+                    y,
+                    font_size,
+                    if *is_bold { " bold" } else { "" },
+                    text
+                ),
+            };
+        }
+    }
+
+    snapshot
+}
+
+// =================================================================================================
+
+#[test]
+fn golden_report_pages() {
+    let snapshot = snapshot(&report(&application_data()));
+    let golden_path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/report/goldens/report_pages.txt");
+
+    // Write the snapshot instead of comparing with it.
+    if env::var_os(UPDATE_GOLDENS_VARIABLE).is_some() {
+        fs::create_dir_all(golden_path.parent().unwrap()).expect("the directory should be created");
+        fs::write(&golden_path, snapshot).expect("the snapshot should be written");
+        return;
+    }
+
+    let golden = fs::read_to_string(&golden_path).unwrap_or_else(|error| {
+        panic!(
+            "Could not read \"{}\", run `./update_goldens.sh` to create it: {}",
+            golden_path.display(),
+            error
+        )
+    });
+
+    if let Some((line_index, (line, golden_line))) = snapshot
+        .lines()
+        .zip(golden.lines())
+        .enumerate()
+        .find(|(_, (line, golden_line))| line != golden_line)
+    {
+        panic!(
+            "Line {} differs from \"{}\":\n  expected: {}\n  actual:   {}",
+            line_index + 1,
+            golden_path.display(),
+            golden_line,
+            line
+        );
+    }
+
+    assert_eq!(
+        snapshot.lines().count(),
+        golden.lines().count(),
+        "\"{}\" has a different number of lines",
+        golden_path.display()
+    );
+}
+
+#[test]
+fn the_factors_count_the_yes_days_and_the_medications_the_scheduled_intakes() {
+    let texts = texts(&report(&application_data()));
+
+    assert!(texts.contains(&"Factors".to_string()));
+    assert!(texts.contains(&"Walk: yes on 2 of 3 recorded days".to_string()));
+    assert!(texts.contains(&"Medications".to_string()));
+    assert!(texts.contains(&"Sertraline: Taken on 2 of 30 scheduled days (7 %)".to_string()));
+}
+
+#[test]
+fn the_factors_and_medications_are_left_out_without_values() {
+    let mut application_data = application_data();
+    application_data.factors.clear();
+    application_data.medications.clear();
+
+    let texts = texts(&report(&application_data));
+
+    assert!(!texts.contains(&"Factors".to_string()));
+    assert!(!texts.contains(&"Medications".to_string()));
+}
+
+#[test]
+fn a_long_report_continues_on_further_pages() {
+    let mut application_data = application_data();
+
+    for day in 1..=30 {
+        application_data.thought_records.push(thought_record(
+            september(day),
+            &"A long situation. ".repeat(10),
+        ));
+    }
+
+    let report = report(&application_data);
+
+    assert!(report.pages.len() > 1);
+
+    for element in report.pages.iter().flatten() {
+        let y = match element {
+            ReportElement::Line { y1, .. } => *y1,
+            ReportElement::Rectangle { y, height, .. } => y + height,
+            ReportElement::Text { y, font_size, .. } => y + mm_from_pt(*font_size),
+        };
+
+        assert!(
+            y <= PAGE_HEIGHT - PAGE_MARGIN,
+            "{:?} reaches into the bottom margin",
+            element
+        );
+    }
+}
+
+#[test]
+fn wrap_breaks_at_whitespace() {
+    assert_eq!(
+        wrap("the quick  brown fox", 10),
+        vec!["the quick".to_string(), "brown fox".to_string()]
+    );
+    assert_eq!(wrap("exactly 10", 10), vec!["exactly 10".to_string()]);
+    assert_eq!(wrap("", 10), vec![String::new()]);
+}
+
+#[test]
+fn wrap_breaks_words_longer_than_a_line() {
+    assert_eq!(
+        wrap("a verylongword", 5),
+        vec![
+            "a".to_string(),
+            "veryl".to_string(),
+            "ongwo".to_string(),
+            "rd".to_string()
+        ]
+    );
+}
+
+#[test]
+fn wrap_counts_characters_of_multibyte_text() {
+    assert_eq!(
+        wrap("äöü ñ 日本語テキスト", 4),
+        vec![
+            "äöü".to_string(),
+            "ñ".to_string(),
+            "日本語テ".to_string(),
+            "キスト".to_string()
+        ]
+    );
+}
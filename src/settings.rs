@@ -0,0 +1,205 @@
+use crate::theme::Theme;
+use freundchen_core::paths::{default_data_path, settings_path, APPLICATION_DIRECTORY_NAME};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use tracing::level_filters::LevelFilter;
+use unic_langid::LanguageIdentifier;
+
+/// The allowed font sizes.
+pub(crate) const FONT_SIZE_RANGE: RangeInclusive<f64> = 6.0..=72.0;
+
+/// The allowed window widths and heights.
+pub(crate) const WINDOW_SIZE_RANGE: RangeInclusive<f64> = 200.0..=10000.0;
+
+/// An error in the settings.
+#[derive(Debug)]
+pub enum SettingsError {
+    /// The data path is not a file path.
+    InvalidDataPath(PathBuf),
+    /// The font size is not within `FONT_SIZE_RANGE`.
+    InvalidFontSize(f64),
+    /// The locale is not a valid language identifier.
+    InvalidLocale(String),
+    /// The log level is not one of `off`, `error`, `warn`, `info`, `debug` or `trace`.
+    InvalidLogLevel(String),
+    /// The theme file could not be read or parsed.
+    InvalidTheme(PathBuf, String),
+    /// The window size is not within `WINDOW_SIZE_RANGE`.
+    InvalidWindowSize(f64, f64),
+    /// The settings file could not be parsed.
+    Parse(toml::de::Error),
+    /// The settings could not be serialized.
+    Serialize(toml::ser::Error),
+    /// There is neither a built-in nor a user-defined theme with the name.
+    UnknownTheme(String),
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::InvalidDataPath(path) => {
+                write!(f, "\"{}\" is not a file path", path.display())
+            }
+            SettingsError::InvalidFontSize(font_size) => write!(
+                f,
+                "The font size {} is not between {} and {}",
+                font_size,
+                FONT_SIZE_RANGE.start(),
+                FONT_SIZE_RANGE.end()
+            ),
+            SettingsError::InvalidLocale(locale) => write!(f, "\"{}\" is not a locale", locale),
+            SettingsError::InvalidLogLevel(log_level) => {
+                write!(f, "\"{}\" is not a log level", log_level)
+            }
+            SettingsError::InvalidTheme(path, reason) => {
+                write!(
+                    f,
+                    "Could not load the theme \"{}\": {}",
+                    path.display(),
+                    reason
+                )
+            }
+            SettingsError::InvalidWindowSize(width, height) => write!(
+                f,
+                "The window size {}×{} is not between {} and {}",
+                width,
+                height,
+                WINDOW_SIZE_RANGE.start(),
+                WINDOW_SIZE_RANGE.end()
+            ),
+            SettingsError::Parse(error) => write!(f, "{}", error),
+            SettingsError::Serialize(error) => write!(f, "{}", error),
+            SettingsError::UnknownTheme(name) => write!(f, "There is no theme \"{}\"", name),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+// =================================================================================================
+
+/// Returns the directory of the log files, within the state directory if the platform has one.
+pub(crate) fn log_directory() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|state_directory| state_directory.join(APPLICATION_DIRECTORY_NAME))
+        .unwrap_or_default()
+        .join("logs")
+}
+
+// =================================================================================================
+
+/// The settings, persisted separately from the application data.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub(crate) struct Settings {
+    /// The path of the application data file. `None` means the default path.
+    pub(crate) data_path: Option<PathBuf>,
+    pub(crate) greeting_font_size: f64,
+    /// The locale, like `de-DE`. `None` means the locale is taken from the environment.
+    pub(crate) locale: Option<String>,
+    /// The level of the messages written to the log, unless `RUST_LOG` is set.
+    pub(crate) log_level: String,
+    /// How the user would like to be addressed.
+    pub(crate) preferred_name: Option<String>,
+    /// The name of a built-in or user-defined theme.
+    pub(crate) theme: String,
+    pub(crate) window_height: f64,
+    pub(crate) window_width: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            data_path: None,
+            greeting_font_size: 16.0,
+            locale: None,
+            log_level: "info".to_string(),
+            preferred_name: None,
+            theme: "dark".to_string(),
+            window_height: 600.0,
+            window_width: 800.0,
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the path of the application data file.
+    pub(crate) fn data_path(&self) -> PathBuf {
+        self.data_path.clone().unwrap_or_else(default_data_path)
+    }
+
+    /// Returns the path of the settings file.
+    pub(crate) fn path() -> PathBuf {
+        settings_path()
+    }
+
+    /// Loads the settings from the given path. If the file does not exist, the default settings
+    /// are returned.
+    pub(crate) fn load(path: &Path) -> Result<Self, crate::ApplicationError> {
+        // The settings file does not exist.
+        if !path.exists() {
+            return Ok(Settings::default());
+        }
+
+        let settings: Settings =
+            toml::from_str(&fs::read_to_string(path)?).map_err(SettingsError::Parse)?;
+
+        settings.validate()?;
+
+        Ok(settings)
+    }
+
+    /// Saves the settings to the given path.
+    pub(crate) fn save(&self, path: &Path) -> Result<(), crate::ApplicationError> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        fs::write(
+            path,
+            toml::to_string_pretty(self).map_err(SettingsError::Serialize)?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Checks whether the settings are valid.
+    pub(crate) fn validate(&self) -> Result<(), SettingsError> {
+        if let Some(data_path) = &self.data_path {
+            if data_path.as_os_str().is_empty() || data_path.is_dir() {
+                return Err(SettingsError::InvalidDataPath(data_path.clone()));
+            }
+        }
+
+        if !FONT_SIZE_RANGE.contains(&self.greeting_font_size) {
+            return Err(SettingsError::InvalidFontSize(self.greeting_font_size));
+        }
+
+        if let Some(locale) = &self.locale {
+            if locale.parse::<LanguageIdentifier>().is_err() {
+                return Err(SettingsError::InvalidLocale(locale.clone()));
+            }
+        }
+
+        if self.log_level.parse::<LevelFilter>().is_err() {
+            return Err(SettingsError::InvalidLogLevel(self.log_level.clone()));
+        }
+
+        Theme::load(&self.theme)?;
+
+        if !WINDOW_SIZE_RANGE.contains(&self.window_width)
+            || !WINDOW_SIZE_RANGE.contains(&self.window_height)
+        {
+            return Err(SettingsError::InvalidWindowSize(
+                self.window_width,
+                self.window_height,
+            ));
+        }
+
+        Ok(())
+    }
+}
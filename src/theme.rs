@@ -0,0 +1,307 @@
+use crate::settings::SettingsError;
+use crate::widget::{MoodWidgetStyle, SleepWidgetStyle};
+use freundchen_core::data::{Dimension, MAXIMUM_SLEEP_QUALITY};
+use freundchen_core::mood::NUMBER_OF_MOOD_VALUES_PER_DAY;
+use freundchen_core::paths::config_directory;
+use guiver::{Color, Command, Font, PaintBrush, WidgetId};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// The names of the built-in themes.
+pub(crate) const BUILT_IN_THEME_NAMES: [&str; 4] = ["dark", "light", "viridis", "cividis"];
+
+/// The red-to-amber mood palette, from the darkest to the brightest mood value.
+const RED_TO_AMBER_MOOD_COLORS: [ThemeColor; NUMBER_OF_MOOD_VALUES_PER_DAY as usize] = [
+    ThemeColor(55, 6, 23),
+    ThemeColor(86, 1, 29),
+    ThemeColor(118, 0, 32),
+    ThemeColor(149, 0, 30),
+    ThemeColor(180, 0, 22),
+    ThemeColor(208, 0, 0),
+    ThemeColor(216, 66, 0),
+    ThemeColor(221, 101, 0),
+    ThemeColor(224, 131, 0),
+    ThemeColor(225, 159, 0),
+    ThemeColor(255, 186, 8),
+];
+
+/// The perceptually uniform, colour-blind-safe viridis palette.
+const VIRIDIS_MOOD_COLORS: [ThemeColor; NUMBER_OF_MOOD_VALUES_PER_DAY as usize] = [
+    ThemeColor(68, 1, 84),
+    ThemeColor(72, 36, 117),
+    ThemeColor(65, 68, 135),
+    ThemeColor(53, 95, 141),
+    ThemeColor(42, 120, 142),
+    ThemeColor(33, 145, 140),
+    ThemeColor(34, 168, 132),
+    ThemeColor(68, 191, 112),
+    ThemeColor(122, 209, 81),
+    ThemeColor(189, 223, 38),
+    ThemeColor(253, 231, 37),
+];
+
+/// The cividis palette, optimized for colour-vision deficiencies.
+const CIVIDIS_MOOD_COLORS: [ThemeColor; NUMBER_OF_MOOD_VALUES_PER_DAY as usize] = [
+    ThemeColor(0, 34, 78),
+    ThemeColor(15, 48, 110),
+    ThemeColor(53, 69, 108),
+    ThemeColor(79, 87, 108),
+    ThemeColor(102, 105, 112),
+    ThemeColor(124, 123, 120),
+    ThemeColor(148, 142, 119),
+    ThemeColor(172, 162, 112),
+    ThemeColor(198, 184, 102),
+    ThemeColor(226, 206, 85),
+    ThemeColor(254, 232, 56),
+];
+
+/// An RGB colour, written as `"#rrggbb"` in theme files.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub(crate) struct ThemeColor(pub(crate) u8, pub(crate) u8, pub(crate) u8);
+
+impl ThemeColor {
+    pub(crate) fn color(&self) -> Color {
+        Color::rgb8(self.0, self.1, self.2)
+    }
+}
+
+impl From<ThemeColor> for String {
+    fn from(theme_color: ThemeColor) -> Self {
+        format!(
+            "#{:02x}{:02x}{:02x}",
+            theme_color.0, theme_color.1, theme_color.2
+        )
+    }
+}
+
+impl TryFrom<String> for ThemeColor {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let invalid_color = || format!("\"{}\" is not a colour like \"#ff8000\"", text);
+
+        let hex_digits = text.strip_prefix('#').ok_or_else(invalid_color)?;
+
+        if hex_digits.len() != 6 || !hex_digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid_color());
+        }
+
+        let component = |index: usize| u8::from_str_radix(&hex_digits[index..index + 2], 16);
+
+        Ok(ThemeColor(
+            component(0).map_err(|_| invalid_color())?,
+            component(2).map_err(|_| invalid_color())?,
+            component(4).map_err(|_| invalid_color())?,
+        ))
+    }
+}
+
+/// The colour of the buttons that remove data in themes that do not set one.
+fn default_destructive_button_color() -> ThemeColor {
+    ThemeColor(170, 20, 20)
+}
+
+// =================================================================================================
+
+/// The colours of the user interface.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct Theme {
+    pub(crate) background_color: ThemeColor,
+    pub(crate) button_color: ThemeColor,
+    /// The colour of the buttons that remove data, like the clear button.
+    #[serde(default = "default_destructive_button_color")]
+    pub(crate) destructive_button_color: ThemeColor,
+    pub(crate) grid_color: ThemeColor,
+    /// The colours per mood value, from the darkest to the brightest.
+    pub(crate) mood_colors: [ThemeColor; NUMBER_OF_MOOD_VALUES_PER_DAY as usize],
+    pub(crate) text_color: ThemeColor,
+}
+
+impl Default for Theme {
+    /// Returns the built-in dark theme.
+    fn default() -> Self {
+        Theme {
+            background_color: ThemeColor(41, 41, 41),
+            button_color: ThemeColor(80, 80, 80),
+            destructive_button_color: default_destructive_button_color(),
+            grid_color: ThemeColor(100, 100, 100),
+            mood_colors: RED_TO_AMBER_MOOD_COLORS,
+            text_color: ThemeColor(255, 255, 255),
+        }
+    }
+}
+
+impl Theme {
+    /// Returns the built-in theme with the given name.
+    pub(crate) fn built_in(name: &str) -> Option<Self> {
+        let dark = Theme::default();
+
+        let light = Theme {
+            background_color: ThemeColor(250, 250, 250),
+            button_color: ThemeColor(220, 220, 220),
+            destructive_button_color: ThemeColor(240, 130, 130),
+            grid_color: ThemeColor(160, 160, 160),
+            mood_colors: RED_TO_AMBER_MOOD_COLORS,
+            text_color: ThemeColor(30, 30, 30),
+        };
+
+        match name {
+            "cividis" => Some(Theme {
+                mood_colors: CIVIDIS_MOOD_COLORS,
+                ..light
+            }),
+            "dark" => Some(dark),
+            "light" => Some(light),
+            "viridis" => Some(Theme {
+                mood_colors: VIRIDIS_MOOD_COLORS,
+                ..dark
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the directory of the user-defined themes.
+    pub(crate) fn directory() -> PathBuf {
+        config_directory().join("themes")
+    }
+
+    /// Loads the theme with the given name, either a built-in one or a user-defined one from
+    /// `<name>.toml` in the themes directory.
+    pub(crate) fn load(name: &str) -> Result<Self, SettingsError> {
+        if let Some(theme) = Theme::built_in(name) {
+            return Ok(theme);
+        }
+
+        let path = Theme::directory().join(format!("{}.toml", name));
+
+        // There is no such user-defined theme.
+        if name.is_empty() || !path.is_file() {
+            return Err(SettingsError::UnknownTheme(name.to_string()));
+        }
+
+        let file_content = fs::read_to_string(&path)
+            .map_err(|error| SettingsError::InvalidTheme(path.clone(), error.to_string()))?;
+
+        toml::from_str(&file_content)
+            .map_err(|error| SettingsError::InvalidTheme(path, error.to_string()))
+    }
+
+    /// Returns the commands to style the given buttons.
+    pub(crate) fn button_commands(&self, button_widget_ids: &[WidgetId]) -> Vec<Command<()>> {
+        button_widget_ids
+            .iter()
+            .flat_map(|button_widget_id| {
+                [
+                    Command::SetFill(
+                        *button_widget_id,
+                        Some(PaintBrush::Color(self.button_color.color())),
+                    ),
+                    Command::SetFont(*button_widget_id, self.font()),
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the commands to style the given buttons that remove data.
+    pub(crate) fn destructive_button_commands(
+        &self,
+        button_widget_ids: &[WidgetId],
+    ) -> Vec<Command<()>> {
+        button_widget_ids
+            .iter()
+            .flat_map(|button_widget_id| {
+                [
+                    Command::SetFill(
+                        *button_widget_id,
+                        Some(PaintBrush::Color(self.destructive_button_color.color())),
+                    ),
+                    Command::SetFont(*button_widget_id, self.font()),
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the default font in the theme's text colour.
+    pub(crate) fn font(&self) -> Font {
+        Font {
+            font_color: self.text_color.color(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the style of the mood widget.
+    pub(crate) fn mood_widget_style(&self) -> MoodWidgetStyle {
+        MoodWidgetStyle {
+            fills_per_mood_value_index: self
+                .mood_colors
+                .map(|mood_color| PaintBrush::Color(mood_color.color())),
+            grid_color: self.grid_color.color(),
+            text_color: self.text_color.color(),
+        }
+    }
+
+    /// Returns the style of the mood widget showing the given dimension. Its values get the
+    /// dimension's colours, or evenly spread mood colours if it has none.
+    pub(crate) fn dimension_mood_widget_style(&self, dimension: &Dimension) -> MoodWidgetStyle {
+        let colors: Vec<ThemeColor> = dimension
+            .colors
+            .iter()
+            .filter_map(|color| ThemeColor::try_from(color.clone()).ok())
+            .collect();
+
+        let highest_value = dimension.number_of_values.saturating_sub(1) as usize;
+
+        MoodWidgetStyle {
+            fills_per_mood_value_index: std::array::from_fn(|value| {
+                let color = match colors.get(value.min(colors.len().saturating_sub(1))) {
+                    Some(color) => *color,
+                    None => self.spread_mood_color(value, highest_value),
+                };
+
+                PaintBrush::Color(color.color())
+            }),
+            ..self.mood_widget_style()
+        }
+    }
+
+    /// Returns the style of the sleep widget. The qualities get evenly spread mood colours.
+    pub(crate) fn sleep_widget_style(&self) -> SleepWidgetStyle {
+        SleepWidgetStyle {
+            fills_per_quality_index: std::array::from_fn(|quality_index| {
+                PaintBrush::Color(
+                    self.spread_mood_color(quality_index, MAXIMUM_SLEEP_QUALITY as usize - 1)
+                        .color(),
+                )
+            }),
+            grid_color: self.grid_color.color(),
+        }
+    }
+
+    /// Returns the mood colour nearest to the given value of a scale from 0 to the highest value,
+    /// spreading the mood colours over the scale.
+    fn spread_mood_color(&self, value: usize, highest_value: usize) -> ThemeColor {
+        let highest_value = highest_value.max(1);
+        let highest_mood_color_index = self.mood_colors.len() - 1;
+
+        self.mood_colors[((value * highest_mood_color_index + highest_value / 2) / highest_value)
+            .min(highest_mood_color_index)]
+    }
+
+    /// Returns the commands to style the given texts.
+    pub(crate) fn text_commands(&self, text_widget_ids: &[WidgetId]) -> Vec<Command<()>> {
+        text_widget_ids
+            .iter()
+            .map(|text_widget_id| Command::SetFont(*text_widget_id, self.font()))
+            .collect()
+    }
+
+    /// Returns the font for titles.
+    pub(crate) fn title_font(&self) -> Font {
+        Font {
+            font_size: 16.0,
+            ..self.font()
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::ApplicationCommand;
+use crate::ApplicationError;
+use chrono::NaiveDate;
+use freundchen_core::data::{Factor, FactorKind, FactorValue};
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The width of the numbers' text inputs.
+const TEXT_INPUT_WIDTH: f64 = 80.0;
+
+/// How a factor's value is entered.
+enum FactorInput {
+    /// A button cycling through no value and the given values, e.g. yes and no.
+    Button {
+        button: WidgetId,
+        values: Vec<FactorValue>,
+    },
+    /// A text input for a number and a button to set it.
+    Number {
+        set_button: WidgetId,
+        text: String,
+        text_input: WidgetId,
+    },
+}
+
+/// The widgets of a factor.
+struct FactorWidgets {
+    /// The factor's definition, to parse its values.
+    factor: Factor,
+    input: FactorInput,
+    /// The factor's value of the shown day.
+    value: Option<FactorValue>,
+}
+
+// =================================================================================================
+
+/// A panel next to the mood widget to edit the factors of the selected days, or of today if no
+/// days are selected. It is hidden while there are no factors.
+pub(crate) struct FactorPanel {
+    column: WidgetId,
+    /// The widgets of each factor, in the order of `ApplicationData::factors`.
+    factor_widgets: Vec<FactorWidgets>,
+    localizer: Rc<Localizer>,
+    /// The column of the factors' rows.
+    rows_column: WidgetId,
+    title_text: WidgetId,
+}
+
+impl FactorPanel {
+    /// Creates the panel's widgets, without factors.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text("");
+        let rows_column = widget_manager.new_column();
+
+        let mut commands = theme.text_commands(&[title_text]);
+
+        for child_widget_id in [title_text, rows_column] {
+            commands.push(Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id,
+            });
+        }
+
+        commands.push(Command::SetIsHidden(column, true));
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(FactorPanel {
+            column,
+            factor_widgets: vec![],
+            localizer,
+            rows_column,
+            title_text,
+        })
+    }
+
+    /// Translates the clicks on the panel's buttons into commands. A number that cannot be parsed
+    /// is an error.
+    pub(crate) fn handle_event(
+        &mut self,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            for (factor_index, factor_widgets) in self.factor_widgets.iter_mut().enumerate() {
+                match (&mut factor_widgets.input, widget_event) {
+                    // A factor's button was clicked, set its next value and after the last one no
+                    // value.
+                    (FactorInput::Button { button, values }, WidgetEvent::Clicked(widget_id))
+                        if widget_id == button =>
+                    {
+                        let value = match &factor_widgets.value {
+                            Some(value) => values
+                                .iter()
+                                .skip_while(|other_value| *other_value != value)
+                                .nth(1)
+                                .cloned(),
+                            None => values.first().cloned(),
+                        };
+
+                        return Ok(Some(ApplicationCommand::SetFactorValue {
+                            factor_index,
+                            value,
+                        }));
+                    }
+                    // A number's set button was clicked, an empty number removes the value.
+                    (
+                        FactorInput::Number {
+                            set_button, text, ..
+                        },
+                        WidgetEvent::Clicked(widget_id),
+                    ) if widget_id == set_button => {
+                        let value = match text.trim() {
+                            "" => None,
+                            text => Some(factor_widgets.factor.parse_value(text)?),
+                        };
+
+                        return Ok(Some(ApplicationCommand::SetFactorValue {
+                            factor_index,
+                            value,
+                        }));
+                    }
+                    // A number was changed.
+                    (
+                        FactorInput::Number {
+                            text, text_input, ..
+                        },
+                        WidgetEvent::ValueChanged(widget_id, value),
+                    ) if widget_id == text_input => {
+                        if let Some(value) = value.downcast_ref::<String>() {
+                            *text = value.clone();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The ID of the panel's column.
+    pub(crate) fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+
+    /// Replaces the factors' rows with ones for the given factors. The panel is hidden if there
+    /// are none.
+    pub(crate) fn set_factors(
+        &mut self,
+        factors: &[Factor],
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let mut commands = vec![
+            Command::RemoveChildren {
+                parent_widget_id: self.rows_column,
+                destroy_child_widgets: true,
+            },
+            Command::SetIsHidden(self.column, factors.is_empty()),
+        ];
+
+        self.factor_widgets.clear();
+
+        for factor in factors {
+            let row = widget_manager.new_row();
+            let name_text = widget_manager.new_text(factor.name.clone());
+
+            let (input, input_widget_ids) = match &factor.kind {
+                FactorKind::Number => {
+                    let text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+                    let set_button =
+                        widget_manager.new_text_button(self.localizer.text("factor-set"));
+
+                    commands.extend(theme.button_commands(&[set_button]));
+
+                    (
+                        FactorInput::Number {
+                            set_button,
+                            text: String::new(),
+                            text_input,
+                        },
+                        vec![text_input, set_button],
+                    )
+                }
+                kind => {
+                    let button = widget_manager.new_text_button("");
+
+                    commands.extend(theme.button_commands(&[button]));
+
+                    let values = match kind {
+                        FactorKind::Choice { choices } => {
+                            choices.iter().cloned().map(FactorValue::Choice).collect()
+                        }
+                        _ => vec![FactorValue::Boolean(true), FactorValue::Boolean(false)],
+                    };
+
+                    (FactorInput::Button { button, values }, vec![button])
+                }
+            };
+
+            commands.extend(theme.text_commands(&[name_text]));
+            commands.push(Command::AddChild {
+                parent_widget_id: self.rows_column,
+                widget_placement: None,
+                child_widget_id: row,
+            });
+
+            for child_widget_id in std::iter::once(name_text).chain(input_widget_ids) {
+                commands.push(Command::AddChild {
+                    parent_widget_id: row,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+
+            self.factor_widgets.push(FactorWidgets {
+                factor: factor.definition(),
+                input,
+                value: None,
+            });
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+
+    /// Shows the given factors' values of the day with the given title, e.g. of the first selected
+    /// day.
+    pub(crate) fn show_values(
+        &mut self,
+        title: String,
+        factors: &[Factor],
+        date: NaiveDate,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let mut commands = vec![Command::SetValue(self.title_text, Box::new(title))];
+
+        for (factor_widgets, factor) in self.factor_widgets.iter_mut().zip(factors) {
+            factor_widgets.value = factor.values_per_date.get(&date).cloned();
+
+            match &mut factor_widgets.input {
+                FactorInput::Button { button, .. } => {
+                    let text = match &factor_widgets.value {
+                        Some(FactorValue::Boolean(true)) => self.localizer.text("factor-yes"),
+                        Some(FactorValue::Boolean(false)) => self.localizer.text("factor-no"),
+                        Some(value) => value.to_string(),
+                        None => self.localizer.text("factor-none"),
+                    };
+
+                    commands.push(Command::SetValue(*button, Box::new(text)));
+                }
+                FactorInput::Number {
+                    text, text_input, ..
+                } => {
+                    *text = factor_widgets
+                        .value
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default();
+
+                    commands.push(Command::SetValue(*text_input, Box::new(text.clone())));
+                }
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+}
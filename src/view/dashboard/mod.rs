@@ -1 +1,5 @@
+mod factor_panel;
+mod selection_bar;
 
+pub(crate) use factor_panel::FactorPanel;
+pub(crate) use selection_bar::SelectionBar;
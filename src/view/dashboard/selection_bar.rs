@@ -0,0 +1,168 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::ApplicationCommand;
+use crate::ApplicationError;
+use freundchen_core::data::BulkEdit;
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The width of the note's text input.
+const TEXT_INPUT_WIDTH: f64 = 300.0;
+
+/// A bar below the mood widget offering the edits of the selected days. It is hidden while no days
+/// are selected.
+pub(crate) struct SelectionBar {
+    add_note_button: WidgetId,
+    clear_button: WidgetId,
+    copy_button: WidgetId,
+    count_text: WidgetId,
+    deselect_button: WidgetId,
+    localizer: Rc<Localizer>,
+    note: String,
+    note_text_input: WidgetId,
+    paste_button: WidgetId,
+    row: WidgetId,
+}
+
+impl SelectionBar {
+    /// Creates the bar's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let row = widget_manager.new_row();
+        let count_text = widget_manager.new_text("");
+        let clear_button = widget_manager.new_text_button(localizer.text("selection-clear"));
+        let copy_button = widget_manager.new_text_button(localizer.text("selection-copy"));
+        let paste_button = widget_manager.new_text_button(localizer.text("selection-paste"));
+        let note_label = widget_manager.new_text(localizer.text("selection-note-label"));
+        let note_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let add_note_button = widget_manager.new_text_button(localizer.text("selection-add-note"));
+        let deselect_button = widget_manager.new_text_button(localizer.text("selection-deselect"));
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[count_text, note_label]);
+        commands.extend(theme.button_commands(&[
+            copy_button,
+            paste_button,
+            add_note_button,
+            deselect_button,
+        ]));
+        commands.extend(theme.destructive_button_commands(&[clear_button]));
+
+        for child_widget_id in [
+            count_text,
+            clear_button,
+            copy_button,
+            paste_button,
+            note_label,
+            note_text_input,
+            add_note_button,
+            deselect_button,
+        ] {
+            commands.push(Command::AddChild {
+                parent_widget_id: row,
+                widget_placement: None,
+                child_widget_id,
+            });
+        }
+
+        commands.push(Command::SetIsHidden(row, true));
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(SelectionBar {
+            add_note_button,
+            clear_button,
+            copy_button,
+            count_text,
+            deselect_button,
+            localizer,
+            note: String::new(),
+            note_text_input,
+            paste_button,
+            row,
+        })
+    }
+
+    /// Translates the clicks on the bar's buttons into commands.
+    pub(crate) fn handle_event(
+        &mut self,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The clear button was clicked.
+                    if *widget_id == self.clear_button {
+                        return Ok(Some(ApplicationCommand::EditSelectedDays(
+                            BulkEdit::ClearMoodValues,
+                        )));
+                    }
+                    // The copy button was clicked.
+                    else if *widget_id == self.copy_button {
+                        return Ok(Some(ApplicationCommand::CopySelectedDay));
+                    }
+                    // The paste button was clicked.
+                    else if *widget_id == self.paste_button {
+                        return Ok(Some(ApplicationCommand::PasteToSelectedDays));
+                    }
+                    // The add note button was clicked and there is a note.
+                    else if *widget_id == self.add_note_button && !self.note.trim().is_empty() {
+                        let note = std::mem::take(&mut self.note);
+
+                        widget_manager.send_command(Command::SetValue(
+                            self.note_text_input,
+                            Box::new(String::new()),
+                        ))?;
+
+                        return Ok(Some(ApplicationCommand::EditSelectedDays(
+                            BulkEdit::AddNote(note),
+                        )));
+                    }
+                    // The deselect button was clicked.
+                    else if *widget_id == self.deselect_button {
+                        return Ok(Some(ApplicationCommand::SelectDays(None)));
+                    }
+                }
+                // The note was changed.
+                WidgetEvent::ValueChanged(widget_id, value)
+                    if *widget_id == self.note_text_input =>
+                {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        self.note = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The ID of the bar's row.
+    pub(crate) fn main_widget_id(&self) -> &WidgetId {
+        &self.row
+    }
+
+    /// Shows the number of selected days, or hides the bar if no days are selected.
+    pub(crate) fn set_number_of_selected_days(
+        &mut self,
+        number_of_selected_days: usize,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let count_text = self.localizer.text_with(
+            "selection-days",
+            &[("count", number_of_selected_days.into())],
+        );
+
+        widget_manager.send_commands(vec![
+            Command::SetValue(self.count_text, Box::new(count_text)),
+            Command::SetIsHidden(self.row, number_of_selected_days == 0),
+        ])?;
+
+        Ok(())
+    }
+}
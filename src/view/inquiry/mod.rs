@@ -0,0 +1,432 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::Local;
+use freundchen_core::data::{ApplicationData, Inquiry, NUMBER_OF_QUESTIONS};
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The maximum number of past inquiries shown per belief.
+const MAXIMUM_NUMBER_OF_SHOWN_INQUIRIES: usize = 5;
+
+/// The width of the text input.
+const TEXT_INPUT_WIDTH: f64 = 600.0;
+
+/// The IDs of the prompt messages of the steps of an inquiry: the belief, the four questions and
+/// the turnarounds.
+const STEP_PROMPTS: [&str; 8] = [
+    "inquiry-prompt-belief",
+    "inquiry-prompt-question-1",
+    "inquiry-prompt-question-2",
+    "inquiry-prompt-question-3",
+    "inquiry-prompt-question-4",
+    "inquiry-prompt-turnaround-opposite",
+    "inquiry-prompt-turnaround-self",
+    "inquiry-prompt-turnaround-other",
+];
+
+/// The index of the step that asks for the belief.
+const STEP_INDEX_BELIEF: usize = 0;
+
+/// The index of the step that asks the first question.
+const STEP_INDEX_FIRST_QUESTION: usize = 1;
+
+/// The index of the step that asks for the first turnaround.
+const STEP_INDEX_FIRST_TURNAROUND: usize = STEP_INDEX_FIRST_QUESTION + NUMBER_OF_QUESTIONS;
+
+// =================================================================================================
+
+/// A view that guides through the four questions and the turnarounds of The Work.
+pub(crate) struct InquiryView {
+    back_button: WidgetId,
+    column: WidgetId,
+    history_texts: Vec<WidgetId>,
+    inquire_again_button: WidgetId,
+    localizer: Rc<Localizer>,
+    next_belief_button: WidgetId,
+    next_button: WidgetId,
+    previous_belief_button: WidgetId,
+    prompt_text: WidgetId,
+    save_button: WidgetId,
+    selected_belief_index: Option<usize>,
+    status_text: WidgetId,
+    step_index: usize,
+    step_text: WidgetId,
+    step_values: Vec<String>,
+    text_input: WidgetId,
+}
+
+impl InquiryView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text(localizer.text("inquiry-title"));
+        let step_text = widget_manager.new_text("");
+        let prompt_text = widget_manager.new_text("");
+        let text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let row_steps = widget_manager.new_row();
+        let back_button = widget_manager.new_text_button(localizer.text("back"));
+        let next_button = widget_manager.new_text_button(localizer.text("next"));
+        let save_button = widget_manager.new_text_button(localizer.text("save"));
+        let status_text = widget_manager.new_text("");
+        let beliefs_text = widget_manager.new_text(localizer.text("inquiry-beliefs"));
+        let row_beliefs = widget_manager.new_row();
+        let previous_belief_button =
+            widget_manager.new_text_button(localizer.text("inquiry-previous-belief"));
+        let next_belief_button =
+            widget_manager.new_text_button(localizer.text("inquiry-next-belief"));
+        let inquire_again_button = widget_manager.new_text_button(localizer.text("inquiry-again"));
+
+        let title_font = theme.title_font();
+
+        let mut commands = vec![];
+
+        for child_widget_id in [
+            title_text,
+            step_text,
+            prompt_text,
+            text_input,
+            row_steps,
+            status_text,
+            beliefs_text,
+            row_beliefs,
+        ] {
+            commands.push(Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id,
+            });
+        }
+
+        for child_widget_id in [back_button, next_button, save_button] {
+            commands.push(Command::AddChild {
+                parent_widget_id: row_steps,
+                widget_placement: None,
+                child_widget_id,
+            });
+        }
+
+        for child_widget_id in [
+            previous_belief_button,
+            next_belief_button,
+            inquire_again_button,
+        ] {
+            commands.push(Command::AddChild {
+                parent_widget_id: row_beliefs,
+                widget_placement: None,
+                child_widget_id,
+            });
+        }
+
+        // Add a text for the belief and a text per shown inquiry.
+        let mut history_texts = vec![];
+
+        for _ in 0..1 + MAXIMUM_NUMBER_OF_SHOWN_INQUIRIES {
+            let history_text = widget_manager.new_text("");
+
+            commands.push(Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: history_text,
+            });
+
+            history_texts.push(history_text);
+        }
+
+        // Apply the theme.
+        commands.extend(theme.text_commands(&[step_text, prompt_text, status_text]));
+        commands.extend(theme.text_commands(&history_texts));
+        commands.extend(theme.button_commands(&[
+            back_button,
+            next_button,
+            save_button,
+            previous_belief_button,
+            next_belief_button,
+            inquire_again_button,
+        ]));
+        commands.push(Command::SetFont(title_text, title_font.clone()));
+        commands.push(Command::SetFont(beliefs_text, title_font));
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(InquiryView {
+            back_button,
+            column,
+            history_texts,
+            inquire_again_button,
+            localizer,
+            next_belief_button,
+            next_button,
+            previous_belief_button,
+            prompt_text,
+            save_button,
+            selected_belief_index: None,
+            status_text,
+            step_index: STEP_INDEX_BELIEF,
+            step_text,
+            step_values: vec![String::new(); STEP_PROMPTS.len()],
+            text_input,
+        })
+    }
+
+    /// Creates an inquiry from the step values.
+    fn inquiry(&self) -> Inquiry {
+        let mut answers: [String; NUMBER_OF_QUESTIONS] = Default::default();
+
+        for (answer, step_value) in answers
+            .iter_mut()
+            .zip(&self.step_values[STEP_INDEX_FIRST_QUESTION..STEP_INDEX_FIRST_TURNAROUND])
+        {
+            *answer = step_value.trim().to_string();
+        }
+
+        Inquiry {
+            belief: self.step_values[STEP_INDEX_BELIEF].trim().to_string(),
+            date_time: Local::now().naive_local(),
+            answers,
+            turnarounds: self.step_values[STEP_INDEX_FIRST_TURNAROUND..]
+                .iter()
+                .map(|step_value| step_value.trim().to_string())
+                .filter(|turnaround| !turnaround.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Restarts the inquiry, optionally with the given belief.
+    fn restart(
+        &mut self,
+        belief: Option<String>,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        for step_value in self.step_values.iter_mut() {
+            step_value.clear();
+        }
+
+        self.step_index = match belief {
+            None => STEP_INDEX_BELIEF,
+            Some(belief) => {
+                self.step_values[STEP_INDEX_BELIEF] = belief;
+                STEP_INDEX_FIRST_QUESTION
+            }
+        };
+
+        self.show_step(widget_manager)
+    }
+
+    /// Sets the status text.
+    fn set_status(
+        &self,
+        status: impl Into<String>,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        widget_manager
+            .send_command(Command::SetValue(self.status_text, Box::new(status.into())))?;
+
+        Ok(())
+    }
+
+    /// Shows the current step.
+    fn show_step(&self, widget_manager: &mut WidgetManager<()>) -> Result<(), ApplicationError> {
+        let is_last_step = self.step_index + 1 == STEP_PROMPTS.len();
+
+        widget_manager.send_commands(vec![
+            Command::SetValue(
+                self.step_text,
+                Box::new(self.localizer.text_with(
+                    "inquiry-step",
+                    &[
+                        ("index", (self.step_index + 1).into()),
+                        ("count", STEP_PROMPTS.len().into()),
+                    ],
+                )),
+            ),
+            Command::SetValue(
+                self.prompt_text,
+                Box::new(self.localizer.text(STEP_PROMPTS[self.step_index])),
+            ),
+            Command::SetValue(
+                self.text_input,
+                Box::new(self.step_values[self.step_index].clone()),
+            ),
+            Command::SetIsDisabled(self.back_button, self.step_index == STEP_INDEX_BELIEF),
+            Command::SetIsDisabled(self.next_button, is_last_step),
+            Command::SetIsDisabled(self.save_button, !is_last_step),
+        ])?;
+
+        Ok(())
+    }
+
+    /// Shows how the answers about the selected belief changed over time.
+    fn update_history_texts(
+        &self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let beliefs = application_data.inquired_beliefs();
+        let mut lines = vec![];
+
+        match self
+            .selected_belief_index
+            .and_then(|index| Some((index, *beliefs.get(index)?)))
+        {
+            None => {
+                lines.push(self.localizer.text("inquiry-none"));
+            }
+            Some((index, belief)) => {
+                let inquiries: Vec<&Inquiry> = application_data
+                    .inquiries
+                    .iter()
+                    .filter(|inquiry| inquiry.is_about(belief))
+                    .collect();
+
+                lines.push(self.localizer.text_with(
+                    "inquiry-belief-header",
+                    &[
+                        ("belief", belief.into()),
+                        ("index", (index + 1).into()),
+                        ("count", beliefs.len().into()),
+                        ("inquiries", inquiries.len().into()),
+                    ],
+                ));
+
+                // Show the most recent inquiries first.
+                for inquiry in inquiries
+                    .iter()
+                    .rev()
+                    .take(MAXIMUM_NUMBER_OF_SHOWN_INQUIRIES)
+                {
+                    lines.push(self.localizer.text_with(
+                        "inquiry-history",
+                        &[
+                            (
+                                "date-time",
+                                self.localizer.date_time(inquiry.date_time).into(),
+                            ),
+                            ("answers", inquiry.answers.join(" | ").into()),
+                            ("turnarounds", inquiry.turnarounds.join(" | ").into()),
+                        ],
+                    ));
+                }
+            }
+        }
+
+        lines.resize(self.history_texts.len(), String::new());
+
+        widget_manager.send_commands(
+            self.history_texts
+                .iter()
+                .zip(lines)
+                .map(|(history_text, line)| Command::SetValue(*history_text, Box::new(line)))
+                .collect(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl View for InquiryView {
+    fn activate(
+        &mut self,
+        _application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        // Select the most recently added belief.
+        self.selected_belief_index = application_data.inquired_beliefs().len().checked_sub(1);
+
+        self.show_step(widget_manager)?;
+        self.update_history_texts(application_data, widget_manager)
+    }
+
+    fn handle_event(
+        &mut self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The back button was clicked.
+                    if *widget_id == self.back_button {
+                        self.step_index = self.step_index.saturating_sub(1);
+                        self.set_status("", widget_manager)?;
+                        self.show_step(widget_manager)?;
+                    }
+                    // The next button was clicked.
+                    else if *widget_id == self.next_button {
+                        // A belief is required.
+                        if self.step_values[STEP_INDEX_BELIEF].trim().is_empty() {
+                            self.set_status(
+                                self.localizer.text("inquiry-belief-missing"),
+                                widget_manager,
+                            )?;
+                            continue;
+                        }
+
+                        self.step_index = (self.step_index + 1).min(STEP_PROMPTS.len() - 1);
+                        self.set_status("", widget_manager)?;
+                        self.show_step(widget_manager)?;
+                    }
+                    // The save button was clicked.
+                    else if *widget_id == self.save_button {
+                        let inquiry = self.inquiry();
+
+                        self.restart(None, widget_manager)?;
+                        self.set_status(self.localizer.text("saved"), widget_manager)?;
+
+                        return Ok(Some(ApplicationCommand::AddInquiry(inquiry)));
+                    }
+                    // The previous belief button was clicked.
+                    else if *widget_id == self.previous_belief_button {
+                        if let Some(index) = self.selected_belief_index {
+                            self.selected_belief_index = Some(index.saturating_sub(1));
+                        }
+
+                        self.update_history_texts(application_data, widget_manager)?;
+                    }
+                    // The next belief button was clicked.
+                    else if *widget_id == self.next_belief_button {
+                        if let Some(index) = self.selected_belief_index {
+                            self.selected_belief_index = Some(
+                                (index + 1).min(application_data.inquired_beliefs().len() - 1),
+                            );
+                        }
+
+                        self.update_history_texts(application_data, widget_manager)?;
+                    }
+                    // The inquire again button was clicked.
+                    else if *widget_id == self.inquire_again_button {
+                        let belief = self.selected_belief_index.and_then(|index| {
+                            Some(application_data.inquired_beliefs().get(index)?.to_string())
+                        });
+
+                        // There is a selected belief.
+                        if belief.is_some() {
+                            self.restart(belief, widget_manager)?;
+                            self.set_status("", widget_manager)?;
+                        }
+                    }
+                }
+                // The text input's value has changed.
+                WidgetEvent::ValueChanged(widget_id, value) if *widget_id == self.text_input => {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        self.step_values[self.step_index] = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,406 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::{Datelike, Local, NaiveDate};
+use freundchen_core::data::{ApplicationData, Medication, Schedule};
+use freundchen_core::mood::number_of_days_in_month;
+use freundchen_core::statistics::Adherence;
+use guiver::{Command, Font, PaintBrush, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The number of days per row of the adherence calendar.
+const DAYS_PER_CALENDAR_ROW: usize = 7;
+
+/// The most days of a month, which get a button in the adherence calendar each.
+const MAXIMUM_NUMBER_OF_DAYS_IN_MONTH: usize = 31;
+
+/// A view to check off today's medications and to show how well the selected medication was taken
+/// in the current month. A click on a day of the calendar checks it off or on.
+pub(crate) struct MedicationsView {
+    adherence_text: WidgetId,
+    /// The adherence calendar's button per day of the month.
+    calendar_day_buttons: Vec<WidgetId>,
+    column: WidgetId,
+    details_text: WidgetId,
+    /// The font of today's buttons, which are created on activation.
+    font: Font,
+    localizer: Rc<Localizer>,
+    medication_text: WidgetId,
+    /// The fill of the scheduled days the medication was not taken on.
+    missed_fill: PaintBrush,
+    next_button: WidgetId,
+    /// The hint shown while there are no medications.
+    none_text: WidgetId,
+    previous_button: WidgetId,
+    /// The index of the medication in the calendar.
+    selected_medication_index: usize,
+    /// The fill of the days the medication was taken on.
+    taken_fill: PaintBrush,
+    /// The button per medication taken today, with the medication's index.
+    today_buttons: Vec<(WidgetId, usize)>,
+    /// The row of today's buttons.
+    today_row: WidgetId,
+    /// The fill of the other days.
+    unscheduled_fill: PaintBrush,
+}
+
+impl MedicationsView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text(localizer.text("medications-title"));
+        let none_text = widget_manager.new_text(localizer.text("medications-none"));
+        let today_text = widget_manager.new_text(localizer.text("medications-today"));
+        let today_row = widget_manager.new_row();
+        let calendar_title_text =
+            widget_manager.new_text(localizer.text("medications-calendar-title"));
+        let row_browse = widget_manager.new_row();
+        let previous_button = widget_manager.new_text_button(localizer.text("previous"));
+        let medication_text = widget_manager.new_text("");
+        let next_button = widget_manager.new_text_button(localizer.text("next"));
+        let details_text = widget_manager.new_text("");
+        let calendar_day_buttons: Vec<WidgetId> = (1..=MAXIMUM_NUMBER_OF_DAYS_IN_MONTH as u8)
+            .map(|day| widget_manager.new_text_button(localizer.day_of_month(day)))
+            .collect();
+        let calendar_rows: Vec<WidgetId> = calendar_day_buttons
+            .chunks(DAYS_PER_CALENDAR_ROW)
+            .map(|_| widget_manager.new_row())
+            .collect();
+        let adherence_text = widget_manager.new_text("");
+        let legend_text = widget_manager.new_text(localizer.text("medications-legend"));
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[
+            none_text,
+            today_text,
+            medication_text,
+            details_text,
+            adherence_text,
+            legend_text,
+        ]);
+        commands.extend(theme.button_commands(&[previous_button, next_button]));
+        commands.extend(theme.button_commands(&calendar_day_buttons));
+        commands.extend([
+            Command::SetFont(title_text, theme.title_font()),
+            Command::SetFont(calendar_title_text, theme.title_font()),
+        ]);
+
+        let mut children = vec![
+            (
+                column,
+                vec![
+                    title_text,
+                    none_text,
+                    today_text,
+                    today_row,
+                    calendar_title_text,
+                    row_browse,
+                    details_text,
+                ],
+            ),
+            (
+                row_browse,
+                vec![previous_button, medication_text, next_button],
+            ),
+            (column, calendar_rows.clone()),
+        ];
+        children.extend(
+            calendar_rows
+                .iter()
+                .zip(calendar_day_buttons.chunks(DAYS_PER_CALENDAR_ROW))
+                .map(|(row, day_buttons)| (*row, day_buttons.to_vec())),
+        );
+        children.push((column, vec![adherence_text, legend_text]));
+
+        for (parent_widget_id, child_widget_ids) in children {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        let mood_colors = &theme.mood_colors;
+
+        Ok(MedicationsView {
+            adherence_text,
+            calendar_day_buttons,
+            column,
+            details_text,
+            font: theme.font(),
+            localizer,
+            medication_text,
+            missed_fill: PaintBrush::Color(mood_colors[0].color()),
+            next_button,
+            none_text,
+            previous_button,
+            selected_medication_index: 0,
+            taken_fill: PaintBrush::Color(mood_colors[mood_colors.len() - 1].color()),
+            today_buttons: vec![],
+            today_row,
+            unscheduled_fill: PaintBrush::Color(theme.button_color.color()),
+        })
+    }
+
+    /// Returns the localized schedule, e.g. "every 2 days".
+    fn schedule_text(&self, schedule: Schedule) -> String {
+        match schedule {
+            Schedule::AsNeeded => self.localizer.text("medications-as-needed"),
+            Schedule::Daily => self.localizer.text("medications-daily"),
+            Schedule::EveryNDays { interval } => self
+                .localizer
+                .text_with("medications-every-n-days", &[("interval", interval.into())]),
+        }
+    }
+
+    /// Replaces the buttons of today's medications with ones for the given medications. The fill of
+    /// a button tells whether the medication was taken today.
+    fn update_today_buttons(
+        &mut self,
+        medications: &[Medication],
+        today: NaiveDate,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let mut commands = vec![Command::RemoveChildren {
+            parent_widget_id: self.today_row,
+            destroy_child_widgets: true,
+        }];
+
+        self.today_buttons.clear();
+
+        for (medication_index, medication) in medications.iter().enumerate() {
+            let Some(dose) = medication.dose_on(today) else {
+                continue;
+            };
+
+            let button = widget_manager.new_text_button(format!("{} {}", medication.name, dose));
+
+            let fill = if medication.intake_dates.contains(&today) {
+                &self.taken_fill
+            } else {
+                &self.unscheduled_fill
+            };
+
+            commands.extend([
+                Command::SetFont(button, self.font.clone()),
+                Command::SetFill(button, Some(fill.clone())),
+                Command::AddChild {
+                    parent_widget_id: self.today_row,
+                    widget_placement: None,
+                    child_widget_id: button,
+                },
+            ]);
+
+            self.today_buttons.push((button, medication_index));
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+
+    /// Shows the selected medication's doses and its intakes in the current month's calendar.
+    fn update_calendar(
+        &self,
+        medications: &[Medication],
+        today: NaiveDate,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let localizer = &self.localizer;
+        let medication = medications.get(self.selected_medication_index);
+        let number_of_days = number_of_days_in_month(today) as usize;
+
+        let mut commands = vec![
+            Command::SetIsHidden(self.none_text, !medications.is_empty()),
+            Command::SetIsDisabled(self.previous_button, self.selected_medication_index == 0),
+            Command::SetIsDisabled(
+                self.next_button,
+                self.selected_medication_index + 1 >= medications.len(),
+            ),
+        ];
+
+        let Some(medication) = medication else {
+            commands.extend([
+                Command::SetValue(self.medication_text, Box::new(String::new())),
+                Command::SetValue(self.details_text, Box::new(String::new())),
+                Command::SetValue(self.adherence_text, Box::new(String::new())),
+            ]);
+            commands.extend(
+                self.calendar_day_buttons
+                    .iter()
+                    .map(|day_button| Command::SetIsDisabled(*day_button, true)),
+            );
+
+            widget_manager.send_commands(commands)?;
+            return Ok(());
+        };
+
+        // Tell the doses, from the start to the stop.
+        let mut doses: Vec<String> = std::iter::once((&medication.start_date, &medication.dose))
+            .chain(&medication.dose_changes)
+            .map(|(date, dose)| {
+                localizer.text_with(
+                    "medications-dose-from",
+                    &[
+                        ("dose", dose.as_str().into()),
+                        ("date", localizer.date(*date).into()),
+                    ],
+                )
+            })
+            .collect();
+
+        if let Some(stop_date) = medication.stop_date {
+            doses.push(localizer.text_with(
+                "medications-stopped-on",
+                &[("date", localizer.date(stop_date).into())],
+            ));
+        }
+
+        let first_date = today.with_day(1).unwrap_or(today);
+        let adherence = Adherence::new(medication, first_date, today);
+
+        let adherence_text = match adherence.ratio() {
+            Some(ratio) => localizer.text_with(
+                "medications-adherence",
+                &[
+                    ("taken", adherence.number_of_days_taken.into()),
+                    ("scheduled", adherence.number_of_scheduled_days.into()),
+                    ("percent", ((ratio * 100.0).round() as i64).into()),
+                ],
+            ),
+            None => localizer.text_with(
+                "medications-intakes",
+                &[("count", adherence.number_of_intakes.into())],
+            ),
+        };
+
+        commands.extend([
+            Command::SetValue(self.medication_text, Box::new(medication.name.clone())),
+            Command::SetValue(
+                self.details_text,
+                Box::new(format!(
+                    "{}: {}",
+                    self.schedule_text(medication.schedule),
+                    localizer.list(&doses)
+                )),
+            ),
+            Command::SetValue(self.adherence_text, Box::new(adherence_text)),
+        ]);
+
+        // Fill the days the medication was taken on and the missed ones. Only the days it is
+        // taken on until today can be checked off.
+        for (day_of_month_index, day_button) in self.calendar_day_buttons.iter().enumerate() {
+            let date = today.with_day0(day_of_month_index as u32);
+            let is_taken = date.is_some_and(|date| medication.intake_dates.contains(&date));
+            let is_missed =
+                date.is_some_and(|date| date <= today && medication.is_scheduled_on(date));
+
+            let fill = if is_taken {
+                &self.taken_fill
+            } else if is_missed {
+                &self.missed_fill
+            } else {
+                &self.unscheduled_fill
+            };
+
+            commands.extend([
+                Command::SetIsHidden(*day_button, day_of_month_index >= number_of_days),
+                Command::SetIsDisabled(
+                    *day_button,
+                    !date.is_some_and(|date| date <= today && medication.is_active_on(date)),
+                ),
+                Command::SetFill(*day_button, Some(fill.clone())),
+            ]);
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+}
+
+impl View for MedicationsView {
+    fn activate(
+        &mut self,
+        _application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let today = Local::now().naive_local().date();
+        let medications = &application_data.medications;
+
+        // The selected medication was removed.
+        self.selected_medication_index = self
+            .selected_medication_index
+            .min(medications.len().saturating_sub(1));
+
+        self.update_today_buttons(medications, today, widget_manager)?;
+        self.update_calendar(medications, today, widget_manager)
+    }
+
+    fn handle_event(
+        &mut self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        let today = Local::now().naive_local().date();
+
+        for widget_event in widget_events {
+            let WidgetEvent::Clicked(widget_id) = widget_event else {
+                continue;
+            };
+
+            // A button of today's medications was clicked.
+            if let Some((_, medication_index)) = self
+                .today_buttons
+                .iter()
+                .find(|(button, _)| button == widget_id)
+            {
+                return Ok(Some(ApplicationCommand::ToggleMedicationIntake {
+                    date: today,
+                    medication_index: *medication_index,
+                }));
+            }
+            // A day of the calendar was clicked.
+            else if let Some(date) = self
+                .calendar_day_buttons
+                .iter()
+                .position(|day_button| day_button == widget_id)
+                .and_then(|day_of_month_index| today.with_day0(day_of_month_index as u32))
+            {
+                return Ok(Some(ApplicationCommand::ToggleMedicationIntake {
+                    date,
+                    medication_index: self.selected_medication_index,
+                }));
+            }
+            // The previous button was clicked.
+            else if *widget_id == self.previous_button {
+                self.selected_medication_index = self.selected_medication_index.saturating_sub(1);
+                self.update_calendar(&application_data.medications, today, widget_manager)?;
+            }
+            // The next button was clicked.
+            else if *widget_id == self.next_button
+                && self.selected_medication_index + 1 < application_data.medications.len()
+            {
+                self.selected_medication_index += 1;
+                self.update_calendar(&application_data.medications, today, widget_manager)?;
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
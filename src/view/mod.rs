@@ -1,14 +1,102 @@
 mod dashboard;
+mod inquiry;
+mod medications;
+mod quotes;
+mod save_failure;
+mod settings;
+mod sleep;
+mod tag_picker;
+mod tags;
+mod thought_record;
 
+use crate::settings::Settings;
 use crate::ApplicationError;
+use chrono::NaiveDate;
+pub(crate) use dashboard::{FactorPanel, SelectionBar};
+use freundchen_core::data::BulkEdit;
+use freundchen_core::data::{
+    ApplicationData, FactorValue, Inquiry, Quote, SleepEntry, ThoughtRecord,
+};
 use guiver::{WidgetEvent, WidgetId, WidgetManager};
+pub(crate) use inquiry::InquiryView;
+pub(crate) use medications::MedicationsView;
+pub(crate) use quotes::QuotesView;
+pub(crate) use save_failure::SaveFailureView;
+pub(crate) use settings::SettingsView;
+pub(crate) use sleep::SleepView;
 use std::cell::RefCell;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
 use std::rc::Rc;
+pub(crate) use tag_picker::TagPickerView;
+pub(crate) use tags::{tag_name, TagsView};
+pub(crate) use thought_record::ThoughtRecordView;
 
 /// A command to the application.
 #[derive(Clone)]
 pub(crate) enum ApplicationCommand {
+    AddInquiry(Inquiry),
+    AddQuotes(Vec<Quote>),
+    AddThoughtRecord(ThoughtRecord),
+    /// Remembers the mood values of the first selected day to paste them to other days.
+    CopySelectedDay,
+    /// Applies the edit to all selected days at once.
+    EditSelectedDays(BulkEdit),
+    /// Lets the settings view show the given settings.
+    EditSettings(Settings),
+    /// Lets the sleep view show the sleep of the night after the given day.
+    EditSleep(NaiveDate),
+    /// Shows only the days tagged with the given tag in the mood widget. `None` shows all days.
+    FilterByTag(Option<String>),
+    /// Sets the copied mood values to the selected days.
+    PasteToSelectedDays,
+    /// Lets the tag picker view show the given day's tags.
+    PickTags(NaiveDate),
+    /// Quits the application, although the data could not be saved.
+    QuitWithoutSaving,
+    RemoveQuote(usize),
+    /// Tries again to save the data and quit.
+    RetrySave,
+    /// Saves the data to the given path instead and quits.
+    SaveAs(PathBuf),
+    /// Selects the given days of the current month. `None` deselects them.
+    SelectDays(Option<RangeInclusive<u8>>),
+    /// Sets the value of the factor at the index to the selected days, or to today if no days are
+    /// selected. `None` removes the value.
+    SetFactorValue {
+        factor_index: usize,
+        value: Option<FactorValue>,
+    },
+    SetSettings(Settings),
+    /// Sets the sleep of the night after the given day. `None` removes it.
+    SetSleep {
+        date: NaiveDate,
+        sleep: Option<SleepEntry>,
+    },
     ShowDashboard,
+    ShowInquiries,
+    ShowMedications,
+    ShowQuotes,
+    /// Lets the save failure view tell why saving to the path failed.
+    ShowSaveFailure {
+        path: PathBuf,
+        reason: String,
+    },
+    ShowSettings,
+    ShowTags,
+    /// Lets the thought record view fill in a record of the given day.
+    ShowThoughtRecords(NaiveDate),
+    ToggleFavouriteQuote(usize),
+    /// Records that the medication at the index was taken on the date, or removes the intake.
+    ToggleMedicationIntake {
+        date: NaiveDate,
+        medication_index: usize,
+    },
+    /// Tags the day with the given tag or removes the tag from it.
+    ToggleTag {
+        date: NaiveDate,
+        tag: String,
+    },
 }
 
 ///
@@ -17,19 +105,21 @@ pub(crate) trait View {
     fn activate(
         &mut self,
         application_command: ApplicationCommand,
+        application_data: &ApplicationData,
         widget_manager: &mut WidgetManager<()>,
     ) -> Result<(), ApplicationError>;
 
     ///
     fn handle_event(
         &mut self,
+        application_data: &ApplicationData,
         widget_manager: &mut WidgetManager<()>,
         widget_events: &[WidgetEvent],
-    ) -> Option<ApplicationCommand>;
+    ) -> Result<Option<ApplicationCommand>, ApplicationError>;
 
     /// The ID of the view's main widget.
     fn main_widget_id(&self) -> &WidgetId;
 }
 
 ///
-type ViewBox = Rc<RefCell<Box<dyn View>>>;
+pub(crate) type ViewBox = Rc<RefCell<Box<dyn View>>>;
@@ -0,0 +1,374 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use freundchen_core::data::{import_quotes, parse_tags, ApplicationData, Quote};
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// The width of the text inputs.
+const TEXT_INPUT_WIDTH: f64 = 300.0;
+
+/// A view to add, import, browse and remove quotes.
+pub(crate) struct QuotesView {
+    add_button: WidgetId,
+    author_text_input: WidgetId,
+    author: String,
+    column: WidgetId,
+    favourite_button: WidgetId,
+    import_button: WidgetId,
+    import_path_text_input: WidgetId,
+    import_path: String,
+    localizer: Rc<Localizer>,
+    next_button: WidgetId,
+    previous_button: WidgetId,
+    quote_texts: Vec<WidgetId>,
+    remove_button: WidgetId,
+    selected_quote_index: Option<usize>,
+    status_text: WidgetId,
+    tags_text_input: WidgetId,
+    tags: String,
+    text_text_input: WidgetId,
+    text: String,
+}
+
+impl QuotesView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text(localizer.text("quotes-title"));
+        let row_text = widget_manager.new_row();
+        let text_label = widget_manager.new_text(localizer.text("quotes-text"));
+        let text_text_input = widget_manager.new_text_input("", 2.0 * TEXT_INPUT_WIDTH);
+        let row_author = widget_manager.new_row();
+        let author_label = widget_manager.new_text(localizer.text("quotes-author"));
+        let author_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let row_tags = widget_manager.new_row();
+        let tags_label = widget_manager.new_text(localizer.text("quotes-tags"));
+        let tags_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let add_button = widget_manager.new_text_button(localizer.text("quotes-add"));
+        let row_import = widget_manager.new_row();
+        let import_label = widget_manager.new_text(localizer.text("quotes-import-label"));
+        let import_path_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let import_button = widget_manager.new_text_button(localizer.text("quotes-import"));
+        let status_text = widget_manager.new_text("");
+        let your_quotes_text = widget_manager.new_text(localizer.text("quotes-yours"));
+        let row_browse = widget_manager.new_row();
+        let previous_button = widget_manager.new_text_button(localizer.text("previous"));
+        let next_button = widget_manager.new_text_button(localizer.text("next"));
+        let favourite_button = widget_manager.new_text_button(localizer.text("quotes-favourite"));
+        let remove_button = widget_manager.new_text_button(localizer.text("quotes-remove"));
+        let quote_texts = vec![widget_manager.new_text(""), widget_manager.new_text("")];
+
+        let title_font = theme.title_font();
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[
+            text_label,
+            author_label,
+            tags_label,
+            import_label,
+            status_text,
+        ]);
+        commands.extend(theme.text_commands(&quote_texts));
+        commands.extend(theme.button_commands(&[
+            add_button,
+            import_button,
+            previous_button,
+            next_button,
+            favourite_button,
+        ]));
+        commands.extend(theme.destructive_button_commands(&[remove_button]));
+        commands.extend([
+            Command::SetFont(title_text, title_font.clone()),
+            Command::SetFont(your_quotes_text, title_font),
+        ]);
+
+        for (parent_widget_id, child_widget_ids) in [
+            (
+                column,
+                vec![
+                    title_text,
+                    row_text,
+                    row_author,
+                    row_tags,
+                    add_button,
+                    row_import,
+                    status_text,
+                    your_quotes_text,
+                    row_browse,
+                ],
+            ),
+            (row_text, vec![text_label, text_text_input]),
+            (row_author, vec![author_label, author_text_input]),
+            (row_tags, vec![tags_label, tags_text_input]),
+            (
+                row_import,
+                vec![import_label, import_path_text_input, import_button],
+            ),
+            (
+                row_browse,
+                vec![
+                    previous_button,
+                    next_button,
+                    favourite_button,
+                    remove_button,
+                ],
+            ),
+            (column, quote_texts.clone()),
+        ] {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(QuotesView {
+            add_button,
+            author_text_input,
+            author: String::new(),
+            column,
+            favourite_button,
+            import_button,
+            import_path_text_input,
+            import_path: String::new(),
+            localizer,
+            next_button,
+            previous_button,
+            quote_texts,
+            remove_button,
+            selected_quote_index: None,
+            status_text,
+            tags_text_input,
+            tags: String::new(),
+            text_text_input,
+            text: String::new(),
+        })
+    }
+
+    /// Clears the text inputs for a new quote.
+    fn clear_text_inputs(
+        &mut self,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        self.author.clear();
+        self.tags.clear();
+        self.text.clear();
+
+        widget_manager.send_commands(vec![
+            Command::SetValue(self.author_text_input, Box::new(String::new())),
+            Command::SetValue(self.tags_text_input, Box::new(String::new())),
+            Command::SetValue(self.text_text_input, Box::new(String::new())),
+        ])?;
+
+        Ok(())
+    }
+
+    /// Sets the status text.
+    fn set_status(
+        &self,
+        status: impl Into<String>,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        widget_manager
+            .send_command(Command::SetValue(self.status_text, Box::new(status.into())))?;
+
+        Ok(())
+    }
+
+    /// Shows the selected quote.
+    fn update_quote_texts(
+        &self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let lines = match self
+            .selected_quote_index
+            .and_then(|index| Some((index, application_data.quotes.get(index)?)))
+        {
+            None => vec![self.localizer.text("quotes-none"), String::new()],
+            Some((index, quote)) => vec![
+                format!(
+                    "({}/{}) {}{}",
+                    index + 1,
+                    application_data.quotes.len(),
+                    if quote.is_favourite { "★ " } else { "" },
+                    quote
+                ),
+                self.localizer.text_with(
+                    "quotes-show-tags",
+                    &[("tags", quote.tags.join(", ").into())],
+                ),
+            ],
+        };
+
+        widget_manager.send_commands(
+            self.quote_texts
+                .iter()
+                .zip(lines)
+                .map(|(quote_text, line)| Command::SetValue(*quote_text, Box::new(line)))
+                .collect(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl View for QuotesView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let number_of_quotes = application_data.quotes.len();
+
+        self.selected_quote_index = match (application_command, self.selected_quote_index) {
+            // Select the first newly added quote.
+            (ApplicationCommand::AddQuotes(quotes), _) if !quotes.is_empty() => {
+                Some(number_of_quotes - quotes.len())
+            }
+            // Keep the selection within range.
+            (_, Some(index)) if number_of_quotes > 0 => Some(index.min(number_of_quotes - 1)),
+            // Select the first quote, if there is one.
+            _ => (number_of_quotes > 0).then_some(0),
+        };
+
+        self.update_quote_texts(application_data, widget_manager)
+    }
+
+    fn handle_event(
+        &mut self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The add button was clicked.
+                    if *widget_id == self.add_button {
+                        // The quote's text is required.
+                        if self.text.trim().is_empty() {
+                            self.set_status(
+                                self.localizer.text("quotes-text-missing"),
+                                widget_manager,
+                            )?;
+                            continue;
+                        }
+
+                        let quote = Quote {
+                            text: self.text.trim().to_string(),
+                            author: self.author.trim().to_string(),
+                            tags: parse_tags(&self.tags),
+                            is_favourite: false,
+                        };
+
+                        self.clear_text_inputs(widget_manager)?;
+                        self.set_status(self.localizer.text("quotes-added"), widget_manager)?;
+
+                        return Ok(Some(ApplicationCommand::AddQuotes(vec![quote])));
+                    }
+                    // The import button was clicked.
+                    else if *widget_id == self.import_button {
+                        let path = PathBuf::from(self.import_path.trim());
+
+                        return match import_quotes(&path) {
+                            Ok(quotes) => {
+                                self.set_status(
+                                    self.localizer.text_with(
+                                        "quotes-imported",
+                                        &[
+                                            ("count", quotes.len().into()),
+                                            ("path", path.display().to_string().into()),
+                                        ],
+                                    ),
+                                    widget_manager,
+                                )?;
+
+                                Ok(Some(ApplicationCommand::AddQuotes(quotes)))
+                            }
+                            Err(error) => {
+                                let reason = error.to_string();
+
+                                self.set_status(
+                                    self.localizer.text_with(
+                                        "quotes-import-failed",
+                                        &[
+                                            ("path", path.display().to_string().into()),
+                                            ("reason", reason.into()),
+                                        ],
+                                    ),
+                                    widget_manager,
+                                )?;
+
+                                Ok(None)
+                            }
+                        };
+                    }
+                    // The previous button was clicked.
+                    else if *widget_id == self.previous_button {
+                        if let Some(index) = self.selected_quote_index {
+                            self.selected_quote_index = Some(index.saturating_sub(1));
+                        }
+
+                        self.update_quote_texts(application_data, widget_manager)?;
+                    }
+                    // The next button was clicked.
+                    else if *widget_id == self.next_button {
+                        if let Some(index) = self.selected_quote_index {
+                            self.selected_quote_index =
+                                Some((index + 1).min(application_data.quotes.len() - 1));
+                        }
+
+                        self.update_quote_texts(application_data, widget_manager)?;
+                    }
+                    // The favourite button was clicked.
+                    else if *widget_id == self.favourite_button {
+                        if let Some(index) = self.selected_quote_index {
+                            return Ok(Some(ApplicationCommand::ToggleFavouriteQuote(index)));
+                        }
+                    }
+                    // The remove button was clicked.
+                    else if *widget_id == self.remove_button {
+                        if let Some(index) = self.selected_quote_index {
+                            self.set_status(self.localizer.text("quotes-removed"), widget_manager)?;
+
+                            return Ok(Some(ApplicationCommand::RemoveQuote(index)));
+                        }
+                    }
+                }
+                WidgetEvent::ValueChanged(widget_id, value) => {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        if *widget_id == self.author_text_input {
+                            self.author = text.clone();
+                        } else if *widget_id == self.import_path_text_input {
+                            self.import_path = text.clone();
+                        } else if *widget_id == self.tags_text_input {
+                            self.tags = text.clone();
+                        } else if *widget_id == self.text_text_input {
+                            self.text = text.clone();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
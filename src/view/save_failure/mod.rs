@@ -0,0 +1,161 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use freundchen_core::data::ApplicationData;
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// The width of the text input.
+const TEXT_INPUT_WIDTH: f64 = 300.0;
+
+/// A view shown when quitting, if the data could not be saved. It offers to retry, to save to
+/// another file or to quit without saving.
+pub(crate) struct SaveFailureView {
+    column: WidgetId,
+    localizer: Rc<Localizer>,
+    message_text: WidgetId,
+    quit_button: WidgetId,
+    retry_button: WidgetId,
+    save_as_button: WidgetId,
+    save_as_path: String,
+    save_as_text_input: WidgetId,
+}
+
+impl SaveFailureView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text(localizer.text("save-failure-title"));
+        let message_text = widget_manager.new_text("");
+        let retry_button = widget_manager.new_text_button(localizer.text("save-failure-retry"));
+        let row_save_as = widget_manager.new_row();
+        let save_as_label = widget_manager.new_text(localizer.text("save-failure-save-as-label"));
+        let save_as_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let save_as_button = widget_manager.new_text_button(localizer.text("save-failure-save-as"));
+        let quit_button = widget_manager.new_text_button(localizer.text("save-failure-quit"));
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[message_text, save_as_label]);
+        commands.extend(theme.button_commands(&[retry_button, save_as_button]));
+        commands.extend(theme.destructive_button_commands(&[quit_button]));
+        commands.push(Command::SetFont(title_text, theme.title_font()));
+
+        for (parent_widget_id, child_widget_ids) in [
+            (
+                column,
+                vec![
+                    title_text,
+                    message_text,
+                    retry_button,
+                    row_save_as,
+                    quit_button,
+                ],
+            ),
+            (
+                row_save_as,
+                vec![save_as_label, save_as_text_input, save_as_button],
+            ),
+        ] {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(SaveFailureView {
+            column,
+            localizer,
+            message_text,
+            quit_button,
+            retry_button,
+            save_as_button,
+            save_as_path: String::new(),
+            save_as_text_input,
+        })
+    }
+}
+
+impl View for SaveFailureView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        _application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        // Tell why saving failed and suggest the same path to save to.
+        if let ApplicationCommand::ShowSaveFailure { path, reason } = application_command {
+            let message = self.localizer.text_with(
+                "save-failure-message",
+                &[
+                    ("path", path.display().to_string().into()),
+                    ("reason", reason.into()),
+                ],
+            );
+
+            self.save_as_path = path.display().to_string();
+
+            widget_manager.send_commands(vec![
+                Command::SetValue(self.message_text, Box::new(message)),
+                Command::SetValue(self.save_as_text_input, Box::new(self.save_as_path.clone())),
+            ])?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_event(
+        &mut self,
+        _application_data: &ApplicationData,
+        _widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The quit button was clicked.
+                    if *widget_id == self.quit_button {
+                        return Ok(Some(ApplicationCommand::QuitWithoutSaving));
+                    }
+                    // The retry button was clicked.
+                    else if *widget_id == self.retry_button {
+                        return Ok(Some(ApplicationCommand::RetrySave));
+                    }
+                    // The save as button was clicked and there is a path.
+                    else if *widget_id == self.save_as_button
+                        && !self.save_as_path.trim().is_empty()
+                    {
+                        return Ok(Some(ApplicationCommand::SaveAs(PathBuf::from(
+                            self.save_as_path.trim(),
+                        ))));
+                    }
+                }
+                // The path was changed.
+                WidgetEvent::ValueChanged(widget_id, value)
+                    if *widget_id == self.save_as_text_input =>
+                {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        self.save_as_path = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,338 @@
+use crate::i18n::Localizer;
+use crate::settings::{Settings, SettingsError, FONT_SIZE_RANGE, WINDOW_SIZE_RANGE};
+use crate::theme::{Theme, BUILT_IN_THEME_NAMES};
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use freundchen_core::data::ApplicationData;
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+/// The width of the text inputs.
+const TEXT_INPUT_WIDTH: f64 = 300.0;
+
+/// The editable settings.
+#[derive(Clone, Copy)]
+enum Field {
+    PreferredName,
+    Locale,
+    DataPath,
+    WindowWidth,
+    WindowHeight,
+    GreetingFontSize,
+    Theme,
+    LogLevel,
+}
+
+impl Field {
+    const ALL: [Field; 8] = [
+        Field::PreferredName,
+        Field::Locale,
+        Field::DataPath,
+        Field::WindowWidth,
+        Field::WindowHeight,
+        Field::GreetingFontSize,
+        Field::Theme,
+        Field::LogLevel,
+    ];
+
+    /// The ID of the field's label message.
+    fn label_message_id(&self) -> &'static str {
+        match self {
+            Field::PreferredName => "settings-preferred-name",
+            Field::Locale => "settings-locale",
+            Field::DataPath => "settings-data-path",
+            Field::WindowWidth => "settings-window-width",
+            Field::WindowHeight => "settings-window-height",
+            Field::GreetingFontSize => "settings-greeting-font-size",
+            Field::Theme => "settings-theme",
+            Field::LogLevel => "settings-log-level",
+        }
+    }
+
+    /// Returns the field's value in the given settings.
+    fn value(&self, settings: &Settings) -> String {
+        match self {
+            Field::PreferredName => settings.preferred_name.clone().unwrap_or_default(),
+            Field::Locale => settings.locale.clone().unwrap_or_default(),
+            Field::DataPath => settings
+                .data_path
+                .as_ref()
+                .map(|data_path| data_path.display().to_string())
+                .unwrap_or_default(),
+            Field::WindowWidth => settings.window_width.to_string(),
+            Field::WindowHeight => settings.window_height.to_string(),
+            Field::GreetingFontSize => settings.greeting_font_size.to_string(),
+            Field::Theme => settings.theme.clone(),
+            Field::LogLevel => settings.log_level.clone(),
+        }
+    }
+}
+
+// =================================================================================================
+
+/// A view to edit the settings.
+pub(crate) struct SettingsView {
+    column: WidgetId,
+    field_text_inputs: Vec<WidgetId>,
+    field_values: Vec<String>,
+    localizer: Rc<Localizer>,
+    save_button: WidgetId,
+    status_text: WidgetId,
+}
+
+impl SettingsView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text(localizer.text("settings-title"));
+        let restart_text = widget_manager.new_text(localizer.text("settings-restart-hint"));
+        let row_save = widget_manager.new_row();
+        let save_button = widget_manager.new_text_button(localizer.text("save"));
+        let status_text = widget_manager.new_text("");
+
+        let mut commands = vec![
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: title_text,
+            },
+            Command::SetFont(title_text, theme.title_font()),
+        ];
+
+        // Add a row with a label and a text input per field.
+        let mut field_text_inputs = vec![];
+        let mut texts = vec![restart_text, status_text];
+
+        for field in Field::ALL {
+            let row = widget_manager.new_row();
+            let label_text = widget_manager.new_text(localizer.text(field.label_message_id()));
+            let text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+
+            commands.extend([
+                Command::AddChild {
+                    parent_widget_id: column,
+                    widget_placement: None,
+                    child_widget_id: row,
+                },
+                Command::AddChild {
+                    parent_widget_id: row,
+                    widget_placement: None,
+                    child_widget_id: label_text,
+                },
+                Command::AddChild {
+                    parent_widget_id: row,
+                    widget_placement: None,
+                    child_widget_id: text_input,
+                },
+            ]);
+
+            field_text_inputs.push(text_input);
+            texts.push(label_text);
+        }
+
+        for (parent_widget_id, child_widget_ids) in [
+            (column, vec![restart_text, row_save]),
+            (row_save, vec![save_button, status_text]),
+        ] {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        // Apply the theme.
+        commands.extend(theme.text_commands(&texts));
+        commands.extend(theme.button_commands(&[save_button]));
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(SettingsView {
+            column,
+            field_text_inputs,
+            field_values: vec![String::new(); Field::ALL.len()],
+            localizer,
+            save_button,
+            status_text,
+        })
+    }
+
+    /// Returns the trimmed value of the given field.
+    fn field_value(&self, field: Field) -> &str {
+        self.field_values[field as usize].trim()
+    }
+
+    /// Returns the value of the given field as a number.
+    fn number(&self, field: Field) -> Result<f64, String> {
+        self.field_value(field).parse::<f64>().map_err(|_| {
+            self.localizer.text_with(
+                "settings-not-a-number",
+                &[(
+                    "field",
+                    self.localizer.text(field.label_message_id()).into(),
+                )],
+            )
+        })
+    }
+
+    /// Returns the settings from the field values or a message why they are invalid.
+    fn settings(&self) -> Result<Settings, String> {
+        let optional_text = |field: Field| {
+            let value = self.field_value(field);
+            (!value.is_empty()).then(|| value.to_string())
+        };
+
+        let settings = Settings {
+            data_path: optional_text(Field::DataPath).map(PathBuf::from),
+            greeting_font_size: self.number(Field::GreetingFontSize)?,
+            locale: optional_text(Field::Locale),
+            log_level: self.field_value(Field::LogLevel).to_string(),
+            preferred_name: optional_text(Field::PreferredName),
+            theme: self.field_value(Field::Theme).to_string(),
+            window_height: self.number(Field::WindowHeight)?,
+            window_width: self.number(Field::WindowWidth)?,
+        };
+
+        settings
+            .validate()
+            .map_err(|error| self.settings_error_message(&error))?;
+
+        Ok(settings)
+    }
+
+    /// Returns a message explaining the given settings error.
+    fn settings_error_message(&self, error: &SettingsError) -> String {
+        match error {
+            SettingsError::InvalidDataPath(data_path) => self.localizer.text_with(
+                "settings-invalid-data-path",
+                &[("path", data_path.display().to_string().into())],
+            ),
+            SettingsError::InvalidFontSize(font_size) => self.localizer.text_with(
+                "settings-invalid-font-size",
+                &[
+                    ("size", (*font_size).into()),
+                    ("minimum", (*FONT_SIZE_RANGE.start()).into()),
+                    ("maximum", (*FONT_SIZE_RANGE.end()).into()),
+                ],
+            ),
+            SettingsError::InvalidLocale(locale) => self.localizer.text_with(
+                "settings-invalid-locale",
+                &[("locale", locale.clone().into())],
+            ),
+            SettingsError::InvalidLogLevel(log_level) => self.localizer.text_with(
+                "settings-invalid-log-level",
+                &[("level", log_level.clone().into())],
+            ),
+            SettingsError::InvalidWindowSize(width, height) => self.localizer.text_with(
+                "settings-invalid-window-size",
+                &[
+                    ("width", (*width).into()),
+                    ("height", (*height).into()),
+                    ("minimum", (*WINDOW_SIZE_RANGE.start()).into()),
+                    ("maximum", (*WINDOW_SIZE_RANGE.end()).into()),
+                ],
+            ),
+            SettingsError::InvalidTheme(path, reason) => self.localizer.text_with(
+                "settings-invalid-theme",
+                &[
+                    ("path", path.display().to_string().into()),
+                    ("reason", reason.clone().into()),
+                ],
+            ),
+            SettingsError::Parse(error) => error.to_string(),
+            SettingsError::Serialize(error) => error.to_string(),
+            SettingsError::UnknownTheme(name) => self.localizer.text_with(
+                "settings-unknown-theme",
+                &[
+                    ("name", name.clone().into()),
+                    (
+                        "themes",
+                        self.localizer
+                            .list(&BUILT_IN_THEME_NAMES.map(String::from))
+                            .into(),
+                    ),
+                    ("directory", Theme::directory().display().to_string().into()),
+                ],
+            ),
+        }
+    }
+}
+
+impl View for SettingsView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        _application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        // Show the current settings.
+        if let ApplicationCommand::EditSettings(settings) = application_command {
+            self.field_values = Field::ALL
+                .iter()
+                .map(|field| field.value(&settings))
+                .collect();
+
+            let mut commands = vec![Command::SetValue(self.status_text, Box::new(String::new()))];
+
+            for (text_input, value) in self.field_text_inputs.iter().zip(&self.field_values) {
+                commands.push(Command::SetValue(*text_input, Box::new(value.clone())));
+            }
+
+            widget_manager.send_commands(commands)?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_event(
+        &mut self,
+        _application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                // The save button was clicked.
+                WidgetEvent::Clicked(widget_id) if *widget_id == self.save_button => {
+                    let (status, application_command) = match self.settings() {
+                        Ok(settings) => (
+                            self.localizer.text("saved"),
+                            Some(ApplicationCommand::SetSettings(settings)),
+                        ),
+                        Err(message) => (message, None),
+                    };
+
+                    widget_manager
+                        .send_command(Command::SetValue(self.status_text, Box::new(status)))?;
+
+                    return Ok(application_command);
+                }
+                // A field value has changed.
+                WidgetEvent::ValueChanged(widget_id, value) => {
+                    if let (Some(index), Some(text)) = (
+                        self.field_text_inputs
+                            .iter()
+                            .position(|text_input| text_input == widget_id),
+                        value.downcast_ref::<String>(),
+                    ) {
+                        self.field_values[index] = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,321 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::{NaiveDate, NaiveTime};
+use freundchen_core::data::{night_duration, ApplicationData, SleepEntry, MAXIMUM_SLEEP_QUALITY};
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The width of the text inputs.
+const TEXT_INPUT_WIDTH: f64 = 80.0;
+
+/// The format of the bedtime and the wake time.
+const TIME_FORMAT: &str = "%H:%M";
+
+/// The fields of a sleep entry, in the order they are filled in.
+#[derive(Clone, Copy)]
+enum Field {
+    Bedtime,
+    WakeTime,
+    Quality,
+    Awakenings,
+}
+
+impl Field {
+    const ALL: [Field; 4] = [
+        Field::Bedtime,
+        Field::WakeTime,
+        Field::Quality,
+        Field::Awakenings,
+    ];
+
+    /// The ID of the field's label message.
+    fn label_message_id(&self) -> &'static str {
+        match self {
+            Field::Bedtime => "sleep-bedtime",
+            Field::WakeTime => "sleep-wake-time",
+            Field::Quality => "sleep-quality",
+            Field::Awakenings => "sleep-awakenings",
+        }
+    }
+}
+
+// =================================================================================================
+
+/// A view to log the sleep of the night after a day. It is opened with a click on the night in the
+/// sleep widget.
+pub(crate) struct SleepView {
+    back_button: WidgetId,
+    column: WidgetId,
+    /// The date of the day before the night.
+    date: Option<NaiveDate>,
+    duration_text: WidgetId,
+    field_text_inputs: Vec<WidgetId>,
+    field_values: Vec<String>,
+    localizer: Rc<Localizer>,
+    remove_button: WidgetId,
+    save_button: WidgetId,
+    status_text: WidgetId,
+    title_text: WidgetId,
+}
+
+impl SleepView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text("");
+        let duration_text = widget_manager.new_text("");
+        let row_buttons = widget_manager.new_row();
+        let save_button = widget_manager.new_text_button(localizer.text("save"));
+        let remove_button = widget_manager.new_text_button(localizer.text("sleep-remove"));
+        let back_button = widget_manager.new_text_button(localizer.text("back"));
+        let status_text = widget_manager.new_text("");
+
+        let mut children = vec![(column, vec![title_text])];
+        let mut field_text_inputs = vec![];
+        let mut texts = vec![duration_text, status_text];
+
+        // Add a row with a label and a text input per field.
+        for field in Field::ALL {
+            let row = widget_manager.new_row();
+            let label_text = widget_manager.new_text(localizer.text(field.label_message_id()));
+            let text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+
+            children.extend([(column, vec![row]), (row, vec![label_text, text_input])]);
+            field_text_inputs.push(text_input);
+            texts.push(label_text);
+        }
+
+        children.extend([
+            (column, vec![duration_text, row_buttons, status_text]),
+            (row_buttons, vec![save_button, remove_button, back_button]),
+        ]);
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&texts);
+        commands.extend(theme.button_commands(&[save_button, back_button]));
+        commands.extend(theme.destructive_button_commands(&[remove_button]));
+        commands.push(Command::SetFont(title_text, theme.title_font()));
+
+        for (parent_widget_id, child_widget_ids) in children {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(SleepView {
+            back_button,
+            column,
+            date: None,
+            duration_text,
+            field_values: vec![String::new(); field_text_inputs.len()],
+            field_text_inputs,
+            localizer,
+            remove_button,
+            save_button,
+            status_text,
+            title_text,
+        })
+    }
+
+    /// Parses the bedtime or the wake time.
+    fn parse_time(&self, field: Field) -> Result<NaiveTime, String> {
+        let text = self.field_values[field as usize].trim();
+
+        NaiveTime::parse_from_str(text, TIME_FORMAT).map_err(|_| {
+            self.localizer.text_with(
+                "sleep-invalid-time",
+                &[
+                    (
+                        "field",
+                        self.localizer.text(field.label_message_id()).into(),
+                    ),
+                    ("time", text.into()),
+                ],
+            )
+        })
+    }
+
+    /// Creates a sleep entry from the text inputs. An empty number of awakenings is none.
+    fn sleep_entry(&self) -> Result<SleepEntry, String> {
+        let bedtime = self.parse_time(Field::Bedtime)?;
+        let wake_time = self.parse_time(Field::WakeTime)?;
+
+        if bedtime == wake_time {
+            return Err(self.localizer.text("sleep-same-times"));
+        }
+
+        let quality = self.field_values[Field::Quality as usize]
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|quality| (1..=MAXIMUM_SLEEP_QUALITY).contains(quality))
+            .ok_or_else(|| {
+                self.localizer.text_with(
+                    "sleep-invalid-quality",
+                    &[("maximum", MAXIMUM_SLEEP_QUALITY.into())],
+                )
+            })?;
+
+        let awakenings = match self.field_values[Field::Awakenings as usize].trim() {
+            "" => 0,
+            text => text
+                .parse::<u32>()
+                .map_err(|_| self.localizer.text("sleep-invalid-awakenings"))?,
+        };
+
+        SleepEntry::new(bedtime, wake_time, quality, awakenings).map_err(|error| error.to_string())
+    }
+
+    /// Shows how long the night is, if both times can be parsed.
+    fn update_duration_text(
+        &self,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let duration = match (
+            self.parse_time(Field::Bedtime),
+            self.parse_time(Field::WakeTime),
+        ) {
+            (Ok(bedtime), Ok(wake_time)) if bedtime != wake_time => {
+                let minutes = night_duration(bedtime, wake_time).num_minutes();
+
+                self.localizer.text_with(
+                    "sleep-duration",
+                    &[
+                        ("hours", (minutes / 60).into()),
+                        ("minutes", (minutes % 60).into()),
+                    ],
+                )
+            }
+            _ => String::new(),
+        };
+
+        widget_manager.send_command(Command::SetValue(self.duration_text, Box::new(duration)))?;
+
+        Ok(())
+    }
+}
+
+impl View for SleepView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let ApplicationCommand::EditSleep(date) = application_command else {
+            return Ok(());
+        };
+
+        self.date = Some(date);
+
+        // Fill in the night's sleep, if it was logged.
+        self.field_values = match application_data.sleep_per_date.get(&date) {
+            Some(sleep) => vec![
+                sleep.bedtime.format(TIME_FORMAT).to_string(),
+                sleep.wake_time.format(TIME_FORMAT).to_string(),
+                sleep.quality.to_string(),
+                sleep.awakenings.to_string(),
+            ],
+            None => vec![String::new(); self.field_text_inputs.len()],
+        };
+
+        let mut commands = vec![
+            Command::SetValue(
+                self.title_text,
+                Box::new(
+                    self.localizer
+                        .text_with("sleep-title", &[("date", self.localizer.date(date).into())]),
+                ),
+            ),
+            Command::SetValue(self.status_text, Box::new(String::new())),
+            Command::SetIsHidden(
+                self.remove_button,
+                !application_data.sleep_per_date.contains_key(&date),
+            ),
+        ];
+        commands.extend(
+            self.field_text_inputs
+                .iter()
+                .zip(&self.field_values)
+                .map(|(text_input, value)| Command::SetValue(*text_input, Box::new(value.clone()))),
+        );
+
+        widget_manager.send_commands(commands)?;
+
+        self.update_duration_text(widget_manager)
+    }
+
+    fn handle_event(
+        &mut self,
+        _application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        let Some(date) = self.date else {
+            return Ok(None);
+        };
+
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The save button was clicked.
+                    if *widget_id == self.save_button {
+                        match self.sleep_entry() {
+                            Ok(sleep) => {
+                                return Ok(Some(ApplicationCommand::SetSleep {
+                                    date,
+                                    sleep: Some(sleep),
+                                }));
+                            }
+                            Err(message) => {
+                                widget_manager.send_command(Command::SetValue(
+                                    self.status_text,
+                                    Box::new(message),
+                                ))?;
+                            }
+                        }
+                    }
+                    // The remove button was clicked.
+                    else if *widget_id == self.remove_button {
+                        return Ok(Some(ApplicationCommand::SetSleep { date, sleep: None }));
+                    }
+                    // The back button was clicked.
+                    else if *widget_id == self.back_button {
+                        return Ok(Some(ApplicationCommand::ShowDashboard));
+                    }
+                }
+                // A field was changed.
+                WidgetEvent::ValueChanged(widget_id, value) => {
+                    if let (Some(index), Some(text)) = (
+                        self.field_text_inputs
+                            .iter()
+                            .position(|text_input| text_input == widget_id),
+                        value.downcast_ref::<String>(),
+                    ) {
+                        self.field_values[index] = text.clone();
+                        self.update_duration_text(widget_manager)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,251 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::tags::tag_name;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::NaiveDate;
+use freundchen_core::data::ApplicationData;
+use freundchen_core::feelings::{is_feeling, FEELINGS_WHEEL};
+use guiver::{Command, PaintBrush, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The width of the text input.
+const TEXT_INPUT_WIDTH: f64 = 200.0;
+
+/// A view to tag a day with feelings of the wheel and custom tags. It is opened with a right click
+/// on the day in the mood widget.
+pub(crate) struct TagPickerView {
+    column: WidgetId,
+    custom_tag: String,
+    custom_tag_text_input: WidgetId,
+    custom_tags_text: WidgetId,
+    /// The date whose tags are picked.
+    date: Option<NaiveDate>,
+    done_button: WidgetId,
+    /// The button of each feeling of the wheel.
+    feeling_buttons: Vec<(WidgetId, &'static str)>,
+    localizer: Rc<Localizer>,
+    /// The fill of the buttons of the day's feelings.
+    picked_fill: PaintBrush,
+    title_text: WidgetId,
+    toggle_custom_tag_button: WidgetId,
+    /// The fill of the other buttons.
+    unpicked_fill: PaintBrush,
+}
+
+impl TagPickerView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text("");
+        let mut feeling_buttons = vec![];
+        let mut rows_feelings = vec![];
+
+        // Add a row per core feeling, followed by its sub feelings.
+        for feeling in FEELINGS_WHEEL {
+            let row = widget_manager.new_row();
+            let mut buttons = vec![];
+
+            for tag in std::iter::once(feeling.name).chain(feeling.sub_feelings.iter().copied()) {
+                let button = widget_manager.new_text_button(tag_name(&localizer, tag));
+                feeling_buttons.push((button, tag));
+                buttons.push(button);
+            }
+
+            rows_feelings.push((row, buttons));
+        }
+
+        let row_custom_tag = widget_manager.new_row();
+        let custom_tag_label = widget_manager.new_text(localizer.text("tag-picker-custom-label"));
+        let custom_tag_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let toggle_custom_tag_button =
+            widget_manager.new_text_button(localizer.text("tag-picker-custom-toggle"));
+        let custom_tags_text = widget_manager.new_text("");
+        let done_button = widget_manager.new_text_button(localizer.text("tag-picker-done"));
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[custom_tag_label, custom_tags_text]);
+        commands.extend(
+            theme.button_commands(
+                &feeling_buttons
+                    .iter()
+                    .map(|(button, _)| *button)
+                    .collect::<Vec<_>>(),
+            ),
+        );
+        commands.extend(theme.button_commands(&[toggle_custom_tag_button, done_button]));
+        commands.push(Command::SetFont(title_text, theme.title_font()));
+
+        let mut children = vec![(column, vec![title_text])];
+        children.push((column, rows_feelings.iter().map(|(row, _)| *row).collect()));
+        children.extend(rows_feelings);
+        children.extend([
+            (column, vec![row_custom_tag, custom_tags_text, done_button]),
+            (
+                row_custom_tag,
+                vec![
+                    custom_tag_label,
+                    custom_tag_text_input,
+                    toggle_custom_tag_button,
+                ],
+            ),
+        ]);
+
+        for (parent_widget_id, child_widget_ids) in children {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(TagPickerView {
+            column,
+            custom_tag: String::new(),
+            custom_tag_text_input,
+            custom_tags_text,
+            date: None,
+            done_button,
+            feeling_buttons,
+            localizer,
+            picked_fill: PaintBrush::Color(theme.grid_color.color()),
+            title_text,
+            toggle_custom_tag_button,
+            unpicked_fill: PaintBrush::Color(theme.button_color.color()),
+        })
+    }
+}
+
+impl View for TagPickerView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        if let ApplicationCommand::PickTags(date) = application_command {
+            self.date = Some(date);
+        }
+
+        let Some(date) = self.date else {
+            return Ok(());
+        };
+
+        let tags = application_data.tags_per_date.get(&date);
+        let is_picked = |tag: &str| tags.is_some_and(|tags| tags.contains(tag));
+
+        let custom_tags: Vec<String> = tags
+            .into_iter()
+            .flatten()
+            .filter(|tag| !is_feeling(tag))
+            .cloned()
+            .collect();
+
+        let mut commands = vec![
+            Command::SetValue(
+                self.title_text,
+                Box::new(self.localizer.text_with(
+                    "tag-picker-title",
+                    &[("date", self.localizer.date(date).into())],
+                )),
+            ),
+            Command::SetValue(
+                self.custom_tags_text,
+                Box::new(self.localizer.text_with(
+                    "tag-picker-custom-tags",
+                    &[
+                        ("count", custom_tags.len().into()),
+                        ("tags", self.localizer.list(&custom_tags).into()),
+                    ],
+                )),
+            ),
+        ];
+
+        // Highlight the day's feelings.
+        for (button, tag) in &self.feeling_buttons {
+            let fill = if is_picked(tag) {
+                &self.picked_fill
+            } else {
+                &self.unpicked_fill
+            };
+
+            commands.push(Command::SetFill(*button, Some(fill.clone())));
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+
+    fn handle_event(
+        &mut self,
+        _application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        let Some(date) = self.date else {
+            return Ok(None);
+        };
+
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // A feeling's button was clicked.
+                    if let Some((_, tag)) = self
+                        .feeling_buttons
+                        .iter()
+                        .find(|(button, _)| button == widget_id)
+                    {
+                        return Ok(Some(ApplicationCommand::ToggleTag {
+                            date,
+                            tag: tag.to_string(),
+                        }));
+                    }
+                    // The toggle button was clicked and there is a custom tag.
+                    else if *widget_id == self.toggle_custom_tag_button
+                        && !self.custom_tag.trim().is_empty()
+                    {
+                        let tag = std::mem::take(&mut self.custom_tag);
+
+                        widget_manager.send_command(Command::SetValue(
+                            self.custom_tag_text_input,
+                            Box::new(String::new()),
+                        ))?;
+
+                        return Ok(Some(ApplicationCommand::ToggleTag {
+                            date,
+                            tag: tag.trim().to_string(),
+                        }));
+                    }
+                    // The done button was clicked.
+                    else if *widget_id == self.done_button {
+                        return Ok(Some(ApplicationCommand::ShowDashboard));
+                    }
+                }
+                // The custom tag was changed.
+                WidgetEvent::ValueChanged(widget_id, value)
+                    if *widget_id == self.custom_tag_text_input =>
+                {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        self.custom_tag = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,211 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::{Datelike, Local};
+use freundchen_core::data::ApplicationData;
+use freundchen_core::feelings::{is_feeling, FEELINGS_WHEEL};
+use freundchen_core::mood::number_of_days_in_month;
+use freundchen_core::statistics::tag_frequencies;
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The number of the most frequent tags in the chart.
+const NUMBER_OF_CHART_LINES: usize = 10;
+
+/// The width of the text input.
+const TEXT_INPUT_WIDTH: f64 = 200.0;
+
+/// Returns the name of the tag: the translated name of a feeling or the custom tag itself.
+pub(crate) fn tag_name(localizer: &Localizer, tag: &str) -> String {
+    if is_feeling(tag) {
+        localizer.text(&format!("feeling-{}", tag))
+    } else {
+        tag.to_string()
+    }
+}
+
+// =================================================================================================
+
+/// A view charting how often the days of the current month were tagged with each tag. It offers to
+/// show only the days with a tag in the mood widget.
+pub(crate) struct TagsView {
+    chart_texts: Vec<WidgetId>,
+    column: WidgetId,
+    filter_button: WidgetId,
+    filter_tag: String,
+    filter_text_input: WidgetId,
+    localizer: Rc<Localizer>,
+    show_all_button: WidgetId,
+    title_text: WidgetId,
+}
+
+impl TagsView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text("");
+        let chart_texts: Vec<WidgetId> = (0..NUMBER_OF_CHART_LINES)
+            .map(|_| widget_manager.new_text(""))
+            .collect();
+        let row_filter = widget_manager.new_row();
+        let filter_label = widget_manager.new_text(localizer.text("tags-filter-label"));
+        let filter_text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+        let filter_button = widget_manager.new_text_button(localizer.text("tags-filter"));
+        let show_all_button = widget_manager.new_text_button(localizer.text("tags-show-all"));
+
+        // Apply the theme.
+        let mut commands = theme.text_commands(&[filter_label]);
+        commands.extend(theme.text_commands(&chart_texts));
+        commands.extend(theme.button_commands(&[filter_button, show_all_button]));
+        commands.push(Command::SetFont(title_text, theme.title_font()));
+
+        for (parent_widget_id, child_widget_ids) in [
+            (column, vec![title_text]),
+            (column, chart_texts.clone()),
+            (column, vec![row_filter]),
+            (
+                row_filter,
+                vec![
+                    filter_label,
+                    filter_text_input,
+                    filter_button,
+                    show_all_button,
+                ],
+            ),
+        ] {
+            for child_widget_id in child_widget_ids {
+                commands.push(Command::AddChild {
+                    parent_widget_id,
+                    widget_placement: None,
+                    child_widget_id,
+                });
+            }
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(TagsView {
+            chart_texts,
+            column,
+            filter_button,
+            filter_tag: String::new(),
+            filter_text_input,
+            localizer,
+            show_all_button,
+            title_text,
+        })
+    }
+
+    /// Returns the tag with the given name: the feeling with the translated name or a custom tag.
+    fn tag_with_name(&self, name: &str) -> String {
+        let name = name.trim();
+
+        FEELINGS_WHEEL
+            .iter()
+            .flat_map(|feeling| std::iter::once(feeling.name).chain(feeling.sub_feelings.to_vec()))
+            .find(|tag| {
+                tag.eq_ignore_ascii_case(name)
+                    || tag_name(&self.localizer, tag).to_lowercase() == name.to_lowercase()
+            })
+            .map_or_else(|| name.to_string(), str::to_string)
+    }
+}
+
+impl View for TagsView {
+    fn activate(
+        &mut self,
+        _application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let today = Local::now().naive_local().date();
+        let first_date = today.with_day(1).unwrap_or(today);
+        let last_date = first_date
+            .with_day(number_of_days_in_month(first_date) as u32)
+            .unwrap_or(today);
+
+        let tag_frequencies =
+            tag_frequencies(&application_data.tags_per_date, first_date, last_date);
+
+        // Chart each tag's frequency as a bar.
+        let mut lines: Vec<String> = tag_frequencies
+            .iter()
+            .take(NUMBER_OF_CHART_LINES)
+            .map(|(tag, frequency)| {
+                format!(
+                    "{} {} {}",
+                    tag_name(&self.localizer, tag),
+                    "█".repeat(*frequency),
+                    frequency
+                )
+            })
+            .collect();
+
+        if lines.is_empty() {
+            lines.push(self.localizer.text("tags-none"));
+        }
+
+        let mut commands = vec![Command::SetValue(
+            self.title_text,
+            Box::new(self.localizer.text_with(
+                "tags-title",
+                &[("month", self.localizer.month_year(today).into())],
+            )),
+        )];
+
+        for (index, chart_text) in self.chart_texts.iter().enumerate() {
+            commands.push(Command::SetValue(
+                *chart_text,
+                Box::new(lines.get(index).cloned().unwrap_or_default()),
+            ));
+        }
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(())
+    }
+
+    fn handle_event(
+        &mut self,
+        _application_data: &ApplicationData,
+        _widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The filter button was clicked and there is a tag.
+                    if *widget_id == self.filter_button && !self.filter_tag.trim().is_empty() {
+                        return Ok(Some(ApplicationCommand::FilterByTag(Some(
+                            self.tag_with_name(&self.filter_tag),
+                        ))));
+                    }
+                    // The show all button was clicked.
+                    else if *widget_id == self.show_all_button {
+                        return Ok(Some(ApplicationCommand::FilterByTag(None)));
+                    }
+                }
+                // The tag to filter by was changed.
+                WidgetEvent::ValueChanged(widget_id, value)
+                    if *widget_id == self.filter_text_input =>
+                {
+                    if let Some(text) = value.downcast_ref::<String>() {
+                        self.filter_tag = text.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
@@ -0,0 +1,481 @@
+use crate::i18n::Localizer;
+use crate::theme::Theme;
+use crate::view::{ApplicationCommand, View};
+use crate::ApplicationError;
+use chrono::NaiveDate;
+use freundchen_core::data::{ApplicationData, ThoughtRecord, MAXIMUM_EMOTION_INTENSITY};
+use guiver::{Command, WidgetEvent, WidgetId, WidgetManager};
+use std::rc::Rc;
+
+/// The number of lines a thought record is shown with: the header, a line per written field and the
+/// mood values of its day.
+const NUMBER_OF_RECORD_LINES: usize = 8;
+
+/// The width of the text inputs.
+const TEXT_INPUT_WIDTH: f64 = 400.0;
+
+/// The fields of a thought record, in the order they are filled in.
+#[derive(Clone, Copy)]
+enum Field {
+    Situation,
+    AutomaticThought,
+    Emotion,
+    EmotionIntensity,
+    EvidenceFor,
+    EvidenceAgainst,
+    BalancedThought,
+    EmotionIntensityRerated,
+}
+
+impl Field {
+    const ALL: [Field; 8] = [
+        Field::Situation,
+        Field::AutomaticThought,
+        Field::Emotion,
+        Field::EmotionIntensity,
+        Field::EvidenceFor,
+        Field::EvidenceAgainst,
+        Field::BalancedThought,
+        Field::EmotionIntensityRerated,
+    ];
+
+    /// The ID of the field's label message.
+    fn label_message_id(&self) -> &'static str {
+        match self {
+            Field::Situation => "thought-record-situation",
+            Field::AutomaticThought => "thought-record-automatic-thought",
+            Field::Emotion => "thought-record-emotion",
+            Field::EmotionIntensity => "thought-record-emotion-intensity",
+            Field::EvidenceFor => "thought-record-evidence-for",
+            Field::EvidenceAgainst => "thought-record-evidence-against",
+            Field::BalancedThought => "thought-record-balanced-thought",
+            Field::EmotionIntensityRerated => "thought-record-emotion-intensity-rerated",
+        }
+    }
+}
+
+// =================================================================================================
+
+/// A view to fill in thought records and to browse the past ones.
+pub(crate) struct ThoughtRecordView {
+    column: WidgetId,
+    /// The day a new record refers to.
+    date: Option<NaiveDate>,
+    field_text_inputs: Vec<WidgetId>,
+    field_values: Vec<String>,
+    localizer: Rc<Localizer>,
+    next_button: WidgetId,
+    previous_button: WidgetId,
+    record_texts: Vec<WidgetId>,
+    save_button: WidgetId,
+    selected_record_index: Option<usize>,
+    status_text: WidgetId,
+    title_text: WidgetId,
+}
+
+impl ThoughtRecordView {
+    /// Creates the view's widgets.
+    pub(crate) fn new(
+        localizer: Rc<Localizer>,
+        theme: &Theme,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<Self, ApplicationError> {
+        let column = widget_manager.new_column();
+        let title_text = widget_manager.new_text("");
+        let row_save = widget_manager.new_row();
+        let save_button = widget_manager.new_text_button(localizer.text("save"));
+        let status_text = widget_manager.new_text("");
+        let past_records_text =
+            widget_manager.new_text(localizer.text("thought-record-past-records"));
+        let row_browse = widget_manager.new_row();
+        let previous_button = widget_manager.new_text_button(localizer.text("previous"));
+        let next_button = widget_manager.new_text_button(localizer.text("next"));
+
+        let title_font = theme.title_font();
+
+        let mut commands = vec![
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: title_text,
+            },
+            Command::SetFont(title_text, title_font.clone()),
+        ];
+
+        // Add a row with a label and a text input per field.
+        let mut field_text_inputs = vec![];
+        let mut texts = vec![status_text];
+
+        for field in Field::ALL {
+            let row = widget_manager.new_row();
+            let label_text = widget_manager.new_text(localizer.text(field.label_message_id()));
+            let text_input = widget_manager.new_text_input("", TEXT_INPUT_WIDTH);
+
+            commands.extend([
+                Command::AddChild {
+                    parent_widget_id: column,
+                    widget_placement: None,
+                    child_widget_id: row,
+                },
+                Command::AddChild {
+                    parent_widget_id: row,
+                    widget_placement: None,
+                    child_widget_id: label_text,
+                },
+                Command::AddChild {
+                    parent_widget_id: row,
+                    widget_placement: None,
+                    child_widget_id: text_input,
+                },
+            ]);
+
+            field_text_inputs.push(text_input);
+            texts.push(label_text);
+        }
+
+        commands.extend([
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: row_save,
+            },
+            Command::AddChild {
+                parent_widget_id: row_save,
+                widget_placement: None,
+                child_widget_id: save_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_save,
+                widget_placement: None,
+                child_widget_id: status_text,
+            },
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: past_records_text,
+            },
+            Command::SetFont(past_records_text, title_font),
+            Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: row_browse,
+            },
+            Command::AddChild {
+                parent_widget_id: row_browse,
+                widget_placement: None,
+                child_widget_id: previous_button,
+            },
+            Command::AddChild {
+                parent_widget_id: row_browse,
+                widget_placement: None,
+                child_widget_id: next_button,
+            },
+        ]);
+
+        // Add a text per line of the selected record.
+        let mut record_texts = vec![];
+
+        for _ in 0..NUMBER_OF_RECORD_LINES {
+            let record_text = widget_manager.new_text("");
+
+            commands.push(Command::AddChild {
+                parent_widget_id: column,
+                widget_placement: None,
+                child_widget_id: record_text,
+            });
+
+            record_texts.push(record_text);
+        }
+
+        texts.extend(&record_texts);
+
+        // Apply the theme.
+        commands.extend(theme.text_commands(&texts));
+        commands.extend(theme.button_commands(&[save_button, previous_button, next_button]));
+
+        widget_manager.send_commands(commands)?;
+
+        Ok(ThoughtRecordView {
+            column,
+            date: None,
+            field_values: vec![String::new(); field_text_inputs.len()],
+            field_text_inputs,
+            localizer,
+            next_button,
+            previous_button,
+            record_texts,
+            save_button,
+            selected_record_index: None,
+            status_text,
+            title_text,
+        })
+    }
+
+    /// Clears the text inputs.
+    fn clear_text_inputs(
+        &mut self,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        for field_value in self.field_values.iter_mut() {
+            field_value.clear();
+        }
+
+        widget_manager.send_commands(
+            self.field_text_inputs
+                .iter()
+                .map(|text_input| Command::SetValue(*text_input, Box::new(String::new())))
+                .collect(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates a thought record of the given day from the text inputs.
+    fn thought_record(&self, date: NaiveDate) -> Result<ThoughtRecord, String> {
+        let field_value = |field: Field| self.field_values[field as usize].trim().to_string();
+
+        let parse_intensity = |field: Field| {
+            field_value(field)
+                .parse::<u8>()
+                .ok()
+                .filter(|intensity| *intensity <= MAXIMUM_EMOTION_INTENSITY)
+                .ok_or_else(|| {
+                    self.localizer.text_with(
+                        "thought-record-intensity-invalid",
+                        &[
+                            (
+                                "field",
+                                self.localizer.text(field.label_message_id()).into(),
+                            ),
+                            ("maximum", MAXIMUM_EMOTION_INTENSITY.into()),
+                        ],
+                    )
+                })
+        };
+
+        // An automatic thought is required.
+        if field_value(Field::AutomaticThought).is_empty() {
+            return Err(self
+                .localizer
+                .text("thought-record-automatic-thought-missing"));
+        }
+
+        Ok(ThoughtRecord {
+            date,
+            situation: field_value(Field::Situation),
+            automatic_thought: field_value(Field::AutomaticThought),
+            emotion: field_value(Field::Emotion),
+            emotion_intensity: parse_intensity(Field::EmotionIntensity)?,
+            evidence_for: field_value(Field::EvidenceFor),
+            evidence_against: field_value(Field::EvidenceAgainst),
+            balanced_thought: field_value(Field::BalancedThought),
+            emotion_intensity_rerated: parse_intensity(Field::EmotionIntensityRerated)?,
+        })
+    }
+
+    /// Shows the selected thought record.
+    fn update_record_texts(
+        &self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let localizer = &self.localizer;
+        let mut lines = vec![];
+
+        match self
+            .selected_record_index
+            .and_then(|index| Some((index, application_data.thought_records.get(index)?)))
+        {
+            None => {
+                lines.push(localizer.text("thought-record-none"));
+            }
+            Some((index, thought_record)) => {
+                lines.push(localizer.text_with(
+                    "thought-record-header",
+                    &[
+                        ("date", localizer.date(thought_record.date).into()),
+                        ("index", (index + 1).into()),
+                        ("count", application_data.thought_records.len().into()),
+                    ],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-situation",
+                    &[("situation", thought_record.situation.as_str().into())],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-automatic-thought",
+                    &[(
+                        "automatic-thought",
+                        thought_record.automatic_thought.as_str().into(),
+                    )],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-emotion",
+                    &[
+                        ("emotion", thought_record.emotion.as_str().into()),
+                        ("intensity", thought_record.emotion_intensity.into()),
+                        (
+                            "intensity-rerated",
+                            thought_record.emotion_intensity_rerated.into(),
+                        ),
+                    ],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-evidence-for",
+                    &[("evidence", thought_record.evidence_for.as_str().into())],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-evidence-against",
+                    &[("evidence", thought_record.evidence_against.as_str().into())],
+                ));
+                lines.push(localizer.text_with(
+                    "thought-record-show-balanced-thought",
+                    &[(
+                        "balanced-thought",
+                        thought_record.balanced_thought.as_str().into(),
+                    )],
+                ));
+
+                // Link the record to the mood values of that day.
+                if let Some(mood_values) = application_data.mood_per_date.get(&thought_record.date)
+                {
+                    let mut mood_values: Vec<_> = mood_values.iter().collect();
+                    mood_values.sort();
+
+                    lines.push(
+                        localizer.text_with(
+                            "thought-record-show-mood",
+                            &[(
+                                "mood-values",
+                                localizer
+                                    .list(
+                                        &mood_values
+                                            .iter()
+                                            .map(|mood_value| mood_value.to_string())
+                                            .collect::<Vec<_>>(),
+                                    )
+                                    .into(),
+                            )],
+                        ),
+                    );
+                }
+            }
+        }
+
+        lines.resize(self.record_texts.len(), String::new());
+
+        widget_manager.send_commands(
+            self.record_texts
+                .iter()
+                .zip(lines)
+                .map(|(record_text, line)| Command::SetValue(*record_text, Box::new(line)))
+                .collect(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl View for ThoughtRecordView {
+    fn activate(
+        &mut self,
+        application_command: ApplicationCommand,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+    ) -> Result<(), ApplicationError> {
+        let ApplicationCommand::ShowThoughtRecords(date) = application_command else {
+            return Ok(());
+        };
+
+        self.date = Some(date);
+
+        widget_manager.send_command(Command::SetValue(
+            self.title_text,
+            Box::new(self.localizer.text_with(
+                "thought-record-title",
+                &[("date", self.localizer.date(date).into())],
+            )),
+        ))?;
+
+        // Select the most recent thought record.
+        self.selected_record_index = application_data.thought_records.len().checked_sub(1);
+
+        self.update_record_texts(application_data, widget_manager)
+    }
+
+    fn handle_event(
+        &mut self,
+        application_data: &ApplicationData,
+        widget_manager: &mut WidgetManager<()>,
+        widget_events: &[WidgetEvent],
+    ) -> Result<Option<ApplicationCommand>, ApplicationError> {
+        let Some(date) = self.date else {
+            return Ok(None);
+        };
+
+        for widget_event in widget_events {
+            match widget_event {
+                WidgetEvent::Clicked(widget_id) => {
+                    // The save button was clicked.
+                    if *widget_id == self.save_button {
+                        return match self.thought_record(date) {
+                            Ok(thought_record) => {
+                                self.clear_text_inputs(widget_manager)?;
+                                widget_manager.send_command(Command::SetValue(
+                                    self.status_text,
+                                    Box::new(self.localizer.text("saved")),
+                                ))?;
+
+                                Ok(Some(ApplicationCommand::AddThoughtRecord(thought_record)))
+                            }
+                            Err(message) => {
+                                widget_manager.send_command(Command::SetValue(
+                                    self.status_text,
+                                    Box::new(message),
+                                ))?;
+
+                                Ok(None)
+                            }
+                        };
+                    }
+                    // The previous button was clicked.
+                    else if *widget_id == self.previous_button {
+                        if let Some(index) = self.selected_record_index {
+                            self.selected_record_index = Some(index.saturating_sub(1));
+                        }
+
+                        self.update_record_texts(application_data, widget_manager)?;
+                    }
+                    // The next button was clicked.
+                    else if *widget_id == self.next_button {
+                        if let Some(index) = self.selected_record_index {
+                            self.selected_record_index =
+                                Some((index + 1).min(application_data.thought_records.len() - 1));
+                        }
+
+                        self.update_record_texts(application_data, widget_manager)?;
+                    }
+                }
+                WidgetEvent::ValueChanged(widget_id, value) => {
+                    // A text input's value has changed.
+                    if let Some(field_index) = self
+                        .field_text_inputs
+                        .iter()
+                        .position(|text_input| text_input == widget_id)
+                    {
+                        if let Some(text) = value.downcast_ref::<String>() {
+                            self.field_values[field_index] = text.clone();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn main_widget_id(&self) -> &WidgetId {
+        &self.column
+    }
+}
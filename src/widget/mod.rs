@@ -1,3 +1,72 @@
 mod mood;
+mod sleep;
 
-pub use mood::{MoodValuesUpdate, MoodWidget};
+pub use mood::{
+    MoodCellClicked, MoodCellRightClicked, MoodDayFilter, MoodMarkers, MoodSelection, MoodWidget,
+    MoodWidgetStyle, MOOD_WIDGET_HEIGHT,
+};
+pub use sleep::{
+    SleepMonth, SleepNightClicked, SleepWidget, SleepWidgetStyle, SLEEP_WIDGET_HEIGHT,
+};
+
+use guiver::{Error, Font, RenderContext};
+use piet_common::{Text, TextLayoutBuilder};
+use std::any::Any;
+use std::cell::RefCell;
+
+/// Lays out the given text in the given font with the render context's text backend, so that the
+/// widgets can be painted to any render context, e.g. to an SVG file.
+pub(crate) fn text_layout<R: RenderContext>(
+    piet: &mut R,
+    font: &Font,
+    text: String,
+) -> Result<R::TextLayout, Error> {
+    piet.text()
+        .new_text_layout(text)
+        .font(font.font_family.clone(), font.font_size)
+        .text_color(font.font_color.clone())
+        .default_attribute(font.font_weight)
+        .build()
+}
+
+/// Caches the text layouts of labels, so that they are not laid out anew with every paint. The
+/// layouts of one text backend cannot be drawn by another, so they are laid out anew when the
+/// labels are painted to another kind of render context. The labels are created anew when their
+/// texts or font change.
+#[derive(Default)]
+pub(crate) struct TextLayoutCache {
+    /// The `Vec` of the text layouts of the render context they were laid out with.
+    text_layouts: RefCell<Option<Box<dyn Any>>>,
+}
+
+impl TextLayoutCache {
+    /// Returns the layouts of the given texts in the given font, laid out with the render
+    /// context's text backend, if they were not yet.
+    pub(crate) fn text_layouts<R: RenderContext>(
+        &self,
+        piet: &mut R,
+        font: &Font,
+        texts: &[String],
+    ) -> Result<Vec<R::TextLayout>, Error>
+    where
+        R::TextLayout: 'static,
+    {
+        let mut cached_text_layouts = self.text_layouts.borrow_mut();
+
+        if let Some(text_layouts) = cached_text_layouts
+            .as_ref()
+            .and_then(|text_layouts| text_layouts.downcast_ref::<Vec<R::TextLayout>>())
+        {
+            return Ok(text_layouts.clone());
+        }
+
+        let text_layouts = texts
+            .iter()
+            .map(|text| text_layout(piet, font, text.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        *cached_text_layouts = Some(Box::new(text_layouts.clone()));
+
+        Ok(text_layouts)
+    }
+}
@@ -0,0 +1,266 @@
+//! Renders the mood widget and its labels into offscreen bitmaps and compares them with the
+//! reference images in `goldens/`. Run `./update_goldens.sh` to write the reference images anew
+//! after an intended change, and check them before committing.
+
+use super::month_labels::MonthLabels;
+use super::mood_value_labels::MoodValueLabels;
+use super::*;
+use piet_common::{Device, ImageFormat};
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that makes the tests write the reference images instead of comparing.
+const UPDATE_GOLDENS_VARIABLE: &str = "FREUNDCHEN_UPDATE_GOLDENS";
+
+/// The maximum difference of a colour channel, so that the pixels are still considered equal, e.g.
+/// due to a different anti-aliasing.
+const CHANNEL_TOLERANCE: u8 = 16;
+
+/// The maximum share of differing pixels, e.g. due to a different hinting of the font.
+const MAXIMUM_DIFFERING_PIXEL_RATIO: f64 = 0.01;
+
+/// The size of a mood cell.
+const CELL_SIZE: Size = Size::new(20.0, 20.0);
+
+/// Returns the path of the reference image with the given name.
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/widget/mood/goldens")
+        .join(format!("{}.png", name))
+}
+
+/// Reads the RGBA pixels of a PNG file.
+fn read_png(path: &Path) -> (u32, u32, Vec<u8>) {
+    let decoder = png::Decoder::new(File::open(path).unwrap_or_else(|error| {
+        panic!(
+            "Could not open \"{}\", run `./update_goldens.sh` to create it: {}",
+            path.display(),
+            error
+        )
+    }));
+    let mut reader = decoder.read_info().expect("the golden should be a PNG");
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut pixels)
+        .expect("the golden should have a frame");
+
+    assert_eq!(
+        (info.color_type, info.bit_depth),
+        (png::ColorType::Rgba, png::BitDepth::Eight),
+        "\"{}\" should have 8 bit RGBA pixels",
+        path.display()
+    );
+
+    pixels.truncate(info.buffer_size());
+
+    (info.width, info.height, pixels)
+}
+
+/// Writes RGBA pixels to a PNG file.
+fn write_png(path: &Path, width: u32, height: u32, pixels: &[u8]) {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory).expect("the directory should be created");
+    }
+
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(path).expect("the PNG file should be created")),
+        width,
+        height,
+    );
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(pixels))
+        .expect("the PNG file should be written");
+}
+
+/// Paints into an offscreen bitmap of the given size on a white background and compares the result
+/// with the reference image of the given name.
+fn assert_matches_golden(
+    name: &str,
+    size: Size,
+    paint: impl FnOnce(&mut Piet, &Region) -> Result<(), Error>,
+) {
+    let width = size.width as usize;
+    let height = size.height as usize;
+
+    let mut device = Device::new().expect("the device should be created");
+    let mut bitmap_target = device
+        .bitmap_target(width, height, 1.0)
+        .expect("the bitmap target should be created");
+
+    {
+        let mut piet = bitmap_target.render_context();
+
+        let mut region = Region::EMPTY;
+        region.add_rect(size.to_rect());
+
+        piet.fill(size.to_rect(), &PaintBrush::Color(Color::WHITE));
+        paint(&mut piet, &region).expect("painting should succeed");
+        piet.finish().expect("painting should finish");
+    }
+
+    // The Cairo backend only provides premultiplied pixels. They equal the separate ones, since the
+    // background is opaque.
+    let image_buf = bitmap_target
+        .to_image_buf(ImageFormat::RgbaPremul)
+        .expect("the pixels should be read");
+    let pixels = image_buf.raw_pixels();
+
+    let golden_path = golden_path(name);
+
+    // Write the reference image instead of comparing with it.
+    if env::var_os(UPDATE_GOLDENS_VARIABLE).is_some() {
+        write_png(&golden_path, width as u32, height as u32, pixels);
+        return;
+    }
+
+    let (golden_width, golden_height, golden_pixels) = read_png(&golden_path);
+
+    assert_eq!(
+        (golden_width as usize, golden_height as usize),
+        (width, height),
+        "\"{}\" has a different size",
+        golden_path.display()
+    );
+
+    let number_of_differing_pixels = pixels
+        .chunks_exact(4)
+        .zip(golden_pixels.chunks_exact(4))
+        .filter(|(pixel, golden_pixel)| {
+            pixel
+                .iter()
+                .zip(golden_pixel.iter())
+                .any(|(channel, golden_channel)| {
+                    channel.abs_diff(*golden_channel) > CHANNEL_TOLERANCE
+                })
+        })
+        .count();
+
+    let differing_pixel_ratio = number_of_differing_pixels as f64 / (width * height) as f64;
+
+    if differing_pixel_ratio > MAXIMUM_DIFFERING_PIXEL_RATIO {
+        let actual_path = env::temp_dir().join(format!("freundchen-{}.png", name));
+        write_png(&actual_path, width as u32, height as u32, pixels);
+
+        panic!(
+            "{} of {} pixels differ from \"{}\", the rendering was written to \"{}\"",
+            number_of_differing_pixels,
+            width * height,
+            golden_path.display(),
+            actual_path.display()
+        );
+    }
+}
+
+/// Returns a black font, which is readable on the white background.
+fn font() -> Font {
+    Font {
+        font_color: Color::rgb8(0, 0, 0),
+        ..Default::default()
+    }
+}
+
+/// Returns a style with distinct colours.
+fn mood_widget_style() -> MoodWidgetStyle {
+    MoodWidgetStyle {
+        fills_per_mood_value_index: std::array::from_fn(|mood_value| {
+            PaintBrush::Color(Color::rgb8(
+                255 - 25 * mood_value as u8,
+                25 * mood_value as u8,
+                64,
+            ))
+        }),
+        grid_color: Color::rgb8(128, 128, 128),
+        text_color: Color::rgb8(0, 0, 0),
+    }
+}
+
+/// Creates a mood widget for a month with 30 days, some mood values and the labels on the fifth
+/// day.
+fn mood_widget() -> MoodWidget {
+    let mut mood_widget = MoodWidget::new(
+        0,
+        Stroke {
+            stroke_brush: PaintBrush::Color(Color::rgb8(255, 0, 0)),
+            stroke_style: Default::default(),
+            stroke_width: 1.0,
+        },
+        (1..=30).map(|day| day.to_string()).collect(),
+        mood_widget_style(),
+    );
+
+    let mut mood_month = MoodMonth::new(30);
+    mood_month.set_mood_values(0, [0].into());
+    mood_month.set_mood_values(4, [3, 7].into());
+    mood_month.set_mood_values(14, [5].into());
+    mood_month.set_mood_values(29, [10].into());
+
+    mood_widget
+        .set_value(Box::new(mood_month))
+        .expect("the mood month should be set");
+    mood_widget.set_labelled_day_of_month_index(Some(4));
+
+    mood_widget.apply_size_constraints(SizeConstraints::new(
+        Size::ZERO,
+        Size::new(600.0, MOOD_WIDGET_HEIGHT),
+    ));
+    mood_widget.set_origin(Point::ZERO);
+
+    mood_widget
+}
+
+// =================================================================================================
+
+#[test]
+fn golden_mood_widget() {
+    let mood_widget = mood_widget();
+
+    assert_matches_golden(
+        "mood_widget",
+        Size::new(600.0, MOOD_WIDGET_HEIGHT),
+        |piet, region| mood_widget.paint(piet, region),
+    );
+}
+
+#[test]
+fn golden_mood_widget_disabled() {
+    let mut mood_widget = mood_widget();
+    mood_widget.set_is_disabled(true);
+
+    assert_matches_golden(
+        "mood_widget_disabled",
+        Size::new(600.0, MOOD_WIDGET_HEIGHT),
+        |piet, region| mood_widget.paint(piet, region),
+    );
+}
+
+#[test]
+fn golden_month_labels() {
+    let month_labels = MonthLabels::new(font(), (1..=31).map(|day| day.to_string()).collect());
+
+    assert_matches_golden(
+        "month_labels",
+        Size::new(31.0 * CELL_SIZE.width, CELL_SIZE.height),
+        |piet, region| month_labels.paint(piet, region, 0.0, 0.0, CELL_SIZE),
+    );
+}
+
+#[test]
+fn golden_mood_value_labels() {
+    let mood_value_labels = MoodValueLabels::new(font(), NUMBER_OF_MOOD_VALUES_PER_DAY);
+    let height = NUMBER_OF_MOOD_VALUES_PER_DAY as f64 * CELL_SIZE.height;
+
+    // The labels are painted upwards from the cell of the lowest mood value.
+    assert_matches_golden(
+        "mood_value_labels",
+        Size::new(CELL_SIZE.width, height),
+        |piet, region| {
+            mood_value_labels.paint(piet, region, 0.0, height - CELL_SIZE.height, CELL_SIZE)
+        },
+    );
+}
@@ -1,120 +1,179 @@
+#[cfg(test)]
+mod golden_tests;
 mod month_labels;
 mod mood_value_labels;
+#[cfg(test)]
+mod tests;
 
 use crate::widget::mood::month_labels::MonthLabels;
 use crate::widget::mood::mood_value_labels::MoodValueLabels;
 use crate::{Piet, Region, Size};
 use chrono::{Datelike, Local};
+use freundchen_core::mood::{MoodMonth, MoodValue, NUMBER_OF_MOOD_VALUES_PER_DAY};
 use guiver::widget::WidgetError;
 use guiver::{
-    Color, Error, Event, Font, Line, PaintBrush, Point, Rect, RenderContext, SizeConstraints,
-    Stroke, Widget, WidgetCore, WidgetEvent, WidgetId,
+    Color, Error, Event, Font, Line, MouseButton, PaintBrush, Point, Rect, RenderContext,
+    SizeConstraints, Stroke, Widget, WidgetCore, WidgetEvent, WidgetId,
 };
 use std::any::Any;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
+use std::ops::RangeInclusive;
 use std::usize;
 
-/// The mood value datatype.
-type MoodValue = u8;
+/// The height of the mood widget.
+pub const MOOD_WIDGET_HEIGHT: f64 = 200.0;
 
-/// The number of mood values per day.
-const NUMBER_OF_MOOD_VALUES_PER_DAY: MoodValue = 11;
+/// The height of the month labels above the mood cells.
+pub const MONTH_LABELS_SPACING: f64 = 20.0;
 
-///
+/// Sent with `WidgetEvent::ValueChanged` when a mood cell was clicked. The widget does not change
+/// its mood values itself, they are set with a `MoodMonth`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoodCellClicked {
+    pub day_of_month_index: u8,
+    pub mood_value: MoodValue,
+}
+
+/// Sent with `WidgetEvent::ValueChanged` when a mood cell was clicked with the right mouse button,
+/// e.g. to open a menu for the day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoodCellRightClicked {
+    pub day_of_month_index: u8,
+    pub mood_value: MoodValue,
+}
+
+/// The days that match a filter, e.g. that are tagged with a feeling. Set with `set_value()`, the
+/// other days are greyed out. `None` shows all days.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoodDayFilter {
+    pub day_of_month_indices: Option<HashSet<u8>>,
+}
+
+/// The days that changed something, e.g. a medication was started, stopped or its dose changed.
+/// Set with `set_value()`, each day gets a vertical line on its left edge.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MoodMarkers {
+    pub day_of_month_indices: BTreeSet<u8>,
+}
+
+/// The selected range of days. Sent with `WidgetEvent::ValueChanged` when the range was selected
+/// with shift-click or shift-drag, or when it was deselected with a click outside of it. It can also
+/// be set with `set_value()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoodSelection {
+    pub day_of_month_indices: Option<RangeInclusive<u8>>,
+}
+
+// =================================================================================================
+
+/// The colours of a mood widget.
 #[derive(Clone, Debug)]
-pub enum MoodValuesUpdate {
-    Clear,
-    Update {
-        day_of_month_index: u8,
-        mood_values: HashSet<MoodValue>,
-    },
+pub struct MoodWidgetStyle {
+    /// The fills per mood value, from the darkest to the brightest.
+    pub fills_per_mood_value_index: [PaintBrush; NUMBER_OF_MOOD_VALUES_PER_DAY as usize],
+    /// The colour of the grid and the month labels.
+    pub grid_color: Color,
+    /// The colour of the mood value labels.
+    pub text_color: Color,
 }
 
 // =================================================================================================
 
-/// A widget that displays mood values for each day of a month.
+/// A widget that displays the mood values for each day of a month.
 pub struct MoodWidget {
     core: WidgetCore,
-    day_of_month_index_today: usize,
+    /// The days that match the filter, the others are greyed out. `None` shows all days.
+    filtered_day_of_month_indices: Option<HashSet<u8>>,
+    /// The day of month index whose column shows the mood value labels.
+    labelled_day_of_month_index: Option<usize>,
+    /// The days marked with a vertical line.
+    marked_day_of_month_indices: BTreeSet<u8>,
+    /// The stroke of the markers, in the colour of the mood value labels.
+    marker_stroke: Stroke,
     fills_per_mood_value_index: [PaintBrush; NUMBER_OF_MOOD_VALUES_PER_DAY as usize],
     month_labels: MonthLabels,
     month_labels_spacing: f64,
     mood_cell_size: Size,
     mood_cells_area_rectangle: Rect,
+    mood_month: MoodMonth,
     mood_value_labels: MoodValueLabels,
-    mood_values_per_day_of_month_index: Vec<HashSet<MoodValue>>,
+    mood_value_labels_font: Font,
     number_of_days_in_month: u8,
     mood_cells_grid_stroke: Stroke,
+    /// Whether the selection is extended by moving the mouse.
+    is_selecting: bool,
+    /// The day of month index the selection is extended from with shift-click.
+    selection_anchor: Option<u8>,
+    /// The fill painted over the selected days.
+    selection_brush: PaintBrush,
+    selected_day_of_month_indices: Option<RangeInclusive<u8>>,
 }
 
 impl MoodWidget {
-    ///
-    pub fn new(widget_id: WidgetId, debug_rendering_stroke: Stroke) -> Self {
+    /// Creates a mood widget. The month labels are given per day of the month.
+    pub fn new(
+        widget_id: WidgetId,
+        debug_rendering_stroke: Stroke,
+        day_of_month_labels: Vec<String>,
+        style: MoodWidgetStyle,
+    ) -> Self {
         // TODO: Use 31 or adjust to the current month?
-        let number_of_days_in_month = 31;
+        let number_of_days_in_month = day_of_month_labels.len() as u8;
 
-        // Determine the day of month.
-        let day_of_month_index_today = Local::today().naive_local().day0() as usize;
-
-        let grid_color = Color::rgb8(100, 100, 100);
+        // Label today's column.
+        let labelled_day_of_month_index = Some(Local::today().naive_local().day0() as usize);
 
         let month_labels_font = Font {
-            font_color: grid_color.clone(),
+            font_color: style.grid_color.clone(),
             ..Default::default()
         };
 
-        let mood_value_labels_font = Font::default();
-
-        let month_labels_spacing = 20.0;
-
-        let mut mood_values_per_day_of_month_index = vec![];
+        let mood_value_labels_font = Font {
+            font_color: style.text_color.clone(),
+            ..Default::default()
+        };
 
-        // Create the mood values per day.
-        for _ in 0..number_of_days_in_month {
-            mood_values_per_day_of_month_index.push(HashSet::new());
-        }
+        let month_labels_spacing = MONTH_LABELS_SPACING;
 
         MoodWidget {
             core: WidgetCore::new(widget_id, debug_rendering_stroke),
-            day_of_month_index_today,
-            fills_per_mood_value_index: [
-                // Darkest.
-                PaintBrush::Color(Color::rgb8(55, 6, 23)),
-                //
-                PaintBrush::Color(Color::rgb8(86, 1, 29)),
-                PaintBrush::Color(Color::rgb8(118, 0, 32)),
-                PaintBrush::Color(Color::rgb8(149, 0, 30)),
-                PaintBrush::Color(Color::rgb8(180, 0, 22)),
-                //
-                // Middle.
-                PaintBrush::Color(Color::rgb8(208, 0, 0)),
-                //
-                PaintBrush::Color(Color::rgb8(216, 66, 0)),
-                PaintBrush::Color(Color::rgb8(221, 101, 0)),
-                PaintBrush::Color(Color::rgb8(224, 131, 0)),
-                PaintBrush::Color(Color::rgb8(225, 159, 0)),
-                //
-                // Brightest.
-                PaintBrush::Color(Color::rgb8(255, 186, 8)),
-            ],
-            month_labels: MonthLabels::new(month_labels_font, number_of_days_in_month),
+            filtered_day_of_month_indices: None,
+            labelled_day_of_month_index,
+            marked_day_of_month_indices: BTreeSet::new(),
+            marker_stroke: Stroke {
+                stroke_brush: PaintBrush::Color(style.text_color),
+                stroke_style: Default::default(),
+                stroke_width: 2.0,
+            },
+            fills_per_mood_value_index: style.fills_per_mood_value_index,
+            month_labels: MonthLabels::new(month_labels_font, day_of_month_labels),
             month_labels_spacing,
             mood_cell_size: Size::ZERO,
             mood_cells_area_rectangle: Rect::default(),
+            mood_month: MoodMonth::new(number_of_days_in_month),
             mood_value_labels: MoodValueLabels::new(
-                mood_value_labels_font,
-                NUMBER_OF_MOOD_VALUES_PER_DAY as u8,
+                mood_value_labels_font.clone(),
+                NUMBER_OF_MOOD_VALUES_PER_DAY,
             ),
-            mood_values_per_day_of_month_index,
+            mood_value_labels_font,
             number_of_days_in_month,
             mood_cells_grid_stroke: Stroke {
-                stroke_brush: PaintBrush::Color(grid_color),
+                stroke_brush: PaintBrush::Color(style.grid_color.clone()),
                 stroke_style: Default::default(),
                 stroke_width: 1.0,
             },
+            is_selecting: false,
+            selection_anchor: None,
+            selection_brush: PaintBrush::Color(style.grid_color.with_alpha(0.25)),
+            selected_day_of_month_indices: None,
         }
     }
 
+    /// Sets the day of month index whose column shows the mood value labels. `None` hides them.
+    pub fn set_labelled_day_of_month_index(&mut self, day_of_month_index: Option<usize>) {
+        self.labelled_day_of_month_index = day_of_month_index;
+    }
+
     ///
     fn cell_x0(&self, column_index: usize) -> f64 {
         self.mood_cells_area_rectangle.x0 + column_index as f64 * self.mood_cell_size.width
@@ -125,13 +184,111 @@ impl MoodWidget {
         self.mood_cells_area_rectangle.y1 - (1.0 + mood_value as f64) * self.mood_cell_size.height
     }
 
+    /// Returns the day of month index of the column at the given x coordinate. Coordinates left or
+    /// right of the mood cells give the first or the last day.
+    fn column_at(&self, x: f64) -> u8 {
+        let column_index =
+            ((x - self.mood_cells_area_rectangle.x0) / self.mood_cell_size.width).floor();
+
+        column_index.clamp(
+            0.0,
+            self.mood_month.number_of_days().saturating_sub(1) as f64,
+        ) as u8
+    }
+
+    /// Returns the vertical lines on the left edges of the marked days. Days after the end of the
+    /// month have none.
+    fn marker_lines(&self) -> Vec<Line> {
+        self.marked_day_of_month_indices
+            .iter()
+            .filter(|day_of_month_index| {
+                (**day_of_month_index as usize) < self.mood_month.number_of_days()
+            })
+            .map(|day_of_month_index| {
+                let x = self.cell_x0(*day_of_month_index as usize);
+
+                Line::new(
+                    (x, self.mood_cells_area_rectangle.y0),
+                    (x, self.mood_cells_area_rectangle.y1),
+                )
+            })
+            .collect()
+    }
+
+    /// Selects the days from the selection anchor to the given day and informs the world about it,
+    /// if the selection changed.
+    fn select_to(&mut self, day_of_month_index: u8, widget_events: &mut Vec<WidgetEvent>) {
+        let anchor = *self.selection_anchor.get_or_insert(day_of_month_index);
+
+        self.set_selection(
+            Some(anchor.min(day_of_month_index)..=anchor.max(day_of_month_index)),
+            widget_events,
+        );
+    }
+
+    /// Sets the selected days and informs the world about it, if they changed.
+    fn set_selection(
+        &mut self,
+        day_of_month_indices: Option<RangeInclusive<u8>>,
+        widget_events: &mut Vec<WidgetEvent>,
+    ) {
+        if self.selected_day_of_month_indices == day_of_month_indices {
+            return;
+        }
+
+        self.selected_day_of_month_indices = day_of_month_indices.clone();
+
+        widget_events.push(WidgetEvent::ValueChanged(
+            self.core.widget_id,
+            Box::new(MoodSelection {
+                day_of_month_indices,
+            }),
+        ));
+    }
+
+    /// Returns the day of month index and the mood value of the cell at the given point, if there
+    /// is one. Points on the right and bottom edges belong to no cell.
+    fn mood_cell_at(&self, point: Point) -> Option<(u8, MoodValue)> {
+        if !self.mood_cells_area_rectangle.contains(point) {
+            return None;
+        }
+
+        // The division can round up to the next column or row right before the edges, which still
+        // belongs to the last one.
+        let column_index = ((point.x - self.mood_cells_area_rectangle.x0)
+            / self.mood_cell_size.width)
+            .floor()
+            .clamp(0.0, self.number_of_days_in_month.saturating_sub(1) as f64);
+        let row_index = ((point.y - self.mood_cells_area_rectangle.y0)
+            / self.mood_cell_size.height)
+            .floor()
+            .clamp(
+                0.0,
+                self.mood_month.number_of_values().saturating_sub(1) as f64,
+            );
+
+        // The rows are ordered from the highest mood value to the lowest.
+        Some((
+            column_index as u8,
+            self.mood_month.number_of_values() - 1 - row_index as MoodValue,
+        ))
+    }
+
     ///
     fn update_layout(&mut self) {
+        // A hidden widget takes no space.
+        if self.core.is_hidden {
+            self.core.rectangle = self.core.rectangle.with_size(Size::ZERO);
+            self.mood_cells_area_rectangle = self.core.rectangle;
+            self.mood_cell_size = Size::ZERO;
+            return;
+        }
+
         // Update the mood widget's size.
-        self.core.rectangle = self
-            .core
-            .rectangle
-            .with_size(Size::new(self.core.size_constraints.maximum().width, 200.0));
+        self.core.rectangle = self.core.rectangle.with_size(Size::new(
+            self.core.size_constraints.maximum().width,
+            MOOD_WIDGET_HEIGHT,
+        ));
 
         // Update the mood cells area's rectangle.
         self.mood_cells_area_rectangle = Rect::new(
@@ -144,104 +301,20 @@ impl MoodWidget {
         // Update the mood cell's size.
         self.mood_cell_size = Size::new(
             self.mood_cells_area_rectangle.width() / self.number_of_days_in_month as f64,
-            self.mood_cells_area_rectangle.height() / NUMBER_OF_MOOD_VALUES_PER_DAY as f64,
+            self.mood_cells_area_rectangle.height() / self.mood_month.number_of_values() as f64,
         );
     }
-}
-
-impl Widget for MoodWidget {
-    fn apply_size_constraints(&mut self, size_constraints: SizeConstraints) -> Size {
-        self.core.size_constraints = size_constraints;
-
-        // Update the layout.
-        self.update_layout();
-
-        self.core.rectangle.size()
-    }
-
-    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), WidgetError> {
-        // The given value is a `MoodValuesUpdate`.
-        if let Some(mood_values_update) = value.downcast_ref::<MoodValuesUpdate>() {
-            match mood_values_update {
-                MoodValuesUpdate::Clear => {
-                    // Clear the mood values.
-                    for mood_values in self.mood_values_per_day_of_month_index.iter_mut() {
-                        mood_values.clear();
-                    }
-                    return Ok(());
-                }
-                MoodValuesUpdate::Update {
-                    day_of_month_index,
-                    mood_values,
-                } => {
-                    // The day of month index is within range.
-                    return if *day_of_month_index < 31 {
-                        // Set the given mood values to the given day of month.
-                        *self
-                            .mood_values_per_day_of_month_index
-                            .get_mut(*day_of_month_index as usize)
-                            .unwrap() = mood_values.clone();
-                        Ok(())
-                    }
-                    // The day of month index is out of range.
-                    else {
-                        Err(WidgetError::NotHandled)
-                    };
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    fn handle_event(&mut self, event: &Event, widget_events: &mut Vec<WidgetEvent>) {
-        if let Event::MouseDown(mouse_event) = event {
-            // The mouse is not down within the cells area.
-            if !self.mood_cells_area_rectangle.contains(mouse_event.pos) {
-                return;
-            }
 
-            // Determine the clicked day of month index.
-            let clicked_day_of_month_index: u8 = ((mouse_event.pos.x
-                - self.mood_cells_area_rectangle.x0)
-                / self.mood_cell_size.width) as u8;
-
-            // Determine the clicked mood value.
-            let clicked_mood_value: MoodValue = (NUMBER_OF_MOOD_VALUES_PER_DAY
-                - ((mouse_event.pos.y - self.mood_cells_area_rectangle.y0)
-                    / self.mood_cell_size.height) as MoodValue)
-                as MoodValue
-                - 1;
-
-            // Get the mood values for the clicked day.
-            let mood_values = self
-                .mood_values_per_day_of_month_index
-                .get_mut(clicked_day_of_month_index as usize)
-                .unwrap();
-
-            // The clicked mood value was set already.
-            if mood_values.contains(&clicked_mood_value) {
-                // Unset it.
-                mood_values.remove(&clicked_mood_value);
-            }
-            // The clicked mood value was not set yet.
-            else {
-                // Set it.
-                mood_values.insert(clicked_mood_value);
-            }
-
-            // Inform the world about the update.
-            widget_events.push(WidgetEvent::ValueChanged(
-                self.core.widget_id,
-                Box::new(MoodValuesUpdate::Update {
-                    day_of_month_index: clicked_day_of_month_index,
-                    mood_values: mood_values.clone(),
-                }),
-            ));
+    /// Paints the widget to the given render context, e.g. to a bitmap or an SVG file.
+    pub fn paint_to<R: RenderContext>(&self, piet: &mut R, region: &Region) -> Result<(), Error>
+    where
+        R::TextLayout: 'static,
+    {
+        // A hidden widget is not painted.
+        if self.core.is_hidden {
+            return Ok(());
         }
-    }
 
-    fn paint(&self, piet: &mut Piet, region: &Region) -> Result<(), Error> {
         // Write the month labels.
         self.month_labels.paint(
             piet,
@@ -252,10 +325,28 @@ impl Widget for MoodWidget {
         )?;
 
         // Fill the mood cells.
-        for (column_index, mood_values) in
-            self.mood_values_per_day_of_month_index.iter().enumerate()
-        {
+        for (column_index, mood_values) in self.mood_month.iter() {
+            // The day does not match the filter.
+            let is_filtered_out =
+                self.filtered_day_of_month_indices
+                    .as_ref()
+                    .is_some_and(|day_of_month_indices| {
+                        !day_of_month_indices.contains(&(column_index as u8))
+                    });
+
             for mood_value in mood_values {
+                // A disabled widget and the days not matching the filter are greyed out.
+                let fill = if self.core.is_disabled || is_filtered_out {
+                    Some(&self.mood_cells_grid_stroke.stroke_brush)
+                } else {
+                    self.fills_per_mood_value_index.get(*mood_value as usize)
+                };
+
+                // There is no fill for the mood value.
+                let Some(fill) = fill else {
+                    continue;
+                };
+
                 let x = self.cell_x0(column_index);
                 let y = self.cell_y0(*mood_value);
 
@@ -266,21 +357,34 @@ impl Widget for MoodWidget {
                         x + self.mood_cell_size.width,
                         y + self.mood_cell_size.height,
                     ),
-                    self.fills_per_mood_value_index
-                        .get(*mood_value as usize)
-                        .unwrap(),
+                    fill,
                 );
             }
         }
 
-        // Write the mood numbers for today.
-        self.mood_value_labels.paint(
-            piet,
-            region,
-            self.cell_x0(self.day_of_month_index_today),
-            self.cell_y0(0),
-            self.mood_cell_size,
-        )?;
+        // Highlight the selected days.
+        if let Some(day_of_month_indices) = &self.selected_day_of_month_indices {
+            piet.fill(
+                Rect::new(
+                    self.cell_x0(*day_of_month_indices.start() as usize),
+                    self.mood_cells_area_rectangle.y0,
+                    self.cell_x0(*day_of_month_indices.end() as usize + 1),
+                    self.mood_cells_area_rectangle.y1,
+                ),
+                &self.selection_brush,
+            );
+        }
+
+        // Write the mood numbers for the labelled day.
+        if let Some(labelled_day_of_month_index) = self.labelled_day_of_month_index {
+            self.mood_value_labels.paint(
+                piet,
+                region,
+                self.cell_x0(labelled_day_of_month_index),
+                self.cell_y0(0),
+                self.mood_cell_size,
+            )?;
+        }
 
         // Stroke the cell lines.
         {
@@ -300,7 +404,7 @@ impl Widget for MoodWidget {
             }
 
             // Stroke the horizontal lines.
-            for row_index in 1..NUMBER_OF_MOOD_VALUES_PER_DAY {
+            for row_index in 1..self.mood_month.number_of_values() {
                 let y = self.mood_cells_area_rectangle.y0
                     + row_index as f64 * self.mood_cell_size.height;
 
@@ -322,6 +426,15 @@ impl Widget for MoodWidget {
             );
         }
 
+        // Stroke the markers over the grid.
+        for marker_line in self.marker_lines() {
+            piet.stroke(
+                marker_line,
+                &self.marker_stroke.stroke_brush,
+                self.marker_stroke.stroke_width,
+            );
+        }
+
         // Render debug hints.
         if self.core.debug_rendering {
             piet.stroke(
@@ -333,6 +446,138 @@ impl Widget for MoodWidget {
 
         Ok(())
     }
+}
+
+impl Widget for MoodWidget {
+    fn apply_size_constraints(&mut self, size_constraints: SizeConstraints) -> Size {
+        self.core.size_constraints = size_constraints;
+
+        // Update the layout.
+        self.update_layout();
+
+        self.core.rectangle.size()
+    }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), WidgetError> {
+        // The given value is a `MoodMonth`.
+        if let Some(mood_month) = value.downcast_ref::<MoodMonth>() {
+            let number_of_values_changed =
+                mood_month.number_of_values() != self.mood_month.number_of_values();
+
+            self.mood_month = mood_month.clone();
+
+            // The month is of a scale with another number of values, e.g. of a dimension.
+            if number_of_values_changed {
+                self.mood_value_labels = MoodValueLabels::new(
+                    self.mood_value_labels_font.clone(),
+                    self.mood_month.number_of_values(),
+                );
+                self.update_layout();
+            }
+        }
+        // The given value is a `MoodDayFilter`.
+        else if let Some(mood_day_filter) = value.downcast_ref::<MoodDayFilter>() {
+            self.filtered_day_of_month_indices = mood_day_filter.day_of_month_indices.clone();
+        }
+        // The given value is a `MoodMarkers`.
+        else if let Some(mood_markers) = value.downcast_ref::<MoodMarkers>() {
+            self.marked_day_of_month_indices = mood_markers.day_of_month_indices.clone();
+        }
+        // The given value is a `MoodWidgetStyle`. Only its fills are used, e.g. the palette of a
+        // dimension.
+        else if let Some(style) = value.downcast_ref::<MoodWidgetStyle>() {
+            self.fills_per_mood_value_index = style.fills_per_mood_value_index.clone();
+        }
+        // The given value is a `MoodSelection`.
+        else if let Some(mood_selection) = value.downcast_ref::<MoodSelection>() {
+            self.selected_day_of_month_indices = mood_selection.day_of_month_indices.clone();
+            self.selection_anchor = mood_selection
+                .day_of_month_indices
+                .as_ref()
+                .map(|day_of_month_indices| *day_of_month_indices.start());
+        }
+
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: &Event, widget_events: &mut Vec<WidgetEvent>) {
+        // A disabled or hidden widget ignores input.
+        if self.core.is_disabled || self.core.is_hidden {
+            return;
+        }
+
+        match event {
+            Event::MouseDown(mouse_event) => {
+                // The mouse is not down on a mood cell.
+                let Some((clicked_day_of_month_index, clicked_mood_value)) =
+                    self.mood_cell_at(mouse_event.pos)
+                else {
+                    return;
+                };
+
+                // The clicked day is not within the month.
+                if self
+                    .mood_month
+                    .mood_values(clicked_day_of_month_index as usize)
+                    .is_none()
+                {
+                    return;
+                }
+
+                // A right click does not select, e.g. to open a menu for the day.
+                if mouse_event.button == MouseButton::Right {
+                    widget_events.push(WidgetEvent::ValueChanged(
+                        self.core.widget_id,
+                        Box::new(MoodCellRightClicked {
+                            day_of_month_index: clicked_day_of_month_index,
+                            mood_value: clicked_mood_value,
+                        }),
+                    ));
+                    return;
+                }
+
+                // Shift-click selects the days from the anchor and shift-drag extends the
+                // selection.
+                if mouse_event.mods.shift() {
+                    self.is_selecting = true;
+                    self.select_to(clicked_day_of_month_index, widget_events);
+                    return;
+                }
+
+                // A click outside of the selection deselects the days.
+                if !self.selected_day_of_month_indices.as_ref().is_some_and(
+                    |day_of_month_indices| {
+                        day_of_month_indices.contains(&clicked_day_of_month_index)
+                    },
+                ) {
+                    self.set_selection(None, widget_events);
+                    self.selection_anchor = Some(clicked_day_of_month_index);
+                }
+
+                // Inform the world about the click. The mood values are updated with `set_value()`.
+                widget_events.push(WidgetEvent::ValueChanged(
+                    self.core.widget_id,
+                    Box::new(MoodCellClicked {
+                        day_of_month_index: clicked_day_of_month_index,
+                        mood_value: clicked_mood_value,
+                    }),
+                ));
+            }
+            // Shift-drag extends the selection.
+            Event::MouseMove(mouse_event) if self.is_selecting => {
+                let day_of_month_index = self.column_at(mouse_event.pos.x);
+                self.select_to(day_of_month_index, widget_events);
+            }
+            Event::MouseUp(_) => {
+                self.is_selecting = false;
+            }
+            _ => {}
+        }
+    }
+
+    fn paint(&self, piet: &mut Piet, region: &Region) -> Result<(), Error> {
+        self.paint_to(piet, region)
+    }
 
     fn rectangle(&self) -> &Rect {
         &self.core.rectangle
@@ -350,14 +595,17 @@ impl Widget for MoodWidget {
     }
 
     fn set_debug_rendering(&mut self, debug_rendering: bool) {
-        todo!()
+        self.core.debug_rendering = debug_rendering;
     }
 
     fn set_is_disabled(&mut self, is_disabled: bool) {
-        todo!()
+        self.core.is_disabled = is_disabled;
     }
 
     fn set_is_hidden(&mut self, is_hidden: bool) {
-        todo!()
+        self.core.is_hidden = is_hidden;
+
+        // Update the layout.
+        self.update_layout();
     }
 }
@@ -1,33 +1,40 @@
-use guiver::{Error, Font, Piet, PietTextLayout, Rect, Region, RenderContext, Size, TextLayout};
+use crate::widget::TextLayoutCache;
+use guiver::{Error, Font, Rect, Region, RenderContext, Size, TextLayout};
 
-/// Caches the `PietTextLayout` for the month labels.
+/// The month labels, laid out when they are painted first.
 pub(crate) struct MonthLabels {
-    text_layouts: Vec<PietTextLayout>,
+    font: Font,
+    text_layout_cache: TextLayoutCache,
+    texts: Vec<String>,
 }
 
 impl MonthLabels {
-    ///
-    pub(crate) fn new(font: Font, number_of_days_in_month: u8) -> Self {
-        let mut text_layouts = vec![];
-
-        for column_index in 0..number_of_days_in_month {
-            let text = u8::to_string(&(column_index + 1));
-            text_layouts.push(font.text_layout(text));
+    /// Creates the month labels from the given, localized labels for the days of a month.
+    pub(crate) fn new(font: Font, day_of_month_labels: Vec<String>) -> Self {
+        MonthLabels {
+            font,
+            text_layout_cache: TextLayoutCache::default(),
+            texts: day_of_month_labels,
         }
-
-        MonthLabels { text_layouts }
     }
 
     ///
-    pub(crate) fn paint(
+    pub(crate) fn paint<R: RenderContext>(
         &self,
-        piet: &mut Piet,
+        piet: &mut R,
         _region: &Region,
         x: f64,
         y: f64,
         cell_size: Size,
-    ) -> Result<(), Error> {
-        for (column_index, text_layout) in self.text_layouts.iter().enumerate() {
+    ) -> Result<(), Error>
+    where
+        R::TextLayout: 'static,
+    {
+        let text_layouts = self
+            .text_layout_cache
+            .text_layouts(piet, &self.font, &self.texts)?;
+
+        for (column_index, text_layout) in text_layouts.iter().enumerate() {
             let text_x = x
                 // Add the current cell's offset.
                 + column_index as f64 * cell_size.width
@@ -1,38 +1,46 @@
-use guiver::{Error, Font, Piet, PietTextLayout, Rect, Region, RenderContext, Size, TextLayout};
-use std::u8;
+use crate::widget::TextLayoutCache;
+use guiver::{Error, Font, Rect, Region, RenderContext, Size, TextLayout};
 
-/// Caches the `PietTextLayout` for the mood value labels.
+/// The mood value labels, laid out when they are painted first.
 pub(crate) struct MoodValueLabels {
-    text_layouts: Vec<PietTextLayout>,
+    font: Font,
+    text_layout_cache: TextLayoutCache,
+    texts: Vec<String>,
 }
 
 impl MoodValueLabels {
     ///
     pub(crate) fn new(font: Font, number_mood_values: u8) -> Self {
-        let mut text_layouts = vec![];
-
-        for mood_value in 0..number_mood_values {
-            let text = u8::to_string(&mood_value);
-            text_layouts.push(font.text_layout(text));
+        MoodValueLabels {
+            font,
+            text_layout_cache: TextLayoutCache::default(),
+            texts: (0..number_mood_values)
+                .map(|mood_value| mood_value.to_string())
+                .collect(),
         }
-
-        MoodValueLabels { text_layouts }
     }
 
     ///
-    pub(crate) fn paint(
+    pub(crate) fn paint<R: RenderContext>(
         &self,
-        piet: &mut Piet,
+        piet: &mut R,
         _region: &Region,
         x: f64,
         y: f64,
         cell_size: Size,
-    ) -> Result<(), Error> {
-        for (mood_value_index, text_layout) in self.text_layouts.iter().enumerate() {
+    ) -> Result<(), Error>
+    where
+        R::TextLayout: 'static,
+    {
+        let text_layouts = self
+            .text_layout_cache
+            .text_layouts(piet, &self.font, &self.texts)?;
+
+        for (mood_value, text_layout) in text_layouts.iter().enumerate() {
             let text_x = x + 0.5 * (cell_size.width - text_layout.size().width).max(0.0);
             let text_y = y
                 // Add the current cell's offset.
-                - mood_value_index as f64 * cell_size.height
+                - mood_value as f64 * cell_size.height
                 // Center the text within the cell's height.
                 + 0.5 * (cell_size.height - text_layout.size().height);
 
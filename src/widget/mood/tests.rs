@@ -0,0 +1,528 @@
+use super::*;
+use guiver::{Modifiers, MouseButton, MouseButtons, MouseEvent, Vec2};
+
+/// The width of the test widget, which gives mood cells that are 10 pixels wide.
+const WIDTH: f64 = 310.0;
+
+/// The height of a mood cell.
+const CELL_HEIGHT: f64 = (MOOD_WIDGET_HEIGHT - MONTH_LABELS_SPACING) / 11.0;
+
+/// Creates a mood widget with 31 days, laid out at the given origin with the given width.
+fn mood_widget(origin: Point, width: f64) -> MoodWidget {
+    let mut mood_widget = MoodWidget::new(
+        0,
+        Stroke {
+            stroke_brush: PaintBrush::Color(Color::rgb8(255, 0, 0)),
+            stroke_style: Default::default(),
+            stroke_width: 1.0,
+        },
+        (1..=31).map(|day| day.to_string()).collect(),
+        MoodWidgetStyle {
+            fills_per_mood_value_index: std::array::from_fn(|mood_value| {
+                PaintBrush::Color(Color::rgb8(0, 0, 20 * mood_value as u8))
+            }),
+            grid_color: Color::rgb8(128, 128, 128),
+            text_color: Color::rgb8(255, 255, 255),
+        },
+    );
+
+    mood_widget.apply_size_constraints(SizeConstraints::new(
+        Size::ZERO,
+        Size::new(width, MOOD_WIDGET_HEIGHT),
+    ));
+    mood_widget.set_origin(origin);
+
+    mood_widget
+}
+
+/// Returns a left button mouse event at the given position.
+fn mouse_event(x: f64, y: f64, mods: Modifiers) -> MouseEvent {
+    MouseEvent {
+        pos: Point::new(x, y),
+        buttons: MouseButtons::new().with(MouseButton::Left),
+        mods,
+        count: 1,
+        focus: false,
+        button: MouseButton::Left,
+        wheel_delta: Vec2::ZERO,
+    }
+}
+
+/// Lets the mood widget handle the event and returns the values of the given type it reported.
+fn handle_event<T: Clone + 'static>(mood_widget: &mut MoodWidget, event: Event) -> Vec<T> {
+    let mut widget_events = vec![];
+
+    mood_widget.handle_event(&event, &mut widget_events);
+
+    widget_events
+        .into_iter()
+        .filter_map(|widget_event| match widget_event {
+            WidgetEvent::ValueChanged(0, value) => value.downcast_ref::<T>().cloned(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Clicks the mood widget at the given position and returns the clicked cells it reported.
+fn click(mood_widget: &mut MoodWidget, x: f64, y: f64) -> Vec<MoodCellClicked> {
+    handle_event(
+        mood_widget,
+        Event::MouseDown(mouse_event(x, y, Modifiers::empty())),
+    )
+}
+
+/// Shift-clicks the mood widget at the given position and returns the selections it reported.
+fn shift_click(mood_widget: &mut MoodWidget, x: f64, y: f64) -> Vec<MoodSelection> {
+    handle_event(
+        mood_widget,
+        Event::MouseDown(mouse_event(x, y, Modifiers::SHIFT)),
+    )
+}
+
+/// Moves the mouse over the mood widget and returns the selections it reported.
+fn move_to(mood_widget: &mut MoodWidget, x: f64, y: f64) -> Vec<MoodSelection> {
+    handle_event(
+        mood_widget,
+        Event::MouseMove(mouse_event(x, y, Modifiers::SHIFT)),
+    )
+}
+
+/// Returns the expected event of selecting the given days.
+fn selected(day_of_month_indices: Option<RangeInclusive<u8>>) -> Vec<MoodSelection> {
+    vec![MoodSelection {
+        day_of_month_indices,
+    }]
+}
+
+/// Returns the expected event of clicking the given cell.
+fn clicked(day_of_month_index: u8, mood_value: MoodValue) -> Vec<MoodCellClicked> {
+    vec![MoodCellClicked {
+        day_of_month_index,
+        mood_value,
+    }]
+}
+
+// =================================================================================================
+
+#[test]
+fn layout_fills_the_width_and_moves_with_the_origin() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    assert_eq!(
+        mood_widget.rectangle(),
+        &Rect::new(0.0, 0.0, WIDTH, MOOD_WIDGET_HEIGHT)
+    );
+    assert_eq!(
+        mood_widget.mood_cells_area_rectangle,
+        Rect::new(0.0, MONTH_LABELS_SPACING, WIDTH, MOOD_WIDGET_HEIGHT)
+    );
+    assert_eq!(mood_widget.mood_cell_size, Size::new(10.0, CELL_HEIGHT));
+
+    mood_widget.set_origin(Point::new(50.0, 30.0));
+
+    assert_eq!(
+        mood_widget.rectangle(),
+        &Rect::new(50.0, 30.0, 50.0 + WIDTH, 30.0 + MOOD_WIDGET_HEIGHT)
+    );
+    assert_eq!(
+        mood_widget.mood_cells_area_rectangle,
+        Rect::new(
+            50.0,
+            30.0 + MONTH_LABELS_SPACING,
+            50.0 + WIDTH,
+            30.0 + MOOD_WIDGET_HEIGHT
+        )
+    );
+}
+
+#[test]
+fn hidden_widget_takes_no_space_and_ignores_clicks() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    mood_widget.set_is_hidden(true);
+
+    assert_eq!(mood_widget.rectangle().size(), Size::ZERO);
+    assert_eq!(click(&mut mood_widget, 5.0, 25.0), vec![]);
+
+    mood_widget.set_is_hidden(false);
+
+    assert_eq!(
+        mood_widget.rectangle().size(),
+        Size::new(WIDTH, MOOD_WIDGET_HEIGHT)
+    );
+    assert_eq!(click(&mut mood_widget, 5.0, 25.0), clicked(0, 10));
+}
+
+#[test]
+fn disabled_widget_ignores_clicks() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    mood_widget.set_is_disabled(true);
+
+    assert_eq!(click(&mut mood_widget, 5.0, 25.0), vec![]);
+}
+
+#[test]
+fn clicks_on_the_corner_cells() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    // The top left cell has the highest mood value of the first day.
+    assert_eq!(
+        click(&mut mood_widget, 0.0, MONTH_LABELS_SPACING),
+        clicked(0, 10)
+    );
+    // The bottom right cell has the lowest mood value of the last day.
+    assert_eq!(
+        click(&mut mood_widget, WIDTH - 0.001, MOOD_WIDGET_HEIGHT - 0.001),
+        clicked(30, 0)
+    );
+    assert_eq!(
+        click(&mut mood_widget, 0.0, MOOD_WIDGET_HEIGHT - 0.001),
+        clicked(0, 0)
+    );
+    assert_eq!(
+        click(&mut mood_widget, WIDTH - 0.001, MONTH_LABELS_SPACING),
+        clicked(30, 10)
+    );
+}
+
+#[test]
+fn clicks_on_cell_boundaries_belong_to_the_next_cell() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    assert_eq!(click(&mut mood_widget, 9.999, 25.0), clicked(0, 10));
+    assert_eq!(click(&mut mood_widget, 10.0, 25.0), clicked(1, 10));
+    assert_eq!(
+        click(
+            &mut mood_widget,
+            5.0,
+            MONTH_LABELS_SPACING + CELL_HEIGHT - 0.001
+        ),
+        clicked(0, 10)
+    );
+    assert_eq!(
+        click(
+            &mut mood_widget,
+            5.0,
+            MONTH_LABELS_SPACING + CELL_HEIGHT + 0.001
+        ),
+        clicked(0, 9)
+    );
+}
+
+#[test]
+fn clicks_outside_the_cells_are_ignored() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    // On the month labels.
+    assert_eq!(
+        click(&mut mood_widget, 5.0, MONTH_LABELS_SPACING - 0.001),
+        vec![]
+    );
+    // On the right and bottom edges.
+    assert_eq!(click(&mut mood_widget, WIDTH, 25.0), vec![]);
+    assert_eq!(click(&mut mood_widget, 5.0, MOOD_WIDGET_HEIGHT), vec![]);
+    // Left of and below the widget.
+    assert_eq!(click(&mut mood_widget, -0.001, 25.0), vec![]);
+    assert_eq!(
+        click(&mut mood_widget, 5.0, MOOD_WIDGET_HEIGHT + 10.0),
+        vec![]
+    );
+}
+
+#[test]
+fn clicks_right_before_the_right_edge_stay_within_the_last_day() {
+    // The division by the cell width rounds up to 31 right before the right edge.
+    let width = 497.0;
+    let mut mood_widget = mood_widget(Point::ZERO, width);
+
+    let x = f64::from_bits(width.to_bits() - 1);
+
+    assert_eq!(click(&mut mood_widget, x, 25.0), clicked(30, 10));
+    assert_eq!(
+        click(&mut mood_widget, width - 0.001, 25.0),
+        clicked(30, 10)
+    );
+}
+
+#[test]
+fn clicks_are_relative_to_the_origin() {
+    let mut mood_widget = mood_widget(Point::new(50.0, 30.0), WIDTH);
+
+    assert_eq!(click(&mut mood_widget, 5.0, 55.0), vec![]);
+    assert_eq!(click(&mut mood_widget, 55.0, 55.0), clicked(0, 10));
+    assert_eq!(
+        click(
+            &mut mood_widget,
+            50.0 + WIDTH - 0.001,
+            30.0 + MOOD_WIDGET_HEIGHT - 0.001
+        ),
+        clicked(30, 0)
+    );
+}
+
+#[test]
+fn clicks_do_not_change_the_mood_values() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    assert_eq!(click(&mut mood_widget, 5.0, 25.0), clicked(0, 10));
+    assert_eq!(click(&mut mood_widget, 5.0, 25.0), clicked(0, 10));
+    assert_eq!(mood_widget.mood_month, MoodMonth::new(31));
+}
+
+#[test]
+fn set_value_replaces_the_mood_month() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    let mut mood_month = MoodMonth::new(30);
+    mood_month.set_mood_values(4, [3, 7].into());
+
+    assert!(mood_widget.set_value(Box::new(mood_month.clone())).is_ok());
+    assert_eq!(mood_widget.mood_month, mood_month);
+
+    // Other values are ignored.
+    assert!(mood_widget.set_value(Box::new("3")).is_ok());
+    assert_eq!(mood_widget.mood_month, mood_month);
+}
+
+#[test]
+fn clicks_on_days_after_the_end_of_the_month_are_ignored() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    mood_widget
+        .set_value(Box::new(MoodMonth::new(30)))
+        .expect("the mood month should be set");
+
+    assert_eq!(click(&mut mood_widget, 295.0, 25.0), clicked(29, 10));
+    assert_eq!(click(&mut mood_widget, 305.0, 25.0), vec![]);
+}
+
+#[test]
+fn shift_click_selects_the_days_from_the_last_click() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    // Without a click before, the shift-clicked day is selected.
+    assert_eq!(
+        shift_click(&mut mood_widget, 45.0, 25.0),
+        selected(Some(4..=4))
+    );
+    assert_eq!(
+        shift_click(&mut mood_widget, 15.0, 25.0),
+        selected(Some(1..=4))
+    );
+    assert_eq!(
+        shift_click(&mut mood_widget, 95.0, 25.0),
+        selected(Some(4..=9))
+    );
+    // Selecting the same days again is not reported.
+    assert_eq!(shift_click(&mut mood_widget, 95.0, 100.0), vec![]);
+
+    // Shift-clicks do not change mood values.
+    assert_eq!(mood_widget.mood_month, MoodMonth::new(31));
+}
+
+#[test]
+fn shift_drag_extends_the_selection_until_the_mouse_is_up() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    assert_eq!(
+        shift_click(&mut mood_widget, 105.0, 25.0),
+        selected(Some(10..=10))
+    );
+    assert_eq!(
+        move_to(&mut mood_widget, 125.0, 25.0),
+        selected(Some(10..=12))
+    );
+    assert_eq!(
+        move_to(&mut mood_widget, 85.0, 25.0),
+        selected(Some(8..=10))
+    );
+    // Beyond the cells, the selection ends at the first and the last day.
+    assert_eq!(
+        move_to(&mut mood_widget, -50.0, 0.0),
+        selected(Some(0..=10))
+    );
+    assert_eq!(
+        move_to(&mut mood_widget, WIDTH + 50.0, 500.0),
+        selected(Some(10..=30))
+    );
+
+    handle_event::<MoodSelection>(
+        &mut mood_widget,
+        Event::MouseUp(mouse_event(WIDTH + 50.0, 500.0, Modifiers::SHIFT)),
+    );
+
+    assert_eq!(move_to(&mut mood_widget, 5.0, 25.0), vec![]);
+    assert_eq!(mood_widget.selected_day_of_month_indices, Some(10..=30));
+}
+
+#[test]
+fn clicks_outside_of_the_selection_deselect_the_days() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    shift_click(&mut mood_widget, 15.0, 25.0);
+    shift_click(&mut mood_widget, 45.0, 25.0);
+
+    // A click within the selection keeps it and is reported.
+    assert_eq!(
+        handle_event::<MoodSelection>(
+            &mut mood_widget,
+            Event::MouseDown(mouse_event(35.0, 25.0, Modifiers::empty()))
+        ),
+        vec![]
+    );
+    assert_eq!(click(&mut mood_widget, 35.0, 25.0), clicked(3, 10));
+    assert_eq!(mood_widget.selected_day_of_month_indices, Some(1..=4));
+
+    // A click outside of it deselects the days, but is still reported.
+    assert_eq!(
+        handle_event::<MoodSelection>(
+            &mut mood_widget,
+            Event::MouseDown(mouse_event(75.0, 25.0, Modifiers::empty()))
+        ),
+        selected(None)
+    );
+    assert_eq!(click(&mut mood_widget, 75.0, 25.0), clicked(7, 10));
+
+    // The click is the anchor of the next selection.
+    assert_eq!(
+        shift_click(&mut mood_widget, 95.0, 25.0),
+        selected(Some(7..=9))
+    );
+}
+
+#[test]
+fn set_value_replaces_the_selection() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    assert!(mood_widget
+        .set_value(Box::new(MoodSelection {
+            day_of_month_indices: Some(5..=8),
+        }))
+        .is_ok());
+    assert_eq!(mood_widget.selected_day_of_month_indices, Some(5..=8));
+    assert_eq!(
+        shift_click(&mut mood_widget, 25.0, 25.0),
+        selected(Some(2..=5))
+    );
+
+    assert!(mood_widget
+        .set_value(Box::new(MoodSelection {
+            day_of_month_indices: None,
+        }))
+        .is_ok());
+    assert_eq!(mood_widget.selected_day_of_month_indices, None);
+}
+
+#[test]
+fn a_month_with_fewer_values_has_fewer_rows() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    mood_widget
+        .set_value(Box::new(MoodMonth::with_number_of_values(31, 4)))
+        .expect("the mood month should be set");
+
+    let cell_height = (MOOD_WIDGET_HEIGHT - MONTH_LABELS_SPACING) / 4.0;
+
+    assert_eq!(mood_widget.mood_cell_size, Size::new(10.0, cell_height));
+    // The top row has the highest value.
+    assert_eq!(
+        click(&mut mood_widget, 5.0, MONTH_LABELS_SPACING),
+        clicked(0, 3)
+    );
+    assert_eq!(
+        click(&mut mood_widget, 5.0, MOOD_WIDGET_HEIGHT - 0.001),
+        clicked(0, 0)
+    );
+}
+
+#[test]
+fn right_clicks_are_reported_without_clicking_or_selecting() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    shift_click(&mut mood_widget, 15.0, 25.0);
+    shift_click(&mut mood_widget, 45.0, 25.0);
+
+    let right_click = Event::MouseDown(MouseEvent {
+        buttons: MouseButtons::new().with(MouseButton::Right),
+        button: MouseButton::Right,
+        ..mouse_event(75.0, 25.0, Modifiers::empty())
+    });
+
+    assert_eq!(
+        handle_event::<MoodCellRightClicked>(&mut mood_widget, right_click.clone()),
+        vec![MoodCellRightClicked {
+            day_of_month_index: 7,
+            mood_value: 10,
+        }]
+    );
+    assert_eq!(
+        handle_event::<MoodCellClicked>(&mut mood_widget, right_click.clone()),
+        vec![]
+    );
+    assert_eq!(
+        handle_event::<MoodSelection>(&mut mood_widget, right_click),
+        vec![]
+    );
+    // The selection is kept.
+    assert_eq!(mood_widget.selected_day_of_month_indices, Some(1..=4));
+}
+
+#[test]
+fn set_value_replaces_the_day_filter() {
+    let mut mood_widget = mood_widget(Point::ZERO, WIDTH);
+
+    mood_widget
+        .set_value(Box::new(MoodDayFilter {
+            day_of_month_indices: Some([2, 5].into()),
+        }))
+        .expect("the day filter should be set");
+    assert_eq!(
+        mood_widget.filtered_day_of_month_indices,
+        Some([2, 5].into())
+    );
+
+    // Filtered out days can still be clicked.
+    assert_eq!(click(&mut mood_widget, 35.0, 25.0), clicked(3, 10));
+
+    mood_widget
+        .set_value(Box::new(MoodDayFilter {
+            day_of_month_indices: None,
+        }))
+        .expect("the day filter should be removed");
+    assert_eq!(mood_widget.filtered_day_of_month_indices, None);
+}
+
+#[test]
+fn markers_are_on_the_left_edges_of_the_marked_days() {
+    let mut mood_widget = mood_widget(Point::new(20.0, 40.0), WIDTH);
+
+    mood_widget
+        .set_value(Box::new(MoodMonth::new(30)))
+        .expect("the mood month should be set");
+    mood_widget
+        .set_value(Box::new(MoodMarkers {
+            day_of_month_indices: [0, 3, 30].into(),
+        }))
+        .expect("the markers should be set");
+
+    let y0 = 40.0 + MONTH_LABELS_SPACING;
+    let y1 = 40.0 + MOOD_WIDGET_HEIGHT;
+
+    // The day after the end of the month has no marker.
+    assert_eq!(
+        mood_widget.marker_lines(),
+        vec![
+            Line::new((20.0, y0), (20.0, y1)),
+            Line::new((50.0, y0), (50.0, y1)),
+        ]
+    );
+
+    // Markers do not change what a click reports.
+    assert_eq!(click(&mut mood_widget, 51.0, 65.0), clicked(3, 10));
+
+    mood_widget
+        .set_value(Box::new(MoodMarkers::default()))
+        .expect("the markers should be removed");
+    assert_eq!(mood_widget.marker_lines(), vec![]);
+}
@@ -0,0 +1,308 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{Piet, Region, Size};
+use chrono::{Datelike, NaiveDate};
+use freundchen_core::data::{SleepEntry, MAXIMUM_SLEEP_QUALITY};
+use freundchen_core::mood::number_of_days_in_month;
+use guiver::widget::WidgetError;
+use guiver::{
+    Color, Error, Event, Line, PaintBrush, Point, Rect, RenderContext, SizeConstraints, Stroke,
+    Widget, WidgetCore, WidgetEvent, WidgetId,
+};
+use std::any::Any;
+use std::collections::BTreeMap;
+
+/// The height of the sleep widget.
+pub const SLEEP_WIDGET_HEIGHT: f64 = 60.0;
+
+/// The hours of sleep of a bar with the widget's full height. Longer nights are cut off.
+const MAXIMUM_CHARTED_HOURS: f64 = 12.0;
+
+/// The hours of sleep marked with a line, as a reference for the bars.
+const REFERENCE_HOURS: f64 = 8.0;
+
+/// The sleep of a night, charted as a bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SleepNight {
+    pub hours: f64,
+    /// From 1 to `MAXIMUM_SLEEP_QUALITY`.
+    pub quality: u8,
+}
+
+/// The sleep of the nights after the days of a month. Set with `set_value()`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SleepMonth {
+    /// The nights per day of month index, `None` if there is no entry.
+    pub nights: Vec<Option<SleepNight>>,
+}
+
+impl SleepMonth {
+    /// Returns the sleep of the nights after the days of the given date's month.
+    pub fn new(sleep_per_date: &BTreeMap<NaiveDate, SleepEntry>, date: NaiveDate) -> Self {
+        SleepMonth {
+            nights: (0..number_of_days_in_month(date) as u32)
+                .map(|day0| {
+                    let sleep = sleep_per_date.get(&date.with_day0(day0)?)?;
+
+                    Some(SleepNight {
+                        hours: sleep.hours(),
+                        quality: sleep.quality,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Sent with `WidgetEvent::ValueChanged` when the night after a day was clicked, e.g. to edit its
+/// sleep.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SleepNightClicked {
+    pub day_of_month_index: u8,
+}
+
+/// The colours of a sleep widget.
+#[derive(Clone, Debug)]
+pub struct SleepWidgetStyle {
+    /// The fills of the bars per quality, from the worst to the best.
+    pub fills_per_quality_index: [PaintBrush; MAXIMUM_SLEEP_QUALITY as usize],
+    /// The colour of the grid and the reference line.
+    pub grid_color: Color,
+}
+
+// =================================================================================================
+
+/// A widget that charts the hours of sleep of the nights after each day of a month as bars. Its
+/// columns are aligned with the `MoodWidget`'s if both have the same width.
+pub struct SleepWidget {
+    bars_area_rectangle: Rect,
+    column_width: f64,
+    core: WidgetCore,
+    fills_per_quality_index: [PaintBrush; MAXIMUM_SLEEP_QUALITY as usize],
+    grid_stroke: Stroke,
+    /// The number of columns, like the mood widget's.
+    number_of_columns: u8,
+    sleep_month: SleepMonth,
+}
+
+impl SleepWidget {
+    /// Creates a sleep widget with the given number of columns, which should be the mood widget's.
+    pub fn new(
+        widget_id: WidgetId,
+        debug_rendering_stroke: Stroke,
+        number_of_columns: u8,
+        style: SleepWidgetStyle,
+    ) -> Self {
+        SleepWidget {
+            bars_area_rectangle: Rect::default(),
+            column_width: 0.0,
+            core: WidgetCore::new(widget_id, debug_rendering_stroke),
+            fills_per_quality_index: style.fills_per_quality_index,
+            grid_stroke: Stroke {
+                stroke_brush: PaintBrush::Color(style.grid_color),
+                stroke_style: Default::default(),
+                stroke_width: 1.0,
+            },
+            number_of_columns,
+            sleep_month: SleepMonth::default(),
+        }
+    }
+
+    /// Returns the rectangle of the bar of the given column with the given hours of sleep.
+    fn bar_rectangle(&self, column_index: usize, hours: f64) -> Rect {
+        let x0 = self.bars_area_rectangle.x0 + column_index as f64 * self.column_width;
+        let height =
+            (hours / MAXIMUM_CHARTED_HOURS).clamp(0.0, 1.0) * self.bars_area_rectangle.height();
+
+        Rect::new(
+            x0,
+            self.bars_area_rectangle.y1 - height,
+            x0 + self.column_width,
+            self.bars_area_rectangle.y1,
+        )
+    }
+
+    /// Returns the day of month index of the column at the given point, if it is a day of the
+    /// month. Points on the right and bottom edges belong to no column.
+    fn column_at(&self, point: Point) -> Option<u8> {
+        if !self.bars_area_rectangle.contains(point) {
+            return None;
+        }
+
+        // The division can round up to the next column right before the edge.
+        let column_index = ((point.x - self.bars_area_rectangle.x0) / self.column_width).floor();
+
+        (0.0..self
+            .sleep_month
+            .nights
+            .len()
+            .min(self.number_of_columns as usize) as f64)
+            .contains(&column_index)
+            .then_some(column_index as u8)
+    }
+
+    ///
+    fn update_layout(&mut self) {
+        // A hidden widget takes no space.
+        if self.core.is_hidden {
+            self.core.rectangle = self.core.rectangle.with_size(Size::ZERO);
+            self.bars_area_rectangle = self.core.rectangle;
+            self.column_width = 0.0;
+            return;
+        }
+
+        self.core.rectangle = self.core.rectangle.with_size(Size::new(
+            self.core.size_constraints.maximum().width,
+            SLEEP_WIDGET_HEIGHT,
+        ));
+        self.bars_area_rectangle = self.core.rectangle;
+        self.column_width = self.bars_area_rectangle.width() / self.number_of_columns as f64;
+    }
+
+    /// Paints the widget to the given render context, e.g. to a bitmap or an SVG file.
+    pub fn paint_to<R: RenderContext>(&self, piet: &mut R) -> Result<(), Error> {
+        // A hidden widget is not painted.
+        if self.core.is_hidden {
+            return Ok(());
+        }
+
+        // Fill the bars.
+        for (column_index, night) in self.sleep_month.nights.iter().enumerate() {
+            let Some(night) = night else {
+                continue;
+            };
+
+            // A disabled widget is greyed out.
+            let fill = if self.core.is_disabled {
+                Some(&self.grid_stroke.stroke_brush)
+            } else {
+                self.fills_per_quality_index
+                    .get((night.quality as usize).saturating_sub(1))
+            };
+
+            if let Some(fill) = fill {
+                piet.fill(self.bar_rectangle(column_index, night.hours), fill);
+            }
+        }
+
+        // Stroke the vertical lines.
+        for column_index in 1..self.number_of_columns {
+            let x = self.bars_area_rectangle.x0 + column_index as f64 * self.column_width;
+
+            piet.stroke(
+                Line::new(
+                    (x, self.bars_area_rectangle.y0),
+                    (x, self.bars_area_rectangle.y1),
+                ),
+                &self.grid_stroke.stroke_brush,
+                self.grid_stroke.stroke_width,
+            );
+        }
+
+        // Mark the reference hours.
+        let y = self.bar_rectangle(0, REFERENCE_HOURS).y0;
+
+        piet.stroke(
+            Line::new(
+                (self.bars_area_rectangle.x0, y),
+                (self.bars_area_rectangle.x1, y),
+            ),
+            &self.grid_stroke.stroke_brush,
+            self.grid_stroke.stroke_width,
+        );
+
+        // Draw a rectangle.
+        piet.stroke(
+            self.bars_area_rectangle,
+            &self.grid_stroke.stroke_brush,
+            self.grid_stroke.stroke_width,
+        );
+
+        // Render debug hints.
+        if self.core.debug_rendering {
+            piet.stroke(
+                self.core.rectangle,
+                &self.core.debug_rendering_stroke.stroke_brush,
+                self.core.debug_rendering_stroke.stroke_width,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Widget for SleepWidget {
+    fn apply_size_constraints(&mut self, size_constraints: SizeConstraints) -> Size {
+        self.core.size_constraints = size_constraints;
+
+        // Update the layout.
+        self.update_layout();
+
+        self.core.rectangle.size()
+    }
+
+    fn set_value(&mut self, value: Box<dyn Any>) -> Result<(), WidgetError> {
+        // The given value is a `SleepMonth`.
+        if let Some(sleep_month) = value.downcast_ref::<SleepMonth>() {
+            self.sleep_month = sleep_month.clone();
+        }
+        // The given value is a `SleepWidgetStyle`.
+        else if let Some(style) = value.downcast_ref::<SleepWidgetStyle>() {
+            self.fills_per_quality_index = style.fills_per_quality_index.clone();
+        }
+
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: &Event, widget_events: &mut Vec<WidgetEvent>) {
+        // A disabled or hidden widget ignores input.
+        if self.core.is_disabled || self.core.is_hidden {
+            return;
+        }
+
+        if let Event::MouseDown(mouse_event) = event {
+            // The night after a day of the month was clicked.
+            if let Some(day_of_month_index) = self.column_at(mouse_event.pos) {
+                widget_events.push(WidgetEvent::ValueChanged(
+                    self.core.widget_id,
+                    Box::new(SleepNightClicked { day_of_month_index }),
+                ));
+            }
+        }
+    }
+
+    fn paint(&self, piet: &mut Piet, _region: &Region) -> Result<(), Error> {
+        self.paint_to(piet)
+    }
+
+    fn rectangle(&self) -> &Rect {
+        &self.core.rectangle
+    }
+
+    fn set_origin(&mut self, origin: Point) {
+        self.core.rectangle = self.core.rectangle.with_origin(origin);
+
+        // Update the layout.
+        self.update_layout();
+    }
+
+    fn widget_id(&self) -> &WidgetId {
+        &self.core.widget_id
+    }
+
+    fn set_debug_rendering(&mut self, debug_rendering: bool) {
+        self.core.debug_rendering = debug_rendering;
+    }
+
+    fn set_is_disabled(&mut self, is_disabled: bool) {
+        self.core.is_disabled = is_disabled;
+    }
+
+    fn set_is_hidden(&mut self, is_hidden: bool) {
+        self.core.is_hidden = is_hidden;
+
+        // Update the layout.
+        self.update_layout();
+    }
+}
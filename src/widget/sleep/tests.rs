@@ -0,0 +1,162 @@
+use super::*;
+use guiver::{Modifiers, MouseButton, MouseButtons, MouseEvent, Vec2};
+
+/// The width of the test widget, which gives columns that are 10 pixels wide like the mood
+/// widget's tests.
+const WIDTH: f64 = 310.0;
+
+/// Creates a sleep widget with 31 columns, laid out at the given origin with the given width.
+fn sleep_widget(origin: Point, width: f64) -> SleepWidget {
+    let mut sleep_widget = SleepWidget::new(
+        0,
+        Stroke {
+            stroke_brush: PaintBrush::Color(Color::rgb8(255, 0, 0)),
+            stroke_style: Default::default(),
+            stroke_width: 1.0,
+        },
+        31,
+        SleepWidgetStyle {
+            fills_per_quality_index: std::array::from_fn(|quality_index| {
+                PaintBrush::Color(Color::rgb8(0, 0, 50 * quality_index as u8))
+            }),
+            grid_color: Color::rgb8(128, 128, 128),
+        },
+    );
+
+    sleep_widget.apply_size_constraints(SizeConstraints::new(
+        Size::ZERO,
+        Size::new(width, SLEEP_WIDGET_HEIGHT),
+    ));
+    sleep_widget.set_origin(origin);
+
+    sleep_widget
+}
+
+/// Returns a month of the given number of days, with a night of the given hours after each day.
+fn sleep_month(number_of_days: usize, hours: f64) -> SleepMonth {
+    SleepMonth {
+        nights: vec![Some(SleepNight { hours, quality: 3 }); number_of_days],
+    }
+}
+
+/// Clicks the sleep widget at the given position and returns the clicked nights it reported.
+fn click(sleep_widget: &mut SleepWidget, x: f64, y: f64) -> Vec<SleepNightClicked> {
+    let mut widget_events = vec![];
+
+    sleep_widget.handle_event(
+        &Event::MouseDown(MouseEvent {
+            pos: Point::new(x, y),
+            buttons: MouseButtons::new().with(MouseButton::Left),
+            mods: Modifiers::empty(),
+            count: 1,
+            focus: false,
+            button: MouseButton::Left,
+            wheel_delta: Vec2::ZERO,
+        }),
+        &mut widget_events,
+    );
+
+    widget_events
+        .into_iter()
+        .filter_map(|widget_event| match widget_event {
+            WidgetEvent::ValueChanged(0, value) => value.downcast_ref().cloned(),
+            _ => None,
+        })
+        .collect()
+}
+
+// =================================================================================================
+
+#[test]
+fn columns_are_as_wide_as_the_mood_widgets() {
+    let sleep_widget = sleep_widget(Point::new(20.0, 40.0), WIDTH);
+
+    assert_eq!(
+        sleep_widget.rectangle(),
+        &Rect::new(20.0, 40.0, 20.0 + WIDTH, 40.0 + SLEEP_WIDGET_HEIGHT)
+    );
+    assert_eq!(sleep_widget.column_width, 10.0);
+}
+
+#[test]
+fn bars_grow_with_the_hours_and_are_cut_off() {
+    let sleep_widget = sleep_widget(Point::ZERO, WIDTH);
+
+    assert_eq!(
+        sleep_widget.bar_rectangle(2, MAXIMUM_CHARTED_HOURS / 2.0),
+        Rect::new(20.0, SLEEP_WIDGET_HEIGHT / 2.0, 30.0, SLEEP_WIDGET_HEIGHT)
+    );
+    assert_eq!(
+        sleep_widget.bar_rectangle(0, MAXIMUM_CHARTED_HOURS + 3.0),
+        Rect::new(0.0, 0.0, 10.0, SLEEP_WIDGET_HEIGHT)
+    );
+}
+
+#[test]
+fn clicks_report_the_night_after_the_day() {
+    let mut sleep_widget = sleep_widget(Point::new(100.0, 0.0), WIDTH);
+
+    sleep_widget
+        .set_value(Box::new(sleep_month(30, 8.0)))
+        .expect("the sleep month should be set");
+
+    assert_eq!(
+        click(&mut sleep_widget, 100.0, 10.0),
+        vec![SleepNightClicked {
+            day_of_month_index: 0
+        }]
+    );
+    assert_eq!(
+        click(&mut sleep_widget, 100.0 + 295.0, 10.0),
+        vec![SleepNightClicked {
+            day_of_month_index: 29
+        }]
+    );
+}
+
+#[test]
+fn clicks_after_the_end_of_the_month_or_outside_are_ignored() {
+    let mut sleep_widget = sleep_widget(Point::ZERO, WIDTH);
+
+    sleep_widget
+        .set_value(Box::new(sleep_month(28, 8.0)))
+        .expect("the sleep month should be set");
+
+    assert_eq!(click(&mut sleep_widget, 285.0, 10.0), vec![]);
+    assert_eq!(
+        click(&mut sleep_widget, 5.0, SLEEP_WIDGET_HEIGHT + 1.0),
+        vec![]
+    );
+
+    sleep_widget.set_is_hidden(true);
+
+    assert_eq!(sleep_widget.rectangle().size(), Size::ZERO);
+    assert_eq!(click(&mut sleep_widget, 5.0, 10.0), vec![]);
+}
+
+#[test]
+fn sleep_month_has_a_night_per_day() {
+    let date = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
+    let sleep_per_date = BTreeMap::from([(
+        NaiveDate::from_ymd_opt(2026, 2, 3).unwrap(),
+        SleepEntry::new(
+            chrono::NaiveTime::from_hms_opt(23, 30, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            4,
+            1,
+        )
+        .unwrap(),
+    )]);
+
+    let sleep_month = SleepMonth::new(&sleep_per_date, date);
+
+    assert_eq!(sleep_month.nights.len(), 28);
+    assert_eq!(
+        sleep_month.nights[2],
+        Some(SleepNight {
+            hours: 7.5,
+            quality: 4
+        })
+    );
+    assert_eq!(sleep_month.nights.iter().flatten().count(), 1);
+}
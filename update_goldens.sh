@@ -0,0 +1,4 @@
+#!/bin/sh
+
+# Writes the reference images of the golden tests anew. Check them before committing.
+FREUNDCHEN_UPDATE_GOLDENS=1 cargo test golden_